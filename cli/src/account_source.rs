@@ -0,0 +1,212 @@
+use std::{collections::HashMap, fs, path::Path, str::FromStr};
+
+use anyhow::{anyhow, Context, Result};
+use solana_account_decoder::{UiAccount, UiAccountEncoding};
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::{Memcmp, RpcFilterType},
+    response::RpcKeyedAccount,
+};
+use solana_sdk::{account::Account, commitment_config::CommitmentConfig, pubkey::Pubkey};
+
+/// Byte-level filter applied to account data, equivalent to an RPC memcmp filter
+pub type DataFilter<'a> = (usize, &'a [u8]);
+
+/// Read-only view of on-chain accounts, backed by RPC or by dumped account files
+pub trait AccountSource {
+    fn get_account(&self, address: &Pubkey) -> Result<Option<Account>>;
+
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[DataFilter],
+    ) -> Result<Vec<(Pubkey, Account)>>;
+}
+
+pub struct RpcAccountSource {
+    client: RpcClient,
+}
+
+impl RpcAccountSource {
+    pub fn new(rpc_url: String) -> Self {
+        Self {
+            client: RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed()),
+        }
+    }
+}
+
+impl AccountSource for RpcAccountSource {
+    fn get_account(&self, address: &Pubkey) -> Result<Option<Account>> {
+        Ok(self
+            .client
+            .get_account_with_commitment(address, self.client.commitment())?
+            .value)
+    }
+
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[DataFilter],
+    ) -> Result<Vec<(Pubkey, Account)>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(
+                filters
+                    .iter()
+                    .map(|(offset, bytes)| {
+                        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(*offset, bytes.to_vec()))
+                    })
+                    .collect(),
+            ),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        Ok(self
+            .client
+            .get_program_accounts_with_config(program_id, config)?)
+    }
+}
+
+/// Accounts loaded from a directory of `solana account --output json` dumps
+pub struct DirectoryAccountSource {
+    accounts: HashMap<Pubkey, Account>,
+}
+
+impl DirectoryAccountSource {
+    pub fn load(dir: &Path) -> Result<Self> {
+        let mut accounts = HashMap::new();
+
+        for entry in fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+            let path = entry?.path();
+            if path.extension().map_or(true, |ext| ext != "json") {
+                continue;
+            }
+
+            let contents =
+                fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+            let (pubkey, account) = parse_keyed_account(&contents)
+                .with_context(|| format!("parsing {}", path.display()))?;
+            accounts.insert(pubkey, account);
+        }
+
+        Ok(Self { accounts })
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+}
+
+impl AccountSource for DirectoryAccountSource {
+    fn get_account(&self, address: &Pubkey) -> Result<Option<Account>> {
+        Ok(self.accounts.get(address).cloned())
+    }
+
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[DataFilter],
+    ) -> Result<Vec<(Pubkey, Account)>> {
+        let mut accounts: Vec<(Pubkey, Account)> = self
+            .accounts
+            .iter()
+            .filter(|(_, account)| account.owner == *program_id)
+            .filter(|(_, account)| {
+                filters.iter().all(|(offset, bytes)| {
+                    account
+                        .data
+                        .get(*offset..offset + bytes.len())
+                        .map_or(false, |data| data == *bytes)
+                })
+            })
+            .map(|(pubkey, account)| (*pubkey, account.clone()))
+            .collect();
+
+        // Keep the output stable regardless of hash map ordering
+        accounts.sort_by_key(|(pubkey, _)| *pubkey);
+
+        Ok(accounts)
+    }
+}
+
+pub fn parse_keyed_account(json: &str) -> Result<(Pubkey, Account)> {
+    let keyed_account: RpcKeyedAccount = serde_json::from_str(json)?;
+    let pubkey = Pubkey::from_str(&keyed_account.pubkey)?;
+    let account = keyed_account
+        .account
+        .decode::<Account>()
+        .ok_or_else(|| anyhow!("Unsupported account encoding for {}", pubkey))?;
+
+    Ok((pubkey, account))
+}
+
+pub fn keyed_account_to_json(pubkey: &Pubkey, account: &Account) -> Result<String> {
+    let keyed_account = RpcKeyedAccount {
+        pubkey: pubkey.to_string(),
+        account: UiAccount::encode(pubkey, account, UiAccountEncoding::Base64, None, None),
+    };
+
+    Ok(serde_json::to_string_pretty(&keyed_account)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_directory_account_source() {
+        let dir = env::temp_dir().join(format!("account-source-{}", Pubkey::new_unique()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let program_id = Pubkey::new_unique();
+        let matching = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+
+        for (pubkey, data) in [(matching, vec![7, 1, 2]), (other, vec![8, 1, 2])] {
+            let account = Account {
+                lamports: 1,
+                data,
+                owner: program_id,
+                executable: false,
+                rent_epoch: 0,
+            };
+            fs::write(
+                dir.join(format!("{}.json", pubkey)),
+                keyed_account_to_json(&pubkey, &account).unwrap(),
+            )
+            .unwrap();
+        }
+        fs::write(dir.join("README.md"), "ignored").unwrap();
+
+        let source = DirectoryAccountSource::load(&dir).unwrap();
+        assert_eq!(source.len(), 2);
+        assert_eq!(
+            source.get_account(&matching).unwrap().unwrap().data,
+            vec![7, 1, 2]
+        );
+        assert!(source.get_account(&Pubkey::new_unique()).unwrap().is_none());
+
+        let accounts = source
+            .get_program_accounts(&program_id, &[(0, &[7])])
+            .unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].0, matching);
+
+        assert!(source
+            .get_program_accounts(&Pubkey::new_unique(), &[])
+            .unwrap()
+            .is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
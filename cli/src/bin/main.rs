@@ -1,7 +1,61 @@
+use anyhow::{bail, Result};
 use clap::Parser;
-use jito_tip_router_cli::cli_args::Args;
+use env_logger::Env;
+use jito_tip_router_cli::{
+    account_source::{AccountSource, DirectoryAccountSource, RpcAccountSource},
+    cli_args::{Args, Commands},
+    snapshot_verifier::verify_snapshot,
+    tree_diff::diff_trees,
+};
+use log::info;
 
-fn main() {
+fn main() -> Result<()> {
+    env_logger::Builder::from_env(Env::default().default_filter_or("info")).init();
     let args = Args::parse();
-    println!("Hello {}!", args.name);
+
+    match args.command {
+        Commands::VerifySnapshot {
+            ncn,
+            ncn_epoch,
+            accounts_dir,
+        } => {
+            let source: Box<dyn AccountSource> = match accounts_dir {
+                Some(dir) => {
+                    let source = DirectoryAccountSource::load(&dir)?;
+                    info!("Loaded {} accounts from {}", source.len(), dir.display());
+                    Box::new(source)
+                }
+                None => Box::new(RpcAccountSource::new(args.rpc_url)),
+            };
+
+            let report = verify_snapshot(
+                source.as_ref(),
+                &args.tip_router_program_id,
+                &ncn,
+                ncn_epoch,
+            )?;
+            println!("{report}");
+
+            if !report.is_consistent() {
+                bail!("Snapshot does not match recomputed state");
+            }
+        }
+        Commands::DiffTrees {
+            expected,
+            actual,
+            kind,
+        } => {
+            let differences = diff_trees(kind, &expected, &actual)?;
+            for difference in differences.iter() {
+                println!("{difference}");
+            }
+
+            if !differences.is_empty() {
+                bail!("Trees differ in {} places", differences.len());
+            }
+            println!("Trees match");
+        }
+    }
+
+    Ok(())
 }
@@ -1,9 +1,66 @@
-use clap::Parser;
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use jito_tip_router_client::JITO_TIP_ROUTER_ID;
+use solana_sdk::pubkey::Pubkey;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
-    /// Name of the person to greet
-    #[arg(short, long)]
-    pub name: String,
+    /// RPC endpoint used to fetch accounts
+    #[arg(
+        long,
+        global = true,
+        default_value = "https://api.mainnet-beta.solana.com"
+    )]
+    pub rpc_url: String,
+
+    /// Tip router program ID
+    #[arg(long, global = true, default_value_t = JITO_TIP_ROUTER_ID)]
+    pub tip_router_program_id: Pubkey,
+
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Commands {
+    /// Recompute an epoch's stake weights from restaking and vault state and compare
+    /// them against the on-chain operator and epoch snapshots
+    VerifySnapshot {
+        /// NCN the snapshot was taken for
+        #[arg(long)]
+        ncn: Pubkey,
+
+        /// NCN epoch of the snapshot
+        #[arg(long)]
+        ncn_epoch: u64,
+
+        /// Read accounts from a directory of `solana account --output json` files
+        /// instead of RPC
+        #[arg(long)]
+        accounts_dir: Option<PathBuf>,
+    },
+
+    /// Compare two merkle trees, JSON or binary, to find out why operators' meta merkle
+    /// roots differ
+    DiffTrees {
+        /// Tree to compare against
+        expected: PathBuf,
+
+        /// Tree reported as missing or extra relative to `expected`
+        actual: PathBuf,
+
+        /// Kind of tree both files hold
+        #[arg(long, value_enum, default_value_t = TreeKind::Meta)]
+        kind: TreeKind,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeKind {
+    /// A `MetaMerkleTree`, one node per tip distribution account
+    Meta,
+    /// A `GeneratedMerkleTreeCollection`, with every claimant of every tip distribution account
+    Generated,
 }
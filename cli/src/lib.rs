@@ -1 +1,4 @@
+pub mod account_source;
 pub mod cli_args;
+pub mod snapshot_verifier;
+pub mod tree_diff;
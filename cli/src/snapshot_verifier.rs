@@ -0,0 +1,508 @@
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_restaking_core::{
+    config::Config, ncn_operator_state::NcnOperatorState, ncn_vault_ticket::NcnVaultTicket,
+};
+use jito_tip_router_core::{
+    epoch_snapshot::{EpochSnapshot, ExclusionReason, OperatorSnapshot},
+    weight_table::WeightTable,
+};
+use jito_vault_core::{
+    vault::Vault, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
+};
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+use crate::account_source::AccountSource;
+
+/// Offset of the first field after the 8 byte account discriminator
+const NCN_FIELD_OFFSET: usize = 8;
+/// `OperatorSnapshot` starts with `operator`, followed by `ncn` and `ncn_epoch`
+const OPERATOR_SNAPSHOT_NCN_OFFSET: usize = 8 + 32;
+const OPERATOR_SNAPSHOT_EPOCH_OFFSET: usize = 8 + 32 + 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotMismatch {
+    MissingOperatorSnapshot {
+        operator: Pubkey,
+    },
+    UnknownOperatorSnapshot {
+        operator: Pubkey,
+    },
+    OperatorActive {
+        operator: Pubkey,
+        expected: bool,
+        actual: bool,
+    },
+    VaultStakeWeight {
+        operator: Pubkey,
+        vault: Pubkey,
+        expected: u128,
+        actual: Option<u128>,
+    },
+    OperatorStakeWeight {
+        operator: Pubkey,
+        expected: u128,
+        actual: u128,
+    },
+    OperatorValidDelegations {
+        operator: Pubkey,
+        expected: u64,
+        actual: u64,
+    },
+    EpochOperatorCount {
+        expected: u64,
+        actual: u64,
+    },
+    EpochStakeWeight {
+        expected: u128,
+        actual: u128,
+    },
+    EpochValidDelegations {
+        expected: u64,
+        actual: u64,
+    },
+}
+
+impl fmt::Display for SnapshotMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingOperatorSnapshot { operator } => {
+                write!(f, "operator {operator}: no operator snapshot")
+            }
+            Self::UnknownOperatorSnapshot { operator } => {
+                write!(f, "operator {operator}: snapshot exists but operator is not registered with the NCN")
+            }
+            Self::OperatorActive {
+                operator,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "operator {operator}: is_active expected {expected}, snapshot has {actual}"
+            ),
+            Self::VaultStakeWeight {
+                operator,
+                vault,
+                expected,
+                actual,
+            } => match actual {
+                Some(actual) => write!(
+                    f,
+                    "operator {operator} vault {vault}: stake weight expected {expected}, snapshot has {actual}"
+                ),
+                None => write!(
+                    f,
+                    "operator {operator} vault {vault}: stake weight expected {expected}, vault missing from snapshot"
+                ),
+            },
+            Self::OperatorStakeWeight {
+                operator,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "operator {operator}: stake weight expected {expected}, snapshot has {actual}"
+            ),
+            Self::OperatorValidDelegations {
+                operator,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "operator {operator}: valid delegations expected {expected}, snapshot has {actual}"
+            ),
+            Self::EpochOperatorCount { expected, actual } => write!(
+                f,
+                "epoch snapshot: operator count expected {expected}, snapshot has {actual}"
+            ),
+            Self::EpochStakeWeight { expected, actual } => write!(
+                f,
+                "epoch snapshot: stake weight expected {expected}, snapshot has {actual}"
+            ),
+            Self::EpochValidDelegations { expected, actual } => write!(
+                f,
+                "epoch snapshot: valid delegations expected {expected}, snapshot has {actual}"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VaultStakeWeight {
+    pub vault: Pubkey,
+    pub vault_index: u64,
+    pub stake_weight: u128,
+}
+
+#[derive(Debug, Clone)]
+pub struct OperatorReport {
+    pub operator: Pubkey,
+    pub is_active: bool,
+    /// Reason recorded on-chain when the operator carries no stake weight
+    pub exclusion_reason: Option<ExclusionReason>,
+    pub vaults: Vec<VaultStakeWeight>,
+    pub stake_weight: u128,
+    pub valid_delegations: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SnapshotReport {
+    pub ncn: Pubkey,
+    pub ncn_epoch: u64,
+    pub operators: Vec<OperatorReport>,
+    pub stake_weight: u128,
+    pub valid_delegations: u64,
+    pub mismatches: Vec<SnapshotMismatch>,
+}
+
+impl SnapshotReport {
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl fmt::Display for SnapshotReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "NCN {} epoch {}", self.ncn, self.ncn_epoch)?;
+        for operator in self.operators.iter() {
+            writeln!(
+                f,
+                "  operator {} active: {} stake weight: {} valid delegations: {}",
+                operator.operator,
+                operator.is_active,
+                operator.stake_weight,
+                operator.valid_delegations
+            )?;
+            if let Some(reason) = operator
+                .exclusion_reason
+                .filter(|reason| *reason != ExclusionReason::NotExcluded)
+            {
+                writeln!(f, "    excluded: {reason:?}")?;
+            }
+        }
+        writeln!(
+            f,
+            "  total stake weight: {} valid delegations: {}",
+            self.stake_weight, self.valid_delegations
+        )?;
+
+        if self.is_consistent() {
+            write!(f, "Snapshot matches recomputed state")
+        } else {
+            writeln!(f, "{} mismatches:", self.mismatches.len())?;
+            for mismatch in self.mismatches.iter() {
+                writeln!(f, "  {mismatch}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn load_account<T: AccountDeserialize + Copy>(
+    source: &dyn AccountSource,
+    address: &Pubkey,
+) -> Result<Option<T>> {
+    source
+        .get_account(address)?
+        .map(|account| decode_account::<T>(address, &account))
+        .transpose()
+}
+
+fn decode_account<T: AccountDeserialize + Copy>(address: &Pubkey, account: &Account) -> Result<T> {
+    T::try_from_slice_unchecked(&account.data)
+        .copied()
+        .map_err(|e| anyhow!("Failed to decode account {}: {}", address, e))
+}
+
+fn require_account<T: AccountDeserialize + Copy>(
+    source: &dyn AccountSource,
+    address: &Pubkey,
+    name: &str,
+) -> Result<T> {
+    load_account::<T>(source, address)?.ok_or_else(|| anyhow!("{} {} not found", name, address))
+}
+
+/// Recomputes every operator's stake weight for `ncn_epoch` from restaking and vault state,
+/// and compares it against the on-chain `OperatorSnapshot` and `EpochSnapshot` accounts.
+///
+/// Ticket and opt-in states are evaluated at the slot each operator snapshot was created. The
+/// recomputation reads current delegation state, so it should be run against accounts fetched
+/// (or dumped) before delegations change.
+pub fn verify_snapshot(
+    source: &dyn AccountSource,
+    tip_router_program_id: &Pubkey,
+    ncn: &Pubkey,
+    ncn_epoch: u64,
+) -> Result<SnapshotReport> {
+    let restaking_program_id = jito_restaking_program::id();
+
+    let restaking_config = require_account::<Config>(
+        source,
+        &Config::find_program_address(&restaking_program_id).0,
+        "Restaking config",
+    )?;
+    let epoch_length = restaking_config.epoch_length();
+
+    let epoch_snapshot = require_account::<EpochSnapshot>(
+        source,
+        &EpochSnapshot::find_program_address(tip_router_program_id, ncn, ncn_epoch).0,
+        "Epoch snapshot",
+    )?;
+    let weight_table = require_account::<WeightTable>(
+        source,
+        &WeightTable::find_program_address(tip_router_program_id, ncn, ncn_epoch).0,
+        "Weight table",
+    )?;
+
+    let ncn_filter = ncn.to_bytes();
+
+    let mut ncn_operator_states: Vec<NcnOperatorState> = source
+        .get_program_accounts(
+            &restaking_program_id,
+            &[
+                (0, &[NcnOperatorState::DISCRIMINATOR]),
+                (NCN_FIELD_OFFSET, &ncn_filter),
+            ],
+        )?
+        .iter()
+        .map(|(address, account)| decode_account::<NcnOperatorState>(address, account))
+        .collect::<Result<Vec<_>>>()?;
+    ncn_operator_states.sort_by_key(|state| state.index());
+
+    let mut vaults: Vec<(Pubkey, Vault)> = Vec::new();
+    for (address, account) in source.get_program_accounts(
+        &restaking_program_id,
+        &[
+            (0, &[NcnVaultTicket::DISCRIMINATOR]),
+            (NCN_FIELD_OFFSET, &ncn_filter),
+        ],
+    )? {
+        let ncn_vault_ticket = decode_account::<NcnVaultTicket>(&address, &account)?;
+        let vault = require_account::<Vault>(source, &ncn_vault_ticket.vault, "Vault")?;
+        vaults.push((ncn_vault_ticket.vault, vault));
+    }
+    vaults.sort_by_key(|(_, vault)| vault.vault_index());
+
+    let mut mismatches = Vec::new();
+    let mut operators = Vec::new();
+
+    for ncn_operator_state in ncn_operator_states.iter() {
+        let operator = ncn_operator_state.operator;
+
+        let operator_snapshot = load_account::<OperatorSnapshot>(
+            source,
+            &OperatorSnapshot::find_program_address(
+                tip_router_program_id,
+                &operator,
+                ncn,
+                ncn_epoch,
+            )
+            .0,
+        )?;
+
+        let slot = operator_snapshot
+            .as_ref()
+            .map_or(epoch_snapshot.slot_created(), |snapshot| {
+                snapshot.slot_created()
+            });
+
+        let is_active = ncn_operator_state
+            .ncn_opt_in_state
+            .is_active(slot, epoch_length)
+            && ncn_operator_state
+                .operator_opt_in_state
+                .is_active(slot, epoch_length);
+
+        let mut vault_stake_weights = Vec::new();
+        if is_active {
+            for (vault_address, vault) in vaults.iter() {
+                let stake_weight = recompute_vault_stake_weight(
+                    source,
+                    &weight_table,
+                    ncn,
+                    &operator,
+                    vault_address,
+                    vault,
+                    slot,
+                    epoch_length,
+                )?;
+
+                vault_stake_weights.push(VaultStakeWeight {
+                    vault: *vault_address,
+                    vault_index: vault.vault_index(),
+                    stake_weight,
+                });
+            }
+        }
+
+        let report = OperatorReport {
+            operator,
+            is_active,
+            exclusion_reason: operator_snapshot
+                .as_ref()
+                .and_then(|snapshot| snapshot.exclusion_reason().ok()),
+            stake_weight: vault_stake_weights.iter().map(|v| v.stake_weight).sum(),
+            valid_delegations: vault_stake_weights
+                .iter()
+                .filter(|v| v.stake_weight > 0)
+                .count() as u64,
+            vaults: vault_stake_weights,
+        };
+
+        match operator_snapshot {
+            Some(snapshot) => diff_operator(&report, &snapshot, &mut mismatches),
+            None => mismatches.push(SnapshotMismatch::MissingOperatorSnapshot { operator }),
+        }
+
+        operators.push(report);
+    }
+
+    let epoch_filter = ncn_epoch.to_le_bytes();
+    for (address, account) in source.get_program_accounts(
+        tip_router_program_id,
+        &[
+            (0, &[OperatorSnapshot::DISCRIMINATOR]),
+            (OPERATOR_SNAPSHOT_NCN_OFFSET, &ncn_filter),
+            (OPERATOR_SNAPSHOT_EPOCH_OFFSET, &epoch_filter),
+        ],
+    )? {
+        let operator = decode_account::<OperatorSnapshot>(&address, &account)?.operator();
+        if !operators.iter().any(|report| report.operator == operator) {
+            mismatches.push(SnapshotMismatch::UnknownOperatorSnapshot { operator });
+        }
+    }
+
+    let stake_weight = operators.iter().map(|o| o.stake_weight).sum();
+    let valid_delegations = operators.iter().map(|o| o.valid_delegations).sum();
+
+    if epoch_snapshot.operator_count() != operators.len() as u64 {
+        mismatches.push(SnapshotMismatch::EpochOperatorCount {
+            expected: operators.len() as u64,
+            actual: epoch_snapshot.operator_count(),
+        });
+    }
+    if epoch_snapshot.stake_weight() != stake_weight {
+        mismatches.push(SnapshotMismatch::EpochStakeWeight {
+            expected: stake_weight,
+            actual: epoch_snapshot.stake_weight(),
+        });
+    }
+    if epoch_snapshot.valid_operator_vault_delegations() != valid_delegations {
+        mismatches.push(SnapshotMismatch::EpochValidDelegations {
+            expected: valid_delegations,
+            actual: epoch_snapshot.valid_operator_vault_delegations(),
+        });
+    }
+
+    Ok(SnapshotReport {
+        ncn: *ncn,
+        ncn_epoch,
+        operators,
+        stake_weight,
+        valid_delegations,
+        mismatches,
+    })
+}
+
+/// Mirrors the on-chain `SnapshotVaultOperatorDelegation` weighting for one vault
+#[allow(clippy::too_many_arguments)]
+fn recompute_vault_stake_weight(
+    source: &dyn AccountSource,
+    weight_table: &WeightTable,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    vault_address: &Pubkey,
+    vault: &Vault,
+    slot: u64,
+    epoch_length: u64,
+) -> Result<u128> {
+    let restaking_program_id = jito_restaking_program::id();
+    let vault_program_id = jito_vault_program::id();
+
+    let vault_ncn_ticket = load_account::<VaultNcnTicket>(
+        source,
+        &VaultNcnTicket::find_program_address(&vault_program_id, vault_address, ncn).0,
+    )?;
+    let ncn_vault_ticket = load_account::<NcnVaultTicket>(
+        source,
+        &NcnVaultTicket::find_program_address(&restaking_program_id, ncn, vault_address).0,
+    )?;
+    let vault_operator_delegation = load_account::<VaultOperatorDelegation>(
+        source,
+        &VaultOperatorDelegation::find_program_address(&vault_program_id, vault_address, operator)
+            .0,
+    )?;
+
+    let (Some(vault_ncn_ticket), Some(ncn_vault_ticket), Some(vault_operator_delegation)) = (
+        vault_ncn_ticket,
+        ncn_vault_ticket,
+        vault_operator_delegation,
+    ) else {
+        return Ok(0);
+    };
+
+    let is_active = vault_ncn_ticket.state.is_active(slot, epoch_length)
+        && ncn_vault_ticket.state.is_active(slot, epoch_length);
+    if !is_active {
+        return Ok(0);
+    }
+
+    OperatorSnapshot::calculate_total_stake_weight(
+        &vault_operator_delegation,
+        weight_table,
+        &vault.supported_mint,
+    )
+    .map_err(|e| anyhow!("Failed to calculate stake weight: {}", e))
+}
+
+fn diff_operator(
+    report: &OperatorReport,
+    snapshot: &OperatorSnapshot,
+    mismatches: &mut Vec<SnapshotMismatch>,
+) {
+    let operator = report.operator;
+
+    if snapshot.is_active() != report.is_active {
+        mismatches.push(SnapshotMismatch::OperatorActive {
+            operator,
+            expected: report.is_active,
+            actual: snapshot.is_active(),
+        });
+    }
+
+    for vault in report.vaults.iter() {
+        let actual = snapshot
+            .vault_operator_stake_weights()
+            .iter()
+            .find(|entry| entry.vault_index() == vault.vault_index)
+            .map(|entry| entry.stake_weight());
+
+        if actual != Some(vault.stake_weight) {
+            mismatches.push(SnapshotMismatch::VaultStakeWeight {
+                operator,
+                vault: vault.vault,
+                expected: vault.stake_weight,
+                actual,
+            });
+        }
+    }
+
+    if snapshot.stake_weight() != report.stake_weight {
+        mismatches.push(SnapshotMismatch::OperatorStakeWeight {
+            operator,
+            expected: report.stake_weight,
+            actual: snapshot.stake_weight(),
+        });
+    }
+
+    if snapshot.valid_operator_vault_delegations() != report.valid_delegations {
+        mismatches.push(SnapshotMismatch::OperatorValidDelegations {
+            operator,
+            expected: report.valid_delegations,
+            actual: snapshot.valid_operator_vault_delegations(),
+        });
+    }
+}
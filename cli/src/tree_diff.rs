@@ -0,0 +1,60 @@
+use std::{fs::File, io::Read, path::PathBuf};
+
+use anyhow::{Context, Result};
+use meta_merkle_tree::{
+    binary::{BinaryFormat, GENERATED_MERKLE_TREE_COLLECTION_MAGIC, META_MERKLE_TREE_MAGIC},
+    diff::{diff_generated_merkle_tree_collections, diff_meta_merkle_trees, TreeDifference},
+    generated_merkle_tree::{read_json_from_file, GeneratedMerkleTreeCollection},
+    meta_merkle_tree::MetaMerkleTree,
+};
+
+use crate::cli_args::TreeKind;
+
+/// Differences of the tree at `actual` from the one at `expected`
+pub fn diff_trees(
+    kind: TreeKind,
+    expected: &PathBuf,
+    actual: &PathBuf,
+) -> Result<Vec<TreeDifference>> {
+    match kind {
+        TreeKind::Meta => Ok(diff_meta_merkle_trees(
+            &load_meta_merkle_tree(expected)?,
+            &load_meta_merkle_tree(actual)?,
+        )),
+        TreeKind::Generated => Ok(diff_generated_merkle_tree_collections(
+            &load_generated_merkle_tree_collection(expected)?,
+            &load_generated_merkle_tree_collection(actual)?,
+        )),
+    }
+}
+
+fn load_meta_merkle_tree(path: &PathBuf) -> Result<MetaMerkleTree> {
+    let tree = if has_magic(path, META_MERKLE_TREE_MAGIC)? {
+        MetaMerkleTree::new_from_binary_file(path)
+    } else {
+        MetaMerkleTree::new_from_file(path)
+    };
+
+    tree.with_context(|| format!("loading meta merkle tree {}", path.display()))
+}
+
+fn load_generated_merkle_tree_collection(path: &PathBuf) -> Result<GeneratedMerkleTreeCollection> {
+    if has_magic(path, GENERATED_MERKLE_TREE_COLLECTION_MAGIC)? {
+        GeneratedMerkleTreeCollection::new_from_binary_file(path)
+            .with_context(|| format!("loading generated merkle trees {}", path.display()))
+    } else {
+        read_json_from_file(path)
+            .with_context(|| format!("loading generated merkle trees {}", path.display()))
+    }
+}
+
+/// Whether the file starts with a binary format magic, JSON is assumed otherwise
+fn has_magic(path: &PathBuf, magic: [u8; 4]) -> Result<bool> {
+    let mut file = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+    let mut header = Vec::with_capacity(magic.len());
+    file.by_ref()
+        .take(magic.len() as u64)
+        .read_to_end(&mut header)?;
+
+    Ok(header == magic)
+}
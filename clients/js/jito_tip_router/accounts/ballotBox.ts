@@ -56,6 +56,7 @@ export type BallotBox = {
   bump: number;
   slotCreated: bigint;
   slotConsensusReached: bigint;
+  payer: Address;
   reserved: Array<number>;
   operatorsVoted: bigint;
   uniqueBallots: bigint;
@@ -71,6 +72,7 @@ export type BallotBoxArgs = {
   bump: number;
   slotCreated: number | bigint;
   slotConsensusReached: number | bigint;
+  payer: Address;
   reserved: Array<number>;
   operatorsVoted: number | bigint;
   uniqueBallots: number | bigint;
@@ -87,7 +89,8 @@ export function getBallotBoxEncoder(): Encoder<BallotBoxArgs> {
     ['bump', getU8Encoder()],
     ['slotCreated', getU64Encoder()],
     ['slotConsensusReached', getU64Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 128 })],
+    ['payer', getAddressEncoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 96 })],
     ['operatorsVoted', getU64Encoder()],
     ['uniqueBallots', getU64Encoder()],
     ['winningBallot', getBallotEncoder()],
@@ -104,7 +107,8 @@ export function getBallotBoxDecoder(): Decoder<BallotBox> {
     ['bump', getU8Decoder()],
     ['slotCreated', getU64Decoder()],
     ['slotConsensusReached', getU64Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 128 })],
+    ['payer', getAddressDecoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 96 })],
     ['operatorsVoted', getU64Decoder()],
     ['uniqueBallots', getU64Decoder()],
     ['winningBallot', getBallotDecoder()],
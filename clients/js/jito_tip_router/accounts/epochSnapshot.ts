@@ -36,12 +36,7 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
-import {
-  getFeesDecoder,
-  getFeesEncoder,
-  type Fees,
-  type FeesArgs,
-} from '../types';
+import { getFeeDecoder, getFeeEncoder, type Fee, type FeeArgs } from '../types';
 
 export type EpochSnapshot = {
   discriminator: bigint;
@@ -50,12 +45,13 @@ export type EpochSnapshot = {
   bump: number;
   slotCreated: bigint;
   slotFinalized: bigint;
-  ncnFees: Fees;
+  fee: Fee;
   operatorCount: bigint;
   vaultCount: bigint;
   operatorsRegistered: bigint;
   validOperatorVaultDelegations: bigint;
   stakeWeight: bigint;
+  payer: Address;
   reserved: Array<number>;
 };
 
@@ -66,12 +62,13 @@ export type EpochSnapshotArgs = {
   bump: number;
   slotCreated: number | bigint;
   slotFinalized: number | bigint;
-  ncnFees: FeesArgs;
+  fee: FeeArgs;
   operatorCount: number | bigint;
   vaultCount: number | bigint;
   operatorsRegistered: number | bigint;
   validOperatorVaultDelegations: number | bigint;
   stakeWeight: number | bigint;
+  payer: Address;
   reserved: Array<number>;
 };
 
@@ -83,13 +80,14 @@ export function getEpochSnapshotEncoder(): Encoder<EpochSnapshotArgs> {
     ['bump', getU8Encoder()],
     ['slotCreated', getU64Encoder()],
     ['slotFinalized', getU64Encoder()],
-    ['ncnFees', getFeesEncoder()],
+    ['fee', getFeeEncoder()],
     ['operatorCount', getU64Encoder()],
     ['vaultCount', getU64Encoder()],
     ['operatorsRegistered', getU64Encoder()],
     ['validOperatorVaultDelegations', getU64Encoder()],
     ['stakeWeight', getU128Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 128 })],
+    ['payer', getAddressEncoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 96 })],
   ]);
 }
 
@@ -101,13 +99,14 @@ export function getEpochSnapshotDecoder(): Decoder<EpochSnapshot> {
     ['bump', getU8Decoder()],
     ['slotCreated', getU64Decoder()],
     ['slotFinalized', getU64Decoder()],
-    ['ncnFees', getFeesDecoder()],
+    ['fee', getFeeDecoder()],
     ['operatorCount', getU64Decoder()],
     ['vaultCount', getU64Decoder()],
     ['operatorsRegistered', getU64Decoder()],
     ['validOperatorVaultDelegations', getU64Decoder()],
     ['stakeWeight', getU128Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 128 })],
+    ['payer', getAddressDecoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 96 })],
   ]);
 }
 
@@ -50,6 +50,9 @@ export type NcnConfig = {
   epochsBeforeStall: bigint;
   fees: Fees;
   bump: number;
+  epochsBeforeClose: bigint;
+  snapshotWindowSlots: bigint;
+  snapshotGraceEpochs: bigint;
   reserved: Array<number>;
 };
 
@@ -62,6 +65,9 @@ export type NcnConfigArgs = {
   epochsBeforeStall: number | bigint;
   fees: FeesArgs;
   bump: number;
+  epochsBeforeClose: number | bigint;
+  snapshotWindowSlots: number | bigint;
+  snapshotGraceEpochs: number | bigint;
   reserved: Array<number>;
 };
 
@@ -75,7 +81,10 @@ export function getNcnConfigEncoder(): Encoder<NcnConfigArgs> {
     ['epochsBeforeStall', getU64Encoder()],
     ['fees', getFeesEncoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 127 })],
+    ['epochsBeforeClose', getU64Encoder()],
+    ['snapshotWindowSlots', getU64Encoder()],
+    ['snapshotGraceEpochs', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 103 })],
   ]);
 }
 
@@ -89,7 +98,10 @@ export function getNcnConfigDecoder(): Decoder<NcnConfig> {
     ['epochsBeforeStall', getU64Decoder()],
     ['fees', getFeesDecoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 127 })],
+    ['epochsBeforeClose', getU64Decoder()],
+    ['snapshotWindowSlots', getU64Decoder()],
+    ['snapshotGraceEpochs', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 103 })],
   ]);
 }
 
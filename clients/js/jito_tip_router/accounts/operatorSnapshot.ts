@@ -63,6 +63,10 @@ export type OperatorSnapshot = {
   vaultOperatorDelegationsRegistered: bigint;
   validOperatorVaultDelegations: bigint;
   stakeWeight: bigint;
+  payer: Address;
+  exclusionReason: number;
+  rewardsRouted: bigint;
+  operatorFeeWallet: Address;
   reserved: Array<number>;
   vaultOperatorStakeWeight: Array<VaultOperatorStakeWeight>;
 };
@@ -83,6 +87,10 @@ export type OperatorSnapshotArgs = {
   vaultOperatorDelegationsRegistered: number | bigint;
   validOperatorVaultDelegations: number | bigint;
   stakeWeight: number | bigint;
+  payer: Address;
+  exclusionReason: number;
+  rewardsRouted: number | bigint;
+  operatorFeeWallet: Address;
   reserved: Array<number>;
   vaultOperatorStakeWeight: Array<VaultOperatorStakeWeightArgs>;
 };
@@ -104,10 +112,14 @@ export function getOperatorSnapshotEncoder(): Encoder<OperatorSnapshotArgs> {
     ['vaultOperatorDelegationsRegistered', getU64Encoder()],
     ['validOperatorVaultDelegations', getU64Encoder()],
     ['stakeWeight', getU128Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 256 })],
+    ['payer', getAddressEncoder()],
+    ['exclusionReason', getU8Encoder()],
+    ['rewardsRouted', getU64Encoder()],
+    ['operatorFeeWallet', getAddressEncoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 183 })],
     [
       'vaultOperatorStakeWeight',
-      getArrayEncoder(getVaultOperatorStakeWeightEncoder(), { size: 32 }),
+      getArrayEncoder(getVaultOperatorStakeWeightEncoder(), { size: 64 }),
     ],
   ]);
 }
@@ -129,10 +141,14 @@ export function getOperatorSnapshotDecoder(): Decoder<OperatorSnapshot> {
     ['vaultOperatorDelegationsRegistered', getU64Decoder()],
     ['validOperatorVaultDelegations', getU64Decoder()],
     ['stakeWeight', getU128Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 256 })],
+    ['payer', getAddressDecoder()],
+    ['exclusionReason', getU8Decoder()],
+    ['rewardsRouted', getU64Decoder()],
+    ['operatorFeeWallet', getAddressDecoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 183 })],
     [
       'vaultOperatorStakeWeight',
-      getArrayDecoder(getVaultOperatorStakeWeightDecoder(), { size: 32 }),
+      getArrayDecoder(getVaultOperatorStakeWeightDecoder(), { size: 64 }),
     ],
   ]);
 }
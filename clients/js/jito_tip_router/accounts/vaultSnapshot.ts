@@ -0,0 +1,178 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type VaultSnapshot = {
+  discriminator: bigint;
+  vault: Address;
+  ncn: Address;
+  ncnEpoch: bigint;
+  bump: number;
+  slotCreated: bigint;
+  slotLastUpdated: bigint;
+  vaultIndex: bigint;
+  stMint: Address;
+  operatorsRegistered: bigint;
+  validOperatorDelegations: bigint;
+  stakeWeight: bigint;
+  payer: Address;
+  feeWallet: Address;
+  reserved: Array<number>;
+};
+
+export type VaultSnapshotArgs = {
+  discriminator: number | bigint;
+  vault: Address;
+  ncn: Address;
+  ncnEpoch: number | bigint;
+  bump: number;
+  slotCreated: number | bigint;
+  slotLastUpdated: number | bigint;
+  vaultIndex: number | bigint;
+  stMint: Address;
+  operatorsRegistered: number | bigint;
+  validOperatorDelegations: number | bigint;
+  stakeWeight: number | bigint;
+  payer: Address;
+  feeWallet: Address;
+  reserved: Array<number>;
+};
+
+export function getVaultSnapshotEncoder(): Encoder<VaultSnapshotArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['vault', getAddressEncoder()],
+    ['ncn', getAddressEncoder()],
+    ['ncnEpoch', getU64Encoder()],
+    ['bump', getU8Encoder()],
+    ['slotCreated', getU64Encoder()],
+    ['slotLastUpdated', getU64Encoder()],
+    ['vaultIndex', getU64Encoder()],
+    ['stMint', getAddressEncoder()],
+    ['operatorsRegistered', getU64Encoder()],
+    ['validOperatorDelegations', getU64Encoder()],
+    ['stakeWeight', getU128Encoder()],
+    ['payer', getAddressEncoder()],
+    ['feeWallet', getAddressEncoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 96 })],
+  ]);
+}
+
+export function getVaultSnapshotDecoder(): Decoder<VaultSnapshot> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['vault', getAddressDecoder()],
+    ['ncn', getAddressDecoder()],
+    ['ncnEpoch', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['slotCreated', getU64Decoder()],
+    ['slotLastUpdated', getU64Decoder()],
+    ['vaultIndex', getU64Decoder()],
+    ['stMint', getAddressDecoder()],
+    ['operatorsRegistered', getU64Decoder()],
+    ['validOperatorDelegations', getU64Decoder()],
+    ['stakeWeight', getU128Decoder()],
+    ['payer', getAddressDecoder()],
+    ['feeWallet', getAddressDecoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 96 })],
+  ]);
+}
+
+export function getVaultSnapshotCodec(): Codec<
+  VaultSnapshotArgs,
+  VaultSnapshot
+> {
+  return combineCodec(getVaultSnapshotEncoder(), getVaultSnapshotDecoder());
+}
+
+export function decodeVaultSnapshot<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<VaultSnapshot, TAddress>;
+export function decodeVaultSnapshot<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<VaultSnapshot, TAddress>;
+export function decodeVaultSnapshot<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<VaultSnapshot, TAddress> | MaybeAccount<VaultSnapshot, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getVaultSnapshotDecoder()
+  );
+}
+
+export async function fetchVaultSnapshot<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<VaultSnapshot, TAddress>> {
+  const maybeAccount = await fetchMaybeVaultSnapshot(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultSnapshot<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<VaultSnapshot, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeVaultSnapshot(maybeAccount);
+}
+
+export async function fetchAllVaultSnapshot(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<VaultSnapshot>[]> {
+  const maybeAccounts = await fetchAllMaybeVaultSnapshot(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeVaultSnapshot(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<VaultSnapshot>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeVaultSnapshot(maybeAccount));
+}
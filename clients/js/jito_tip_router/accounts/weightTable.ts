@@ -47,6 +47,7 @@ export type WeightTable = {
   ncnEpoch: bigint;
   slotCreated: bigint;
   bump: number;
+  payer: Address;
   reserved: Array<number>;
   table: Array<WeightEntry>;
 };
@@ -57,6 +58,7 @@ export type WeightTableArgs = {
   ncnEpoch: number | bigint;
   slotCreated: number | bigint;
   bump: number;
+  payer: Address;
   reserved: Array<number>;
   table: Array<WeightEntryArgs>;
 };
@@ -68,7 +70,8 @@ export function getWeightTableEncoder(): Encoder<WeightTableArgs> {
     ['ncnEpoch', getU64Encoder()],
     ['slotCreated', getU64Encoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 128 })],
+    ['payer', getAddressEncoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 96 })],
     ['table', getArrayEncoder(getWeightEntryEncoder(), { size: 32 })],
   ]);
 }
@@ -80,7 +83,8 @@ export function getWeightTableDecoder(): Decoder<WeightTable> {
     ['ncnEpoch', getU64Decoder()],
     ['slotCreated', getU64Decoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 128 })],
+    ['payer', getAddressDecoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 96 })],
     ['table', getArrayDecoder(getWeightEntryDecoder(), { size: 32 })],
   ]);
 }
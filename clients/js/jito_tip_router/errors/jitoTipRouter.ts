@@ -98,10 +98,47 @@ export const JITO_TIP_ROUTER_ERROR__TIE_BREAKER_NOT_IN_PRIOR_VOTES = 0x2222; //
 export const JITO_TIP_ROUTER_ERROR__INVALID_MERKLE_PROOF = 0x2223; // 8739
 /** OperatorAdminInvalid: Operator admin needs to sign its vote */
 export const JITO_TIP_ROUTER_ERROR__OPERATOR_ADMIN_INVALID = 0x2224; // 8740
+/** MintAlreadyDeregistered: Mint is already deregistered */
+export const JITO_TIP_ROUTER_ERROR__MINT_ALREADY_DEREGISTERED = 0x2225; // 8741
+/** VaultIndexInvalid: Vault index is invalid */
+export const JITO_TIP_ROUTER_ERROR__VAULT_INDEX_INVALID = 0x2226; // 8742
+/** VaultStillActive: Vault is still active for the NCN */
+export const JITO_TIP_ROUTER_ERROR__VAULT_STILL_ACTIVE = 0x2227; // 8743
+/** InvalidExchangeRate: Invalid exchange rate */
+export const JITO_TIP_ROUTER_ERROR__INVALID_EXCHANGE_RATE = 0x2228; // 8744
+/** InvalidEpochsBeforeClose: Epochs before close must be greater than zero */
+export const JITO_TIP_ROUTER_ERROR__INVALID_EPOCHS_BEFORE_CLOSE = 0x2229; // 8745
+/** CannotCloseAccount: Account cannot be closed yet */
+export const JITO_TIP_ROUTER_ERROR__CANNOT_CLOSE_ACCOUNT = 0x222a; // 8746
+/** IncorrectPayer: Incorrect payer */
+export const JITO_TIP_ROUTER_ERROR__INCORRECT_PAYER = 0x222b; // 8747
+/** InvalidSnapshotWindow: Snapshot window must be greater than zero */
+export const JITO_TIP_ROUTER_ERROR__INVALID_SNAPSHOT_WINDOW = 0x222c; // 8748
+/** SnapshotWindowClosed: Snapshot window for this epoch has closed */
+export const JITO_TIP_ROUTER_ERROR__SNAPSHOT_WINDOW_CLOSED = 0x222d; // 8749
+/** SnapshotGracePeriodExpired: Grace period for past epoch snapshots has expired */
+export const JITO_TIP_ROUTER_ERROR__SNAPSHOT_GRACE_PERIOD_EXPIRED = 0x222e; // 8750
+/** InvalidExclusionReason: Invalid exclusion reason */
+export const JITO_TIP_ROUTER_ERROR__INVALID_EXCLUSION_REASON = 0x222f; // 8751
+/** IncorrectFeeWallet: Fee wallet does not match the epoch fees */
+export const JITO_TIP_ROUTER_ERROR__INCORRECT_FEE_WALLET = 0x2230; // 8752
+/** InvalidFeeActivationDelay: Fee activation delay must be greater than zero */
+export const JITO_TIP_ROUTER_ERROR__INVALID_FEE_ACTIVATION_DELAY = 0x2231; // 8753
+/** OperatorSnapshotMismatch: Operator snapshot does not belong to the epoch snapshot */
+export const JITO_TIP_ROUTER_ERROR__OPERATOR_SNAPSHOT_MISMATCH = 0x2232; // 8754
+/** VaultSnapshotMismatch: Vault snapshot does not belong to the epoch snapshot */
+export const JITO_TIP_ROUTER_ERROR__VAULT_SNAPSHOT_MISMATCH = 0x2233; // 8755
+/** NotEnoughComputeUnits: Not enough compute units to snapshot a vault delegation */
+export const JITO_TIP_ROUTER_ERROR__NOT_ENOUGH_COMPUTE_UNITS = 0x2234; // 8756
+/** RewardsNotDistributed: Rewards of the epoch have not been fully distributed */
+export const JITO_TIP_ROUTER_ERROR__REWARDS_NOT_DISTRIBUTED = 0x2235; // 8757
+/** InvalidTipDistributionEpoch: Tip distribution epoch is not part of the NCN epoch */
+export const JITO_TIP_ROUTER_ERROR__INVALID_TIP_DISTRIBUTION_EPOCH = 0x2236; // 8758
 
 export type JitoTipRouterError =
   | typeof JITO_TIP_ROUTER_ERROR__ARITHMETIC_OVERFLOW
   | typeof JITO_TIP_ROUTER_ERROR__BALLOT_TALLY_FULL
+  | typeof JITO_TIP_ROUTER_ERROR__CANNOT_CLOSE_ACCOUNT
   | typeof JITO_TIP_ROUTER_ERROR__CANNOT_CREATE_FUTURE_WEIGHT_TABLES
   | typeof JITO_TIP_ROUTER_ERROR__CAST_TO_IMPRECISE_NUMBER_ERROR
   | typeof JITO_TIP_ROUTER_ERROR__CONFIG_MINT_LIST_FULL
@@ -115,18 +152,32 @@ export type JitoTipRouterError =
   | typeof JITO_TIP_ROUTER_ERROR__EPOCH_SNAPSHOT_NOT_FINALIZED
   | typeof JITO_TIP_ROUTER_ERROR__FEE_CAP_EXCEEDED
   | typeof JITO_TIP_ROUTER_ERROR__INCORRECT_FEE_ADMIN
+  | typeof JITO_TIP_ROUTER_ERROR__INCORRECT_FEE_WALLET
   | typeof JITO_TIP_ROUTER_ERROR__INCORRECT_NCN
   | typeof JITO_TIP_ROUTER_ERROR__INCORRECT_NCN_ADMIN
+  | typeof JITO_TIP_ROUTER_ERROR__INCORRECT_PAYER
   | typeof JITO_TIP_ROUTER_ERROR__INCORRECT_WEIGHT_TABLE_ADMIN
+  | typeof JITO_TIP_ROUTER_ERROR__INVALID_EPOCHS_BEFORE_CLOSE
+  | typeof JITO_TIP_ROUTER_ERROR__INVALID_EXCHANGE_RATE
+  | typeof JITO_TIP_ROUTER_ERROR__INVALID_EXCLUSION_REASON
+  | typeof JITO_TIP_ROUTER_ERROR__INVALID_FEE_ACTIVATION_DELAY
   | typeof JITO_TIP_ROUTER_ERROR__INVALID_MERKLE_PROOF
   | typeof JITO_TIP_ROUTER_ERROR__INVALID_MINT_FOR_WEIGHT_TABLE
+  | typeof JITO_TIP_ROUTER_ERROR__INVALID_SNAPSHOT_WINDOW
+  | typeof JITO_TIP_ROUTER_ERROR__INVALID_TIP_DISTRIBUTION_EPOCH
+  | typeof JITO_TIP_ROUTER_ERROR__MINT_ALREADY_DEREGISTERED
   | typeof JITO_TIP_ROUTER_ERROR__MODULO_OVERFLOW
   | typeof JITO_TIP_ROUTER_ERROR__NEW_PRECISE_NUMBER_ERROR
   | typeof JITO_TIP_ROUTER_ERROR__NO_MINTS_IN_TABLE
   | typeof JITO_TIP_ROUTER_ERROR__NO_OPERATORS
+  | typeof JITO_TIP_ROUTER_ERROR__NOT_ENOUGH_COMPUTE_UNITS
   | typeof JITO_TIP_ROUTER_ERROR__OPERATOR_ADMIN_INVALID
   | typeof JITO_TIP_ROUTER_ERROR__OPERATOR_FINALIZED
+  | typeof JITO_TIP_ROUTER_ERROR__OPERATOR_SNAPSHOT_MISMATCH
   | typeof JITO_TIP_ROUTER_ERROR__OPERATOR_VOTES_FULL
+  | typeof JITO_TIP_ROUTER_ERROR__REWARDS_NOT_DISTRIBUTED
+  | typeof JITO_TIP_ROUTER_ERROR__SNAPSHOT_GRACE_PERIOD_EXPIRED
+  | typeof JITO_TIP_ROUTER_ERROR__SNAPSHOT_WINDOW_CLOSED
   | typeof JITO_TIP_ROUTER_ERROR__TIE_BREAKER_ADMIN_INVALID
   | typeof JITO_TIP_ROUTER_ERROR__TIE_BREAKER_NOT_IN_PRIOR_VOTES
   | typeof JITO_TIP_ROUTER_ERROR__TOO_MANY_MINTS_FOR_TABLE
@@ -134,7 +185,10 @@ export type JitoTipRouterError =
   | typeof JITO_TIP_ROUTER_ERROR__TRACKED_MINT_LIST_FULL
   | typeof JITO_TIP_ROUTER_ERROR__TRACKED_MINTS_LOCKED
   | typeof JITO_TIP_ROUTER_ERROR__VAULT_INDEX_ALREADY_IN_USE
+  | typeof JITO_TIP_ROUTER_ERROR__VAULT_INDEX_INVALID
   | typeof JITO_TIP_ROUTER_ERROR__VAULT_OPERATOR_DELEGATION_FINALIZED
+  | typeof JITO_TIP_ROUTER_ERROR__VAULT_SNAPSHOT_MISMATCH
+  | typeof JITO_TIP_ROUTER_ERROR__VAULT_STILL_ACTIVE
   | typeof JITO_TIP_ROUTER_ERROR__VOTING_NOT_FINALIZED
   | typeof JITO_TIP_ROUTER_ERROR__VOTING_NOT_VALID
   | typeof JITO_TIP_ROUTER_ERROR__WEIGHT_MINTS_DO_NOT_MATCH_LENGTH
@@ -148,6 +202,7 @@ if (process.env.NODE_ENV !== 'production') {
   jitoTipRouterErrorMessages = {
     [JITO_TIP_ROUTER_ERROR__ARITHMETIC_OVERFLOW]: `Overflow`,
     [JITO_TIP_ROUTER_ERROR__BALLOT_TALLY_FULL]: `Merkle root tally full`,
+    [JITO_TIP_ROUTER_ERROR__CANNOT_CLOSE_ACCOUNT]: `Account cannot be closed yet`,
     [JITO_TIP_ROUTER_ERROR__CANNOT_CREATE_FUTURE_WEIGHT_TABLES]: `Cannnot create future weight tables`,
     [JITO_TIP_ROUTER_ERROR__CAST_TO_IMPRECISE_NUMBER_ERROR]: `Cast to imprecise number error`,
     [JITO_TIP_ROUTER_ERROR__CONFIG_MINT_LIST_FULL]: `NCN config vaults are at capacity`,
@@ -161,18 +216,32 @@ if (process.env.NODE_ENV !== 'production') {
     [JITO_TIP_ROUTER_ERROR__EPOCH_SNAPSHOT_NOT_FINALIZED]: `Epoch snapshot not finalized`,
     [JITO_TIP_ROUTER_ERROR__FEE_CAP_EXCEEDED]: `Fee cap exceeded`,
     [JITO_TIP_ROUTER_ERROR__INCORRECT_FEE_ADMIN]: `Incorrect fee admin`,
+    [JITO_TIP_ROUTER_ERROR__INCORRECT_FEE_WALLET]: `Fee wallet does not match the epoch fees`,
     [JITO_TIP_ROUTER_ERROR__INCORRECT_NCN]: `Incorrect NCN`,
     [JITO_TIP_ROUTER_ERROR__INCORRECT_NCN_ADMIN]: `Incorrect NCN Admin`,
+    [JITO_TIP_ROUTER_ERROR__INCORRECT_PAYER]: `Incorrect payer`,
     [JITO_TIP_ROUTER_ERROR__INCORRECT_WEIGHT_TABLE_ADMIN]: `Incorrect weight table admin`,
+    [JITO_TIP_ROUTER_ERROR__INVALID_EPOCHS_BEFORE_CLOSE]: `Epochs before close must be greater than zero`,
+    [JITO_TIP_ROUTER_ERROR__INVALID_EXCHANGE_RATE]: `Invalid exchange rate`,
+    [JITO_TIP_ROUTER_ERROR__INVALID_EXCLUSION_REASON]: `Invalid exclusion reason`,
+    [JITO_TIP_ROUTER_ERROR__INVALID_FEE_ACTIVATION_DELAY]: `Fee activation delay must be greater than zero`,
     [JITO_TIP_ROUTER_ERROR__INVALID_MERKLE_PROOF]: `Invalid merkle proof`,
     [JITO_TIP_ROUTER_ERROR__INVALID_MINT_FOR_WEIGHT_TABLE]: `Invalid mint for weight table`,
+    [JITO_TIP_ROUTER_ERROR__INVALID_SNAPSHOT_WINDOW]: `Snapshot window must be greater than zero`,
+    [JITO_TIP_ROUTER_ERROR__INVALID_TIP_DISTRIBUTION_EPOCH]: `Tip distribution epoch is not part of the NCN epoch`,
+    [JITO_TIP_ROUTER_ERROR__MINT_ALREADY_DEREGISTERED]: `Mint is already deregistered`,
     [JITO_TIP_ROUTER_ERROR__MODULO_OVERFLOW]: `Modulo Overflow`,
     [JITO_TIP_ROUTER_ERROR__NEW_PRECISE_NUMBER_ERROR]: `New precise number error`,
     [JITO_TIP_ROUTER_ERROR__NO_MINTS_IN_TABLE]: `There are no mints in the table`,
     [JITO_TIP_ROUTER_ERROR__NO_OPERATORS]: `No operators in ncn`,
+    [JITO_TIP_ROUTER_ERROR__NOT_ENOUGH_COMPUTE_UNITS]: `Not enough compute units to snapshot a vault delegation`,
     [JITO_TIP_ROUTER_ERROR__OPERATOR_ADMIN_INVALID]: `Operator admin needs to sign its vote`,
     [JITO_TIP_ROUTER_ERROR__OPERATOR_FINALIZED]: `Operator is already finalized - should not happen`,
+    [JITO_TIP_ROUTER_ERROR__OPERATOR_SNAPSHOT_MISMATCH]: `Operator snapshot does not belong to the epoch snapshot`,
     [JITO_TIP_ROUTER_ERROR__OPERATOR_VOTES_FULL]: `Operator votes full`,
+    [JITO_TIP_ROUTER_ERROR__REWARDS_NOT_DISTRIBUTED]: `Rewards of the epoch have not been fully distributed`,
+    [JITO_TIP_ROUTER_ERROR__SNAPSHOT_GRACE_PERIOD_EXPIRED]: `Grace period for past epoch snapshots has expired`,
+    [JITO_TIP_ROUTER_ERROR__SNAPSHOT_WINDOW_CLOSED]: `Snapshot window for this epoch has closed`,
     [JITO_TIP_ROUTER_ERROR__TIE_BREAKER_ADMIN_INVALID]: `Tie breaker admin invalid`,
     [JITO_TIP_ROUTER_ERROR__TIE_BREAKER_NOT_IN_PRIOR_VOTES]: `Tie breaking ballot must be one of the prior votes`,
     [JITO_TIP_ROUTER_ERROR__TOO_MANY_MINTS_FOR_TABLE]: `Too many mints for table`,
@@ -180,7 +249,10 @@ if (process.env.NODE_ENV !== 'production') {
     [JITO_TIP_ROUTER_ERROR__TRACKED_MINT_LIST_FULL]: `Tracked mints are at capacity`,
     [JITO_TIP_ROUTER_ERROR__TRACKED_MINTS_LOCKED]: `Tracked mints are locked for the epoch`,
     [JITO_TIP_ROUTER_ERROR__VAULT_INDEX_ALREADY_IN_USE]: `Vault index already in use by a different mint`,
+    [JITO_TIP_ROUTER_ERROR__VAULT_INDEX_INVALID]: `Vault index is invalid`,
     [JITO_TIP_ROUTER_ERROR__VAULT_OPERATOR_DELEGATION_FINALIZED]: `Vault operator delegation is already finalized - should not happen`,
+    [JITO_TIP_ROUTER_ERROR__VAULT_SNAPSHOT_MISMATCH]: `Vault snapshot does not belong to the epoch snapshot`,
+    [JITO_TIP_ROUTER_ERROR__VAULT_STILL_ACTIVE]: `Vault is still active for the NCN`,
     [JITO_TIP_ROUTER_ERROR__VOTING_NOT_FINALIZED]: `Voting not finalized`,
     [JITO_TIP_ROUTER_ERROR__VOTING_NOT_VALID]: `Voting not valid, too many slots after consensus reached`,
     [JITO_TIP_ROUTER_ERROR__WEIGHT_MINTS_DO_NOT_MATCH_LENGTH]: `Weight mints do not match - length`,
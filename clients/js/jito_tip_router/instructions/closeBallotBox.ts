@@ -0,0 +1,266 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_TIP_ROUTER_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CLOSE_BALLOT_BOX_DISCRIMINATOR = 20;
+
+export function getCloseBallotBoxDiscriminatorBytes() {
+  return getU8Encoder().encode(CLOSE_BALLOT_BOX_DISCRIMINATOR);
+}
+
+export type CloseBallotBoxInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountNcnConfig extends string | IAccountMeta<string> = string,
+  TAccountRestakingConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountBallotBox extends string | IAccountMeta<string> = string,
+  TAccountEpochRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountRestakingProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcnConfig extends string
+        ? ReadonlyAccount<TAccountNcnConfig>
+        : TAccountNcnConfig,
+      TAccountRestakingConfig extends string
+        ? ReadonlyAccount<TAccountRestakingConfig>
+        : TAccountRestakingConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountBallotBox extends string
+        ? WritableAccount<TAccountBallotBox>
+        : TAccountBallotBox,
+      TAccountEpochRewardRouter extends string
+        ? ReadonlyAccount<TAccountEpochRewardRouter>
+        : TAccountEpochRewardRouter,
+      TAccountPayer extends string
+        ? WritableAccount<TAccountPayer>
+        : TAccountPayer,
+      TAccountRestakingProgram extends string
+        ? ReadonlyAccount<TAccountRestakingProgram>
+        : TAccountRestakingProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CloseBallotBoxInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type CloseBallotBoxInstructionDataArgs = { epoch: number | bigint };
+
+export function getCloseBallotBoxInstructionDataEncoder(): Encoder<CloseBallotBoxInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: CLOSE_BALLOT_BOX_DISCRIMINATOR })
+  );
+}
+
+export function getCloseBallotBoxInstructionDataDecoder(): Decoder<CloseBallotBoxInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getCloseBallotBoxInstructionDataCodec(): Codec<
+  CloseBallotBoxInstructionDataArgs,
+  CloseBallotBoxInstructionData
+> {
+  return combineCodec(
+    getCloseBallotBoxInstructionDataEncoder(),
+    getCloseBallotBoxInstructionDataDecoder()
+  );
+}
+
+export type CloseBallotBoxInput<
+  TAccountNcnConfig extends string = string,
+  TAccountRestakingConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountBallotBox extends string = string,
+  TAccountEpochRewardRouter extends string = string,
+  TAccountPayer extends string = string,
+  TAccountRestakingProgram extends string = string,
+> = {
+  ncnConfig: Address<TAccountNcnConfig>;
+  restakingConfig: Address<TAccountRestakingConfig>;
+  ncn: Address<TAccountNcn>;
+  ballotBox: Address<TAccountBallotBox>;
+  epochRewardRouter: Address<TAccountEpochRewardRouter>;
+  payer: Address<TAccountPayer>;
+  restakingProgram: Address<TAccountRestakingProgram>;
+  epoch: CloseBallotBoxInstructionDataArgs['epoch'];
+};
+
+export function getCloseBallotBoxInstruction<
+  TAccountNcnConfig extends string,
+  TAccountRestakingConfig extends string,
+  TAccountNcn extends string,
+  TAccountBallotBox extends string,
+  TAccountEpochRewardRouter extends string,
+  TAccountPayer extends string,
+  TAccountRestakingProgram extends string,
+  TProgramAddress extends Address = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+>(
+  input: CloseBallotBoxInput<
+    TAccountNcnConfig,
+    TAccountRestakingConfig,
+    TAccountNcn,
+    TAccountBallotBox,
+    TAccountEpochRewardRouter,
+    TAccountPayer,
+    TAccountRestakingProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): CloseBallotBoxInstruction<
+  TProgramAddress,
+  TAccountNcnConfig,
+  TAccountRestakingConfig,
+  TAccountNcn,
+  TAccountBallotBox,
+  TAccountEpochRewardRouter,
+  TAccountPayer,
+  TAccountRestakingProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_TIP_ROUTER_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncnConfig: { value: input.ncnConfig ?? null, isWritable: false },
+    restakingConfig: {
+      value: input.restakingConfig ?? null,
+      isWritable: false,
+    },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ballotBox: { value: input.ballotBox ?? null, isWritable: true },
+    epochRewardRouter: {
+      value: input.epochRewardRouter ?? null,
+      isWritable: false,
+    },
+    payer: { value: input.payer ?? null, isWritable: true },
+    restakingProgram: {
+      value: input.restakingProgram ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncnConfig),
+      getAccountMeta(accounts.restakingConfig),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ballotBox),
+      getAccountMeta(accounts.epochRewardRouter),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.restakingProgram),
+    ],
+    programAddress,
+    data: getCloseBallotBoxInstructionDataEncoder().encode(
+      args as CloseBallotBoxInstructionDataArgs
+    ),
+  } as CloseBallotBoxInstruction<
+    TProgramAddress,
+    TAccountNcnConfig,
+    TAccountRestakingConfig,
+    TAccountNcn,
+    TAccountBallotBox,
+    TAccountEpochRewardRouter,
+    TAccountPayer,
+    TAccountRestakingProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedCloseBallotBoxInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncnConfig: TAccountMetas[0];
+    restakingConfig: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    ballotBox: TAccountMetas[3];
+    epochRewardRouter: TAccountMetas[4];
+    payer: TAccountMetas[5];
+    restakingProgram: TAccountMetas[6];
+  };
+  data: CloseBallotBoxInstructionData;
+};
+
+export function parseCloseBallotBoxInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCloseBallotBoxInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncnConfig: getNextAccount(),
+      restakingConfig: getNextAccount(),
+      ncn: getNextAccount(),
+      ballotBox: getNextAccount(),
+      epochRewardRouter: getNextAccount(),
+      payer: getNextAccount(),
+      restakingProgram: getNextAccount(),
+    },
+    data: getCloseBallotBoxInstructionDataDecoder().decode(instruction.data),
+  };
+}
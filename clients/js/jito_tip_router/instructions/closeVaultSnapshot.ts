@@ -0,0 +1,296 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_TIP_ROUTER_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CLOSE_VAULT_SNAPSHOT_DISCRIMINATOR = 23;
+
+export function getCloseVaultSnapshotDiscriminatorBytes() {
+  return getU8Encoder().encode(CLOSE_VAULT_SNAPSHOT_DISCRIMINATOR);
+}
+
+export type CloseVaultSnapshotInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountNcnConfig extends string | IAccountMeta<string> = string,
+  TAccountRestakingConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountBallotBox extends string | IAccountMeta<string> = string,
+  TAccountEpochRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultSnapshot extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountRestakingProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcnConfig extends string
+        ? ReadonlyAccount<TAccountNcnConfig>
+        : TAccountNcnConfig,
+      TAccountRestakingConfig extends string
+        ? ReadonlyAccount<TAccountRestakingConfig>
+        : TAccountRestakingConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountBallotBox extends string
+        ? ReadonlyAccount<TAccountBallotBox>
+        : TAccountBallotBox,
+      TAccountEpochRewardRouter extends string
+        ? ReadonlyAccount<TAccountEpochRewardRouter>
+        : TAccountEpochRewardRouter,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultSnapshot extends string
+        ? WritableAccount<TAccountVaultSnapshot>
+        : TAccountVaultSnapshot,
+      TAccountPayer extends string
+        ? WritableAccount<TAccountPayer>
+        : TAccountPayer,
+      TAccountRestakingProgram extends string
+        ? ReadonlyAccount<TAccountRestakingProgram>
+        : TAccountRestakingProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CloseVaultSnapshotInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type CloseVaultSnapshotInstructionDataArgs = { epoch: number | bigint };
+
+export function getCloseVaultSnapshotInstructionDataEncoder(): Encoder<CloseVaultSnapshotInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: CLOSE_VAULT_SNAPSHOT_DISCRIMINATOR })
+  );
+}
+
+export function getCloseVaultSnapshotInstructionDataDecoder(): Decoder<CloseVaultSnapshotInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getCloseVaultSnapshotInstructionDataCodec(): Codec<
+  CloseVaultSnapshotInstructionDataArgs,
+  CloseVaultSnapshotInstructionData
+> {
+  return combineCodec(
+    getCloseVaultSnapshotInstructionDataEncoder(),
+    getCloseVaultSnapshotInstructionDataDecoder()
+  );
+}
+
+export type CloseVaultSnapshotInput<
+  TAccountNcnConfig extends string = string,
+  TAccountRestakingConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountBallotBox extends string = string,
+  TAccountEpochRewardRouter extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultSnapshot extends string = string,
+  TAccountPayer extends string = string,
+  TAccountRestakingProgram extends string = string,
+> = {
+  ncnConfig: Address<TAccountNcnConfig>;
+  restakingConfig: Address<TAccountRestakingConfig>;
+  ncn: Address<TAccountNcn>;
+  ballotBox: Address<TAccountBallotBox>;
+  epochRewardRouter: Address<TAccountEpochRewardRouter>;
+  vault: Address<TAccountVault>;
+  vaultSnapshot: Address<TAccountVaultSnapshot>;
+  payer: Address<TAccountPayer>;
+  restakingProgram: Address<TAccountRestakingProgram>;
+  epoch: CloseVaultSnapshotInstructionDataArgs['epoch'];
+};
+
+export function getCloseVaultSnapshotInstruction<
+  TAccountNcnConfig extends string,
+  TAccountRestakingConfig extends string,
+  TAccountNcn extends string,
+  TAccountBallotBox extends string,
+  TAccountEpochRewardRouter extends string,
+  TAccountVault extends string,
+  TAccountVaultSnapshot extends string,
+  TAccountPayer extends string,
+  TAccountRestakingProgram extends string,
+  TProgramAddress extends Address = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+>(
+  input: CloseVaultSnapshotInput<
+    TAccountNcnConfig,
+    TAccountRestakingConfig,
+    TAccountNcn,
+    TAccountBallotBox,
+    TAccountEpochRewardRouter,
+    TAccountVault,
+    TAccountVaultSnapshot,
+    TAccountPayer,
+    TAccountRestakingProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): CloseVaultSnapshotInstruction<
+  TProgramAddress,
+  TAccountNcnConfig,
+  TAccountRestakingConfig,
+  TAccountNcn,
+  TAccountBallotBox,
+  TAccountEpochRewardRouter,
+  TAccountVault,
+  TAccountVaultSnapshot,
+  TAccountPayer,
+  TAccountRestakingProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_TIP_ROUTER_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncnConfig: { value: input.ncnConfig ?? null, isWritable: false },
+    restakingConfig: {
+      value: input.restakingConfig ?? null,
+      isWritable: false,
+    },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ballotBox: { value: input.ballotBox ?? null, isWritable: false },
+    epochRewardRouter: {
+      value: input.epochRewardRouter ?? null,
+      isWritable: false,
+    },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultSnapshot: { value: input.vaultSnapshot ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    restakingProgram: {
+      value: input.restakingProgram ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncnConfig),
+      getAccountMeta(accounts.restakingConfig),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ballotBox),
+      getAccountMeta(accounts.epochRewardRouter),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultSnapshot),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.restakingProgram),
+    ],
+    programAddress,
+    data: getCloseVaultSnapshotInstructionDataEncoder().encode(
+      args as CloseVaultSnapshotInstructionDataArgs
+    ),
+  } as CloseVaultSnapshotInstruction<
+    TProgramAddress,
+    TAccountNcnConfig,
+    TAccountRestakingConfig,
+    TAccountNcn,
+    TAccountBallotBox,
+    TAccountEpochRewardRouter,
+    TAccountVault,
+    TAccountVaultSnapshot,
+    TAccountPayer,
+    TAccountRestakingProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedCloseVaultSnapshotInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncnConfig: TAccountMetas[0];
+    restakingConfig: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    ballotBox: TAccountMetas[3];
+    epochRewardRouter: TAccountMetas[4];
+    vault: TAccountMetas[5];
+    vaultSnapshot: TAccountMetas[6];
+    payer: TAccountMetas[7];
+    restakingProgram: TAccountMetas[8];
+  };
+  data: CloseVaultSnapshotInstructionData;
+};
+
+export function parseCloseVaultSnapshotInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCloseVaultSnapshotInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 9) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncnConfig: getNextAccount(),
+      restakingConfig: getNextAccount(),
+      ncn: getNextAccount(),
+      ballotBox: getNextAccount(),
+      epochRewardRouter: getNextAccount(),
+      vault: getNextAccount(),
+      vaultSnapshot: getNextAccount(),
+      payer: getNextAccount(),
+      restakingProgram: getNextAccount(),
+    },
+    data: getCloseVaultSnapshotInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
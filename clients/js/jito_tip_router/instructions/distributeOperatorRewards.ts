@@ -0,0 +1,290 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_TIP_ROUTER_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const DISTRIBUTE_OPERATOR_REWARDS_DISCRIMINATOR = 26;
+
+export function getDistributeOperatorRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(DISTRIBUTE_OPERATOR_REWARDS_DISCRIMINATOR);
+}
+
+export type DistributeOperatorRewardsInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountNcnConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountOperatorFeeWallet extends string | IAccountMeta<string> = string,
+  TAccountEpochSnapshot extends string | IAccountMeta<string> = string,
+  TAccountOperatorSnapshot extends string | IAccountMeta<string> = string,
+  TAccountEpochRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountRestakingProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcnConfig extends string
+        ? ReadonlyAccount<TAccountNcnConfig>
+        : TAccountNcnConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountOperatorFeeWallet extends string
+        ? WritableAccount<TAccountOperatorFeeWallet>
+        : TAccountOperatorFeeWallet,
+      TAccountEpochSnapshot extends string
+        ? ReadonlyAccount<TAccountEpochSnapshot>
+        : TAccountEpochSnapshot,
+      TAccountOperatorSnapshot extends string
+        ? WritableAccount<TAccountOperatorSnapshot>
+        : TAccountOperatorSnapshot,
+      TAccountEpochRewardRouter extends string
+        ? WritableAccount<TAccountEpochRewardRouter>
+        : TAccountEpochRewardRouter,
+      TAccountRestakingProgram extends string
+        ? ReadonlyAccount<TAccountRestakingProgram>
+        : TAccountRestakingProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type DistributeOperatorRewardsInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type DistributeOperatorRewardsInstructionDataArgs = {
+  epoch: number | bigint;
+};
+
+export function getDistributeOperatorRewardsInstructionDataEncoder(): Encoder<DistributeOperatorRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: DISTRIBUTE_OPERATOR_REWARDS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getDistributeOperatorRewardsInstructionDataDecoder(): Decoder<DistributeOperatorRewardsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getDistributeOperatorRewardsInstructionDataCodec(): Codec<
+  DistributeOperatorRewardsInstructionDataArgs,
+  DistributeOperatorRewardsInstructionData
+> {
+  return combineCodec(
+    getDistributeOperatorRewardsInstructionDataEncoder(),
+    getDistributeOperatorRewardsInstructionDataDecoder()
+  );
+}
+
+export type DistributeOperatorRewardsInput<
+  TAccountNcnConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountOperatorFeeWallet extends string = string,
+  TAccountEpochSnapshot extends string = string,
+  TAccountOperatorSnapshot extends string = string,
+  TAccountEpochRewardRouter extends string = string,
+  TAccountRestakingProgram extends string = string,
+> = {
+  ncnConfig: Address<TAccountNcnConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  operatorFeeWallet: Address<TAccountOperatorFeeWallet>;
+  epochSnapshot: Address<TAccountEpochSnapshot>;
+  operatorSnapshot: Address<TAccountOperatorSnapshot>;
+  epochRewardRouter: Address<TAccountEpochRewardRouter>;
+  restakingProgram: Address<TAccountRestakingProgram>;
+  epoch: DistributeOperatorRewardsInstructionDataArgs['epoch'];
+};
+
+export function getDistributeOperatorRewardsInstruction<
+  TAccountNcnConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountOperatorFeeWallet extends string,
+  TAccountEpochSnapshot extends string,
+  TAccountOperatorSnapshot extends string,
+  TAccountEpochRewardRouter extends string,
+  TAccountRestakingProgram extends string,
+  TProgramAddress extends Address = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+>(
+  input: DistributeOperatorRewardsInput<
+    TAccountNcnConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountOperatorFeeWallet,
+    TAccountEpochSnapshot,
+    TAccountOperatorSnapshot,
+    TAccountEpochRewardRouter,
+    TAccountRestakingProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): DistributeOperatorRewardsInstruction<
+  TProgramAddress,
+  TAccountNcnConfig,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountOperatorFeeWallet,
+  TAccountEpochSnapshot,
+  TAccountOperatorSnapshot,
+  TAccountEpochRewardRouter,
+  TAccountRestakingProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_TIP_ROUTER_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncnConfig: { value: input.ncnConfig ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    operatorFeeWallet: {
+      value: input.operatorFeeWallet ?? null,
+      isWritable: true,
+    },
+    epochSnapshot: { value: input.epochSnapshot ?? null, isWritable: false },
+    operatorSnapshot: {
+      value: input.operatorSnapshot ?? null,
+      isWritable: true,
+    },
+    epochRewardRouter: {
+      value: input.epochRewardRouter ?? null,
+      isWritable: true,
+    },
+    restakingProgram: {
+      value: input.restakingProgram ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncnConfig),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.operatorFeeWallet),
+      getAccountMeta(accounts.epochSnapshot),
+      getAccountMeta(accounts.operatorSnapshot),
+      getAccountMeta(accounts.epochRewardRouter),
+      getAccountMeta(accounts.restakingProgram),
+    ],
+    programAddress,
+    data: getDistributeOperatorRewardsInstructionDataEncoder().encode(
+      args as DistributeOperatorRewardsInstructionDataArgs
+    ),
+  } as DistributeOperatorRewardsInstruction<
+    TProgramAddress,
+    TAccountNcnConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountOperatorFeeWallet,
+    TAccountEpochSnapshot,
+    TAccountOperatorSnapshot,
+    TAccountEpochRewardRouter,
+    TAccountRestakingProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedDistributeOperatorRewardsInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncnConfig: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    operatorFeeWallet: TAccountMetas[3];
+    epochSnapshot: TAccountMetas[4];
+    operatorSnapshot: TAccountMetas[5];
+    epochRewardRouter: TAccountMetas[6];
+    restakingProgram: TAccountMetas[7];
+  };
+  data: DistributeOperatorRewardsInstructionData;
+};
+
+export function parseDistributeOperatorRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedDistributeOperatorRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncnConfig: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      operatorFeeWallet: getNextAccount(),
+      epochSnapshot: getNextAccount(),
+      operatorSnapshot: getNextAccount(),
+      epochRewardRouter: getNextAccount(),
+      restakingProgram: getNextAccount(),
+    },
+    data: getDistributeOperatorRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
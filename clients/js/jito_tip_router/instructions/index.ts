@@ -8,15 +8,30 @@
 
 export * from './adminUpdateWeightTable';
 export * from './castVote';
+export * from './closeBallotBox';
+export * from './closeEpochRewardRouter';
+export * from './closeEpochSnapshot';
+export * from './closeOperatorSnapshot';
+export * from './closeVaultSnapshot';
+export * from './closeWeightTable';
+export * from './deregisterMint';
+export * from './distributeOperatorRewards';
 export * from './initializeBallotBox';
+export * from './initializeEpochRewardRouter';
 export * from './initializeEpochSnapshot';
 export * from './initializeNCNConfig';
 export * from './initializeOperatorSnapshot';
 export * from './initializeTrackedMints';
+export * from './initializeVaultSnapshot';
 export * from './initializeWeightTable';
+export * from './migrateNcnConfig';
+export * from './reallocTrackedMints';
 export * from './registerMint';
+export * from './routeNcnRewards';
 export * from './setConfigFees';
 export * from './setMerkleRoot';
 export * from './setNewAdmin';
+export * from './setParameters';
 export * from './setTieBreaker';
 export * from './snapshotVaultOperatorDelegation';
+export * from './snapshotVaultOperatorDelegations';
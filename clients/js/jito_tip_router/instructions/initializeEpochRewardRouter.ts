@@ -0,0 +1,251 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { JITO_TIP_ROUTER_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_EPOCH_REWARD_ROUTER_DISCRIMINATOR = 24;
+
+export function getInitializeEpochRewardRouterDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_EPOCH_REWARD_ROUTER_DISCRIMINATOR);
+}
+
+export type InitializeEpochRewardRouterInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountNcnConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountEpochRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcnConfig extends string
+        ? ReadonlyAccount<TAccountNcnConfig>
+        : TAccountNcnConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountEpochRewardRouter extends string
+        ? WritableAccount<TAccountEpochRewardRouter>
+        : TAccountEpochRewardRouter,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeEpochRewardRouterInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type InitializeEpochRewardRouterInstructionDataArgs = {
+  epoch: number | bigint;
+};
+
+export function getInitializeEpochRewardRouterInstructionDataEncoder(): Encoder<InitializeEpochRewardRouterInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_EPOCH_REWARD_ROUTER_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeEpochRewardRouterInstructionDataDecoder(): Decoder<InitializeEpochRewardRouterInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getInitializeEpochRewardRouterInstructionDataCodec(): Codec<
+  InitializeEpochRewardRouterInstructionDataArgs,
+  InitializeEpochRewardRouterInstructionData
+> {
+  return combineCodec(
+    getInitializeEpochRewardRouterInstructionDataEncoder(),
+    getInitializeEpochRewardRouterInstructionDataDecoder()
+  );
+}
+
+export type InitializeEpochRewardRouterInput<
+  TAccountNcnConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountEpochRewardRouter extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  ncnConfig: Address<TAccountNcnConfig>;
+  ncn: Address<TAccountNcn>;
+  epochRewardRouter: Address<TAccountEpochRewardRouter>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  epoch: InitializeEpochRewardRouterInstructionDataArgs['epoch'];
+};
+
+export function getInitializeEpochRewardRouterInstruction<
+  TAccountNcnConfig extends string,
+  TAccountNcn extends string,
+  TAccountEpochRewardRouter extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+>(
+  input: InitializeEpochRewardRouterInput<
+    TAccountNcnConfig,
+    TAccountNcn,
+    TAccountEpochRewardRouter,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): InitializeEpochRewardRouterInstruction<
+  TProgramAddress,
+  TAccountNcnConfig,
+  TAccountNcn,
+  TAccountEpochRewardRouter,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_TIP_ROUTER_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncnConfig: { value: input.ncnConfig ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    epochRewardRouter: {
+      value: input.epochRewardRouter ?? null,
+      isWritable: true,
+    },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncnConfig),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.epochRewardRouter),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeEpochRewardRouterInstructionDataEncoder().encode(
+      args as InitializeEpochRewardRouterInstructionDataArgs
+    ),
+  } as InitializeEpochRewardRouterInstruction<
+    TProgramAddress,
+    TAccountNcnConfig,
+    TAccountNcn,
+    TAccountEpochRewardRouter,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeEpochRewardRouterInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncnConfig: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    epochRewardRouter: TAccountMetas[2];
+    payer: TAccountMetas[3];
+    systemProgram: TAccountMetas[4];
+  };
+  data: InitializeEpochRewardRouterInstructionData;
+};
+
+export function parseInitializeEpochRewardRouterInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeEpochRewardRouterInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncnConfig: getNextAccount(),
+      ncn: getNextAccount(),
+      epochRewardRouter: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeEpochRewardRouterInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
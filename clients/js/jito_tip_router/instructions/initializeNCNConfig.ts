@@ -44,7 +44,8 @@ export type InitializeNCNConfigInstruction<
   TAccountNcnConfig extends string | IAccountMeta<string> = string,
   TAccountNcn extends string | IAccountMeta<string> = string,
   TAccountNcnAdmin extends string | IAccountMeta<string> = string,
-  TAccountFeeWallet extends string | IAccountMeta<string> = string,
+  TAccountDaoFeeWallet extends string | IAccountMeta<string> = string,
+  TAccountNcnFeeWallet extends string | IAccountMeta<string> = string,
   TAccountTieBreakerAdmin extends string | IAccountMeta<string> = string,
   TAccountRestakingProgram extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
@@ -66,9 +67,12 @@ export type InitializeNCNConfigInstruction<
         ? ReadonlySignerAccount<TAccountNcnAdmin> &
             IAccountSignerMeta<TAccountNcnAdmin>
         : TAccountNcnAdmin,
-      TAccountFeeWallet extends string
-        ? ReadonlyAccount<TAccountFeeWallet>
-        : TAccountFeeWallet,
+      TAccountDaoFeeWallet extends string
+        ? ReadonlyAccount<TAccountDaoFeeWallet>
+        : TAccountDaoFeeWallet,
+      TAccountNcnFeeWallet extends string
+        ? ReadonlyAccount<TAccountNcnFeeWallet>
+        : TAccountNcnFeeWallet,
       TAccountTieBreakerAdmin extends string
         ? ReadonlyAccount<TAccountTieBreakerAdmin>
         : TAccountTieBreakerAdmin,
@@ -134,7 +138,8 @@ export type InitializeNCNConfigInput<
   TAccountNcnConfig extends string = string,
   TAccountNcn extends string = string,
   TAccountNcnAdmin extends string = string,
-  TAccountFeeWallet extends string = string,
+  TAccountDaoFeeWallet extends string = string,
+  TAccountNcnFeeWallet extends string = string,
   TAccountTieBreakerAdmin extends string = string,
   TAccountRestakingProgram extends string = string,
   TAccountSystemProgram extends string = string,
@@ -143,7 +148,8 @@ export type InitializeNCNConfigInput<
   ncnConfig: Address<TAccountNcnConfig>;
   ncn: Address<TAccountNcn>;
   ncnAdmin: TransactionSigner<TAccountNcnAdmin>;
-  feeWallet: Address<TAccountFeeWallet>;
+  daoFeeWallet: Address<TAccountDaoFeeWallet>;
+  ncnFeeWallet: Address<TAccountNcnFeeWallet>;
   tieBreakerAdmin: Address<TAccountTieBreakerAdmin>;
   restakingProgram: Address<TAccountRestakingProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -157,7 +163,8 @@ export function getInitializeNCNConfigInstruction<
   TAccountNcnConfig extends string,
   TAccountNcn extends string,
   TAccountNcnAdmin extends string,
-  TAccountFeeWallet extends string,
+  TAccountDaoFeeWallet extends string,
+  TAccountNcnFeeWallet extends string,
   TAccountTieBreakerAdmin extends string,
   TAccountRestakingProgram extends string,
   TAccountSystemProgram extends string,
@@ -168,7 +175,8 @@ export function getInitializeNCNConfigInstruction<
     TAccountNcnConfig,
     TAccountNcn,
     TAccountNcnAdmin,
-    TAccountFeeWallet,
+    TAccountDaoFeeWallet,
+    TAccountNcnFeeWallet,
     TAccountTieBreakerAdmin,
     TAccountRestakingProgram,
     TAccountSystemProgram
@@ -180,7 +188,8 @@ export function getInitializeNCNConfigInstruction<
   TAccountNcnConfig,
   TAccountNcn,
   TAccountNcnAdmin,
-  TAccountFeeWallet,
+  TAccountDaoFeeWallet,
+  TAccountNcnFeeWallet,
   TAccountTieBreakerAdmin,
   TAccountRestakingProgram,
   TAccountSystemProgram
@@ -198,7 +207,8 @@ export function getInitializeNCNConfigInstruction<
     ncnConfig: { value: input.ncnConfig ?? null, isWritable: true },
     ncn: { value: input.ncn ?? null, isWritable: false },
     ncnAdmin: { value: input.ncnAdmin ?? null, isWritable: false },
-    feeWallet: { value: input.feeWallet ?? null, isWritable: false },
+    daoFeeWallet: { value: input.daoFeeWallet ?? null, isWritable: false },
+    ncnFeeWallet: { value: input.ncnFeeWallet ?? null, isWritable: false },
     tieBreakerAdmin: {
       value: input.tieBreakerAdmin ?? null,
       isWritable: false,
@@ -230,7 +240,8 @@ export function getInitializeNCNConfigInstruction<
       getAccountMeta(accounts.ncnConfig),
       getAccountMeta(accounts.ncn),
       getAccountMeta(accounts.ncnAdmin),
-      getAccountMeta(accounts.feeWallet),
+      getAccountMeta(accounts.daoFeeWallet),
+      getAccountMeta(accounts.ncnFeeWallet),
       getAccountMeta(accounts.tieBreakerAdmin),
       getAccountMeta(accounts.restakingProgram),
       getAccountMeta(accounts.systemProgram),
@@ -245,7 +256,8 @@ export function getInitializeNCNConfigInstruction<
     TAccountNcnConfig,
     TAccountNcn,
     TAccountNcnAdmin,
-    TAccountFeeWallet,
+    TAccountDaoFeeWallet,
+    TAccountNcnFeeWallet,
     TAccountTieBreakerAdmin,
     TAccountRestakingProgram,
     TAccountSystemProgram
@@ -264,10 +276,11 @@ export type ParsedInitializeNCNConfigInstruction<
     ncnConfig: TAccountMetas[1];
     ncn: TAccountMetas[2];
     ncnAdmin: TAccountMetas[3];
-    feeWallet: TAccountMetas[4];
-    tieBreakerAdmin: TAccountMetas[5];
-    restakingProgram: TAccountMetas[6];
-    systemProgram: TAccountMetas[7];
+    daoFeeWallet: TAccountMetas[4];
+    ncnFeeWallet: TAccountMetas[5];
+    tieBreakerAdmin: TAccountMetas[6];
+    restakingProgram: TAccountMetas[7];
+    systemProgram: TAccountMetas[8];
   };
   data: InitializeNCNConfigInstructionData;
 };
@@ -280,7 +293,7 @@ export function parseInitializeNCNConfigInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedInitializeNCNConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -297,7 +310,8 @@ export function parseInitializeNCNConfigInstruction<
       ncnConfig: getNextAccount(),
       ncn: getNextAccount(),
       ncnAdmin: getNextAccount(),
-      feeWallet: getNextAccount(),
+      daoFeeWallet: getNextAccount(),
+      ncnFeeWallet: getNextAccount(),
       tieBreakerAdmin: getNextAccount(),
       restakingProgram: getNextAccount(),
       systemProgram: getNextAccount(),
@@ -0,0 +1,328 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { JITO_TIP_ROUTER_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_VAULT_SNAPSHOT_DISCRIMINATOR = 22;
+
+export function getInitializeVaultSnapshotDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_VAULT_SNAPSHOT_DISCRIMINATOR);
+}
+
+export type InitializeVaultSnapshotInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountNcnConfig extends string | IAccountMeta<string> = string,
+  TAccountRestakingConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountEpochSnapshot extends string | IAccountMeta<string> = string,
+  TAccountVaultSnapshot extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountVaultProgram extends string | IAccountMeta<string> = string,
+  TAccountRestakingProgram extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcnConfig extends string
+        ? ReadonlyAccount<TAccountNcnConfig>
+        : TAccountNcnConfig,
+      TAccountRestakingConfig extends string
+        ? ReadonlyAccount<TAccountRestakingConfig>
+        : TAccountRestakingConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountEpochSnapshot extends string
+        ? ReadonlyAccount<TAccountEpochSnapshot>
+        : TAccountEpochSnapshot,
+      TAccountVaultSnapshot extends string
+        ? WritableAccount<TAccountVaultSnapshot>
+        : TAccountVaultSnapshot,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountVaultProgram extends string
+        ? ReadonlyAccount<TAccountVaultProgram>
+        : TAccountVaultProgram,
+      TAccountRestakingProgram extends string
+        ? ReadonlyAccount<TAccountRestakingProgram>
+        : TAccountRestakingProgram,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeVaultSnapshotInstructionData = {
+  discriminator: number;
+  firstSlotOfNcnEpoch: Option<bigint>;
+};
+
+export type InitializeVaultSnapshotInstructionDataArgs = {
+  firstSlotOfNcnEpoch: OptionOrNullable<number | bigint>;
+};
+
+export function getInitializeVaultSnapshotInstructionDataEncoder(): Encoder<InitializeVaultSnapshotInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['firstSlotOfNcnEpoch', getOptionEncoder(getU64Encoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_VAULT_SNAPSHOT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeVaultSnapshotInstructionDataDecoder(): Decoder<InitializeVaultSnapshotInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['firstSlotOfNcnEpoch', getOptionDecoder(getU64Decoder())],
+  ]);
+}
+
+export function getInitializeVaultSnapshotInstructionDataCodec(): Codec<
+  InitializeVaultSnapshotInstructionDataArgs,
+  InitializeVaultSnapshotInstructionData
+> {
+  return combineCodec(
+    getInitializeVaultSnapshotInstructionDataEncoder(),
+    getInitializeVaultSnapshotInstructionDataDecoder()
+  );
+}
+
+export type InitializeVaultSnapshotInput<
+  TAccountNcnConfig extends string = string,
+  TAccountRestakingConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVault extends string = string,
+  TAccountEpochSnapshot extends string = string,
+  TAccountVaultSnapshot extends string = string,
+  TAccountPayer extends string = string,
+  TAccountVaultProgram extends string = string,
+  TAccountRestakingProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  ncnConfig: Address<TAccountNcnConfig>;
+  restakingConfig: Address<TAccountRestakingConfig>;
+  ncn: Address<TAccountNcn>;
+  vault: Address<TAccountVault>;
+  epochSnapshot: Address<TAccountEpochSnapshot>;
+  vaultSnapshot: Address<TAccountVaultSnapshot>;
+  payer: TransactionSigner<TAccountPayer>;
+  vaultProgram: Address<TAccountVaultProgram>;
+  restakingProgram: Address<TAccountRestakingProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  firstSlotOfNcnEpoch: InitializeVaultSnapshotInstructionDataArgs['firstSlotOfNcnEpoch'];
+};
+
+export function getInitializeVaultSnapshotInstruction<
+  TAccountNcnConfig extends string,
+  TAccountRestakingConfig extends string,
+  TAccountNcn extends string,
+  TAccountVault extends string,
+  TAccountEpochSnapshot extends string,
+  TAccountVaultSnapshot extends string,
+  TAccountPayer extends string,
+  TAccountVaultProgram extends string,
+  TAccountRestakingProgram extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+>(
+  input: InitializeVaultSnapshotInput<
+    TAccountNcnConfig,
+    TAccountRestakingConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountEpochSnapshot,
+    TAccountVaultSnapshot,
+    TAccountPayer,
+    TAccountVaultProgram,
+    TAccountRestakingProgram,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): InitializeVaultSnapshotInstruction<
+  TProgramAddress,
+  TAccountNcnConfig,
+  TAccountRestakingConfig,
+  TAccountNcn,
+  TAccountVault,
+  TAccountEpochSnapshot,
+  TAccountVaultSnapshot,
+  TAccountPayer,
+  TAccountVaultProgram,
+  TAccountRestakingProgram,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_TIP_ROUTER_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncnConfig: { value: input.ncnConfig ?? null, isWritable: false },
+    restakingConfig: {
+      value: input.restakingConfig ?? null,
+      isWritable: false,
+    },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    epochSnapshot: { value: input.epochSnapshot ?? null, isWritable: false },
+    vaultSnapshot: { value: input.vaultSnapshot ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    vaultProgram: { value: input.vaultProgram ?? null, isWritable: false },
+    restakingProgram: {
+      value: input.restakingProgram ?? null,
+      isWritable: false,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncnConfig),
+      getAccountMeta(accounts.restakingConfig),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.epochSnapshot),
+      getAccountMeta(accounts.vaultSnapshot),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.vaultProgram),
+      getAccountMeta(accounts.restakingProgram),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeVaultSnapshotInstructionDataEncoder().encode(
+      args as InitializeVaultSnapshotInstructionDataArgs
+    ),
+  } as InitializeVaultSnapshotInstruction<
+    TProgramAddress,
+    TAccountNcnConfig,
+    TAccountRestakingConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountEpochSnapshot,
+    TAccountVaultSnapshot,
+    TAccountPayer,
+    TAccountVaultProgram,
+    TAccountRestakingProgram,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeVaultSnapshotInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncnConfig: TAccountMetas[0];
+    restakingConfig: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    vault: TAccountMetas[3];
+    epochSnapshot: TAccountMetas[4];
+    vaultSnapshot: TAccountMetas[5];
+    payer: TAccountMetas[6];
+    vaultProgram: TAccountMetas[7];
+    restakingProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
+  };
+  data: InitializeVaultSnapshotInstructionData;
+};
+
+export function parseInitializeVaultSnapshotInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeVaultSnapshotInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 10) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncnConfig: getNextAccount(),
+      restakingConfig: getNextAccount(),
+      ncn: getNextAccount(),
+      vault: getNextAccount(),
+      epochSnapshot: getNextAccount(),
+      vaultSnapshot: getNextAccount(),
+      payer: getNextAccount(),
+      vaultProgram: getNextAccount(),
+      restakingProgram: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeVaultSnapshotInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
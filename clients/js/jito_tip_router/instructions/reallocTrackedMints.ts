@@ -0,0 +1,229 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { JITO_TIP_ROUTER_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const REALLOC_TRACKED_MINTS_DISCRIMINATOR = 15;
+
+export function getReallocTrackedMintsDiscriminatorBytes() {
+  return getU8Encoder().encode(REALLOC_TRACKED_MINTS_DISCRIMINATOR);
+}
+
+export type ReallocTrackedMintsInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountNcnConfig extends string | IAccountMeta<string> = string,
+  TAccountTrackedMints extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcnConfig extends string
+        ? ReadonlyAccount<TAccountNcnConfig>
+        : TAccountNcnConfig,
+      TAccountTrackedMints extends string
+        ? WritableAccount<TAccountTrackedMints>
+        : TAccountTrackedMints,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ReallocTrackedMintsInstructionData = { discriminator: number };
+
+export type ReallocTrackedMintsInstructionDataArgs = {};
+
+export function getReallocTrackedMintsInstructionDataEncoder(): Encoder<ReallocTrackedMintsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: REALLOC_TRACKED_MINTS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getReallocTrackedMintsInstructionDataDecoder(): Decoder<ReallocTrackedMintsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getReallocTrackedMintsInstructionDataCodec(): Codec<
+  ReallocTrackedMintsInstructionDataArgs,
+  ReallocTrackedMintsInstructionData
+> {
+  return combineCodec(
+    getReallocTrackedMintsInstructionDataEncoder(),
+    getReallocTrackedMintsInstructionDataDecoder()
+  );
+}
+
+export type ReallocTrackedMintsInput<
+  TAccountNcnConfig extends string = string,
+  TAccountTrackedMints extends string = string,
+  TAccountNcn extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  ncnConfig: Address<TAccountNcnConfig>;
+  trackedMints: Address<TAccountTrackedMints>;
+  ncn: Address<TAccountNcn>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getReallocTrackedMintsInstruction<
+  TAccountNcnConfig extends string,
+  TAccountTrackedMints extends string,
+  TAccountNcn extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+>(
+  input: ReallocTrackedMintsInput<
+    TAccountNcnConfig,
+    TAccountTrackedMints,
+    TAccountNcn,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): ReallocTrackedMintsInstruction<
+  TProgramAddress,
+  TAccountNcnConfig,
+  TAccountTrackedMints,
+  TAccountNcn,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_TIP_ROUTER_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncnConfig: { value: input.ncnConfig ?? null, isWritable: false },
+    trackedMints: { value: input.trackedMints ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncnConfig),
+      getAccountMeta(accounts.trackedMints),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getReallocTrackedMintsInstructionDataEncoder().encode({}),
+  } as ReallocTrackedMintsInstruction<
+    TProgramAddress,
+    TAccountNcnConfig,
+    TAccountTrackedMints,
+    TAccountNcn,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedReallocTrackedMintsInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncnConfig: TAccountMetas[0];
+    trackedMints: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    payer: TAccountMetas[3];
+    systemProgram: TAccountMetas[4];
+  };
+  data: ReallocTrackedMintsInstructionData;
+};
+
+export function parseReallocTrackedMintsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedReallocTrackedMintsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncnConfig: getNextAccount(),
+      trackedMints: getNextAccount(),
+      ncn: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getReallocTrackedMintsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,246 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_TIP_ROUTER_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ROUTE_NCN_REWARDS_DISCRIMINATOR = 25;
+
+export function getRouteNcnRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(ROUTE_NCN_REWARDS_DISCRIMINATOR);
+}
+
+export type RouteNcnRewardsInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountNcnConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountEpochSnapshot extends string | IAccountMeta<string> = string,
+  TAccountEpochRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountDaoFeeWallet extends string | IAccountMeta<string> = string,
+  TAccountNcnFeeWallet extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcnConfig extends string
+        ? ReadonlyAccount<TAccountNcnConfig>
+        : TAccountNcnConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountEpochSnapshot extends string
+        ? ReadonlyAccount<TAccountEpochSnapshot>
+        : TAccountEpochSnapshot,
+      TAccountEpochRewardRouter extends string
+        ? WritableAccount<TAccountEpochRewardRouter>
+        : TAccountEpochRewardRouter,
+      TAccountDaoFeeWallet extends string
+        ? WritableAccount<TAccountDaoFeeWallet>
+        : TAccountDaoFeeWallet,
+      TAccountNcnFeeWallet extends string
+        ? WritableAccount<TAccountNcnFeeWallet>
+        : TAccountNcnFeeWallet,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type RouteNcnRewardsInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type RouteNcnRewardsInstructionDataArgs = { epoch: number | bigint };
+
+export function getRouteNcnRewardsInstructionDataEncoder(): Encoder<RouteNcnRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: ROUTE_NCN_REWARDS_DISCRIMINATOR })
+  );
+}
+
+export function getRouteNcnRewardsInstructionDataDecoder(): Decoder<RouteNcnRewardsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getRouteNcnRewardsInstructionDataCodec(): Codec<
+  RouteNcnRewardsInstructionDataArgs,
+  RouteNcnRewardsInstructionData
+> {
+  return combineCodec(
+    getRouteNcnRewardsInstructionDataEncoder(),
+    getRouteNcnRewardsInstructionDataDecoder()
+  );
+}
+
+export type RouteNcnRewardsInput<
+  TAccountNcnConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountEpochSnapshot extends string = string,
+  TAccountEpochRewardRouter extends string = string,
+  TAccountDaoFeeWallet extends string = string,
+  TAccountNcnFeeWallet extends string = string,
+> = {
+  ncnConfig: Address<TAccountNcnConfig>;
+  ncn: Address<TAccountNcn>;
+  epochSnapshot: Address<TAccountEpochSnapshot>;
+  epochRewardRouter: Address<TAccountEpochRewardRouter>;
+  daoFeeWallet: Address<TAccountDaoFeeWallet>;
+  ncnFeeWallet: Address<TAccountNcnFeeWallet>;
+  epoch: RouteNcnRewardsInstructionDataArgs['epoch'];
+};
+
+export function getRouteNcnRewardsInstruction<
+  TAccountNcnConfig extends string,
+  TAccountNcn extends string,
+  TAccountEpochSnapshot extends string,
+  TAccountEpochRewardRouter extends string,
+  TAccountDaoFeeWallet extends string,
+  TAccountNcnFeeWallet extends string,
+  TProgramAddress extends Address = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+>(
+  input: RouteNcnRewardsInput<
+    TAccountNcnConfig,
+    TAccountNcn,
+    TAccountEpochSnapshot,
+    TAccountEpochRewardRouter,
+    TAccountDaoFeeWallet,
+    TAccountNcnFeeWallet
+  >,
+  config?: { programAddress?: TProgramAddress }
+): RouteNcnRewardsInstruction<
+  TProgramAddress,
+  TAccountNcnConfig,
+  TAccountNcn,
+  TAccountEpochSnapshot,
+  TAccountEpochRewardRouter,
+  TAccountDaoFeeWallet,
+  TAccountNcnFeeWallet
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_TIP_ROUTER_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncnConfig: { value: input.ncnConfig ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    epochSnapshot: { value: input.epochSnapshot ?? null, isWritable: false },
+    epochRewardRouter: {
+      value: input.epochRewardRouter ?? null,
+      isWritable: true,
+    },
+    daoFeeWallet: { value: input.daoFeeWallet ?? null, isWritable: true },
+    ncnFeeWallet: { value: input.ncnFeeWallet ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncnConfig),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.epochSnapshot),
+      getAccountMeta(accounts.epochRewardRouter),
+      getAccountMeta(accounts.daoFeeWallet),
+      getAccountMeta(accounts.ncnFeeWallet),
+    ],
+    programAddress,
+    data: getRouteNcnRewardsInstructionDataEncoder().encode(
+      args as RouteNcnRewardsInstructionDataArgs
+    ),
+  } as RouteNcnRewardsInstruction<
+    TProgramAddress,
+    TAccountNcnConfig,
+    TAccountNcn,
+    TAccountEpochSnapshot,
+    TAccountEpochRewardRouter,
+    TAccountDaoFeeWallet,
+    TAccountNcnFeeWallet
+  >;
+
+  return instruction;
+}
+
+export type ParsedRouteNcnRewardsInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncnConfig: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    epochSnapshot: TAccountMetas[2];
+    epochRewardRouter: TAccountMetas[3];
+    daoFeeWallet: TAccountMetas[4];
+    ncnFeeWallet: TAccountMetas[5];
+  };
+  data: RouteNcnRewardsInstructionData;
+};
+
+export function parseRouteNcnRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedRouteNcnRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncnConfig: getNextAccount(),
+      ncn: getNextAccount(),
+      epochSnapshot: getNextAccount(),
+      epochRewardRouter: getNextAccount(),
+      daoFeeWallet: getNextAccount(),
+      ncnFeeWallet: getNextAccount(),
+    },
+    data: getRouteNcnRewardsInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -79,14 +79,16 @@ export type SetConfigFeesInstructionData = {
   newDaoFeeBps: Option<bigint>;
   newNcnFeeBps: Option<bigint>;
   newBlockEngineFeeBps: Option<bigint>;
-  newFeeWallet: Option<Address>;
+  newDaoFeeWallet: Option<Address>;
+  newNcnFeeWallet: Option<Address>;
 };
 
 export type SetConfigFeesInstructionDataArgs = {
   newDaoFeeBps: OptionOrNullable<number | bigint>;
   newNcnFeeBps: OptionOrNullable<number | bigint>;
   newBlockEngineFeeBps: OptionOrNullable<number | bigint>;
-  newFeeWallet: OptionOrNullable<Address>;
+  newDaoFeeWallet: OptionOrNullable<Address>;
+  newNcnFeeWallet: OptionOrNullable<Address>;
 };
 
 export function getSetConfigFeesInstructionDataEncoder(): Encoder<SetConfigFeesInstructionDataArgs> {
@@ -96,7 +98,8 @@ export function getSetConfigFeesInstructionDataEncoder(): Encoder<SetConfigFeesI
       ['newDaoFeeBps', getOptionEncoder(getU64Encoder())],
       ['newNcnFeeBps', getOptionEncoder(getU64Encoder())],
       ['newBlockEngineFeeBps', getOptionEncoder(getU64Encoder())],
-      ['newFeeWallet', getOptionEncoder(getAddressEncoder())],
+      ['newDaoFeeWallet', getOptionEncoder(getAddressEncoder())],
+      ['newNcnFeeWallet', getOptionEncoder(getAddressEncoder())],
     ]),
     (value) => ({ ...value, discriminator: SET_CONFIG_FEES_DISCRIMINATOR })
   );
@@ -108,7 +111,8 @@ export function getSetConfigFeesInstructionDataDecoder(): Decoder<SetConfigFeesI
     ['newDaoFeeBps', getOptionDecoder(getU64Decoder())],
     ['newNcnFeeBps', getOptionDecoder(getU64Decoder())],
     ['newBlockEngineFeeBps', getOptionDecoder(getU64Decoder())],
-    ['newFeeWallet', getOptionDecoder(getAddressDecoder())],
+    ['newDaoFeeWallet', getOptionDecoder(getAddressDecoder())],
+    ['newNcnFeeWallet', getOptionDecoder(getAddressDecoder())],
   ]);
 }
 
@@ -137,7 +141,8 @@ export type SetConfigFeesInput<
   newDaoFeeBps: SetConfigFeesInstructionDataArgs['newDaoFeeBps'];
   newNcnFeeBps: SetConfigFeesInstructionDataArgs['newNcnFeeBps'];
   newBlockEngineFeeBps: SetConfigFeesInstructionDataArgs['newBlockEngineFeeBps'];
-  newFeeWallet: SetConfigFeesInstructionDataArgs['newFeeWallet'];
+  newDaoFeeWallet: SetConfigFeesInstructionDataArgs['newDaoFeeWallet'];
+  newNcnFeeWallet: SetConfigFeesInstructionDataArgs['newNcnFeeWallet'];
 };
 
 export function getSetConfigFeesInstruction<
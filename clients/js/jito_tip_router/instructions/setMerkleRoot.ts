@@ -45,6 +45,7 @@ export function getSetMerkleRootDiscriminatorBytes() {
 export type SetMerkleRootInstruction<
   TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
   TAccountNcnConfig extends string | IAccountMeta<string> = string,
+  TAccountRestakingConfig extends string | IAccountMeta<string> = string,
   TAccountNcn extends string | IAccountMeta<string> = string,
   TAccountBallotBox extends string | IAccountMeta<string> = string,
   TAccountVoteAccount extends string | IAccountMeta<string> = string,
@@ -60,6 +61,9 @@ export type SetMerkleRootInstruction<
       TAccountNcnConfig extends string
         ? WritableAccount<TAccountNcnConfig>
         : TAccountNcnConfig,
+      TAccountRestakingConfig extends string
+        ? ReadonlyAccount<TAccountRestakingConfig>
+        : TAccountRestakingConfig,
       TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
       TAccountBallotBox extends string
         ? ReadonlyAccount<TAccountBallotBox>
@@ -90,6 +94,7 @@ export type SetMerkleRootInstructionData = {
   maxTotalClaim: bigint;
   maxNumNodes: bigint;
   epoch: bigint;
+  tipDistributionEpoch: bigint;
 };
 
 export type SetMerkleRootInstructionDataArgs = {
@@ -98,6 +103,7 @@ export type SetMerkleRootInstructionDataArgs = {
   maxTotalClaim: number | bigint;
   maxNumNodes: number | bigint;
   epoch: number | bigint;
+  tipDistributionEpoch: number | bigint;
 };
 
 export function getSetMerkleRootInstructionDataEncoder(): Encoder<SetMerkleRootInstructionDataArgs> {
@@ -109,6 +115,7 @@ export function getSetMerkleRootInstructionDataEncoder(): Encoder<SetMerkleRootI
       ['maxTotalClaim', getU64Encoder()],
       ['maxNumNodes', getU64Encoder()],
       ['epoch', getU64Encoder()],
+      ['tipDistributionEpoch', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: SET_MERKLE_ROOT_DISCRIMINATOR })
   );
@@ -122,6 +129,7 @@ export function getSetMerkleRootInstructionDataDecoder(): Decoder<SetMerkleRootI
     ['maxTotalClaim', getU64Decoder()],
     ['maxNumNodes', getU64Decoder()],
     ['epoch', getU64Decoder()],
+    ['tipDistributionEpoch', getU64Decoder()],
   ]);
 }
 
@@ -137,6 +145,7 @@ export function getSetMerkleRootInstructionDataCodec(): Codec<
 
 export type SetMerkleRootInput<
   TAccountNcnConfig extends string = string,
+  TAccountRestakingConfig extends string = string,
   TAccountNcn extends string = string,
   TAccountBallotBox extends string = string,
   TAccountVoteAccount extends string = string,
@@ -146,6 +155,7 @@ export type SetMerkleRootInput<
   TAccountRestakingProgram extends string = string,
 > = {
   ncnConfig: Address<TAccountNcnConfig>;
+  restakingConfig: Address<TAccountRestakingConfig>;
   ncn: Address<TAccountNcn>;
   ballotBox: Address<TAccountBallotBox>;
   voteAccount: Address<TAccountVoteAccount>;
@@ -158,10 +168,12 @@ export type SetMerkleRootInput<
   maxTotalClaim: SetMerkleRootInstructionDataArgs['maxTotalClaim'];
   maxNumNodes: SetMerkleRootInstructionDataArgs['maxNumNodes'];
   epoch: SetMerkleRootInstructionDataArgs['epoch'];
+  tipDistributionEpoch: SetMerkleRootInstructionDataArgs['tipDistributionEpoch'];
 };
 
 export function getSetMerkleRootInstruction<
   TAccountNcnConfig extends string,
+  TAccountRestakingConfig extends string,
   TAccountNcn extends string,
   TAccountBallotBox extends string,
   TAccountVoteAccount extends string,
@@ -173,6 +185,7 @@ export function getSetMerkleRootInstruction<
 >(
   input: SetMerkleRootInput<
     TAccountNcnConfig,
+    TAccountRestakingConfig,
     TAccountNcn,
     TAccountBallotBox,
     TAccountVoteAccount,
@@ -185,6 +198,7 @@ export function getSetMerkleRootInstruction<
 ): SetMerkleRootInstruction<
   TProgramAddress,
   TAccountNcnConfig,
+  TAccountRestakingConfig,
   TAccountNcn,
   TAccountBallotBox,
   TAccountVoteAccount,
@@ -200,6 +214,10 @@ export function getSetMerkleRootInstruction<
   // Original accounts.
   const originalAccounts = {
     ncnConfig: { value: input.ncnConfig ?? null, isWritable: true },
+    restakingConfig: {
+      value: input.restakingConfig ?? null,
+      isWritable: false,
+    },
     ncn: { value: input.ncn ?? null, isWritable: false },
     ballotBox: { value: input.ballotBox ?? null, isWritable: false },
     voteAccount: { value: input.voteAccount ?? null, isWritable: false },
@@ -232,6 +250,7 @@ export function getSetMerkleRootInstruction<
   const instruction = {
     accounts: [
       getAccountMeta(accounts.ncnConfig),
+      getAccountMeta(accounts.restakingConfig),
       getAccountMeta(accounts.ncn),
       getAccountMeta(accounts.ballotBox),
       getAccountMeta(accounts.voteAccount),
@@ -247,6 +266,7 @@ export function getSetMerkleRootInstruction<
   } as SetMerkleRootInstruction<
     TProgramAddress,
     TAccountNcnConfig,
+    TAccountRestakingConfig,
     TAccountNcn,
     TAccountBallotBox,
     TAccountVoteAccount,
@@ -266,13 +286,14 @@ export type ParsedSetMerkleRootInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     ncnConfig: TAccountMetas[0];
-    ncn: TAccountMetas[1];
-    ballotBox: TAccountMetas[2];
-    voteAccount: TAccountMetas[3];
-    tipDistributionAccount: TAccountMetas[4];
-    tipDistributionConfig: TAccountMetas[5];
-    tipDistributionProgram: TAccountMetas[6];
-    restakingProgram: TAccountMetas[7];
+    restakingConfig: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    ballotBox: TAccountMetas[3];
+    voteAccount: TAccountMetas[4];
+    tipDistributionAccount: TAccountMetas[5];
+    tipDistributionConfig: TAccountMetas[6];
+    tipDistributionProgram: TAccountMetas[7];
+    restakingProgram: TAccountMetas[8];
   };
   data: SetMerkleRootInstructionData;
 };
@@ -285,7 +306,7 @@ export function parseSetMerkleRootInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedSetMerkleRootInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -299,6 +320,7 @@ export function parseSetMerkleRootInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       ncnConfig: getNextAccount(),
+      restakingConfig: getNextAccount(),
       ncn: getNextAccount(),
       ballotBox: getNextAccount(),
       voteAccount: getNextAccount(),
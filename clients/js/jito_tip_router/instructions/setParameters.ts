@@ -0,0 +1,243 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_TIP_ROUTER_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_PARAMETERS_DISCRIMINATOR = 16;
+
+export function getSetParametersDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_PARAMETERS_DISCRIMINATOR);
+}
+
+export type SetParametersInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountNcnAdmin extends string | IAccountMeta<string> = string,
+  TAccountRestakingProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountNcnAdmin extends string
+        ? ReadonlySignerAccount<TAccountNcnAdmin> &
+            IAccountSignerMeta<TAccountNcnAdmin>
+        : TAccountNcnAdmin,
+      TAccountRestakingProgram extends string
+        ? ReadonlyAccount<TAccountRestakingProgram>
+        : TAccountRestakingProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetParametersInstructionData = {
+  discriminator: number;
+  epochsBeforeClose: Option<bigint>;
+  snapshotWindowSlots: Option<bigint>;
+  snapshotGraceEpochs: Option<bigint>;
+  feeActivationDelayEpochs: Option<bigint>;
+};
+
+export type SetParametersInstructionDataArgs = {
+  epochsBeforeClose: OptionOrNullable<number | bigint>;
+  snapshotWindowSlots: OptionOrNullable<number | bigint>;
+  snapshotGraceEpochs: OptionOrNullable<number | bigint>;
+  feeActivationDelayEpochs: OptionOrNullable<number | bigint>;
+};
+
+export function getSetParametersInstructionDataEncoder(): Encoder<SetParametersInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epochsBeforeClose', getOptionEncoder(getU64Encoder())],
+      ['snapshotWindowSlots', getOptionEncoder(getU64Encoder())],
+      ['snapshotGraceEpochs', getOptionEncoder(getU64Encoder())],
+      ['feeActivationDelayEpochs', getOptionEncoder(getU64Encoder())],
+    ]),
+    (value) => ({ ...value, discriminator: SET_PARAMETERS_DISCRIMINATOR })
+  );
+}
+
+export function getSetParametersInstructionDataDecoder(): Decoder<SetParametersInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epochsBeforeClose', getOptionDecoder(getU64Decoder())],
+    ['snapshotWindowSlots', getOptionDecoder(getU64Decoder())],
+    ['snapshotGraceEpochs', getOptionDecoder(getU64Decoder())],
+    ['feeActivationDelayEpochs', getOptionDecoder(getU64Decoder())],
+  ]);
+}
+
+export function getSetParametersInstructionDataCodec(): Codec<
+  SetParametersInstructionDataArgs,
+  SetParametersInstructionData
+> {
+  return combineCodec(
+    getSetParametersInstructionDataEncoder(),
+    getSetParametersInstructionDataDecoder()
+  );
+}
+
+export type SetParametersInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountNcnAdmin extends string = string,
+  TAccountRestakingProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  ncnAdmin: TransactionSigner<TAccountNcnAdmin>;
+  restakingProgram: Address<TAccountRestakingProgram>;
+  epochsBeforeClose: SetParametersInstructionDataArgs['epochsBeforeClose'];
+  snapshotWindowSlots: SetParametersInstructionDataArgs['snapshotWindowSlots'];
+  snapshotGraceEpochs: SetParametersInstructionDataArgs['snapshotGraceEpochs'];
+  feeActivationDelayEpochs: SetParametersInstructionDataArgs['feeActivationDelayEpochs'];
+};
+
+export function getSetParametersInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountNcnAdmin extends string,
+  TAccountRestakingProgram extends string,
+  TProgramAddress extends Address = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+>(
+  input: SetParametersInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnAdmin,
+    TAccountRestakingProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SetParametersInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountNcnAdmin,
+  TAccountRestakingProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_TIP_ROUTER_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnAdmin: { value: input.ncnAdmin ?? null, isWritable: false },
+    restakingProgram: {
+      value: input.restakingProgram ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnAdmin),
+      getAccountMeta(accounts.restakingProgram),
+    ],
+    programAddress,
+    data: getSetParametersInstructionDataEncoder().encode(
+      args as SetParametersInstructionDataArgs
+    ),
+  } as SetParametersInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnAdmin,
+    TAccountRestakingProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetParametersInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    ncnAdmin: TAccountMetas[2];
+    restakingProgram: TAccountMetas[3];
+  };
+  data: SetParametersInstructionData;
+};
+
+export function parseSetParametersInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetParametersInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      ncnAdmin: getNextAccount(),
+      restakingProgram: getNextAccount(),
+    },
+    data: getSetParametersInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -46,6 +46,7 @@ export function getSetTieBreakerDiscriminatorBytes() {
 export type SetTieBreakerInstruction<
   TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
   TAccountNcnConfig extends string | IAccountMeta<string> = string,
+  TAccountRestakingConfig extends string | IAccountMeta<string> = string,
   TAccountBallotBox extends string | IAccountMeta<string> = string,
   TAccountNcn extends string | IAccountMeta<string> = string,
   TAccountTieBreakerAdmin extends string | IAccountMeta<string> = string,
@@ -58,6 +59,9 @@ export type SetTieBreakerInstruction<
       TAccountNcnConfig extends string
         ? ReadonlyAccount<TAccountNcnConfig>
         : TAccountNcnConfig,
+      TAccountRestakingConfig extends string
+        ? ReadonlyAccount<TAccountRestakingConfig>
+        : TAccountRestakingConfig,
       TAccountBallotBox extends string
         ? WritableAccount<TAccountBallotBox>
         : TAccountBallotBox,
@@ -115,12 +119,14 @@ export function getSetTieBreakerInstructionDataCodec(): Codec<
 
 export type SetTieBreakerInput<
   TAccountNcnConfig extends string = string,
+  TAccountRestakingConfig extends string = string,
   TAccountBallotBox extends string = string,
   TAccountNcn extends string = string,
   TAccountTieBreakerAdmin extends string = string,
   TAccountRestakingProgram extends string = string,
 > = {
   ncnConfig: Address<TAccountNcnConfig>;
+  restakingConfig: Address<TAccountRestakingConfig>;
   ballotBox: Address<TAccountBallotBox>;
   ncn: Address<TAccountNcn>;
   tieBreakerAdmin: TransactionSigner<TAccountTieBreakerAdmin>;
@@ -131,6 +137,7 @@ export type SetTieBreakerInput<
 
 export function getSetTieBreakerInstruction<
   TAccountNcnConfig extends string,
+  TAccountRestakingConfig extends string,
   TAccountBallotBox extends string,
   TAccountNcn extends string,
   TAccountTieBreakerAdmin extends string,
@@ -139,6 +146,7 @@ export function getSetTieBreakerInstruction<
 >(
   input: SetTieBreakerInput<
     TAccountNcnConfig,
+    TAccountRestakingConfig,
     TAccountBallotBox,
     TAccountNcn,
     TAccountTieBreakerAdmin,
@@ -148,6 +156,7 @@ export function getSetTieBreakerInstruction<
 ): SetTieBreakerInstruction<
   TProgramAddress,
   TAccountNcnConfig,
+  TAccountRestakingConfig,
   TAccountBallotBox,
   TAccountNcn,
   TAccountTieBreakerAdmin,
@@ -160,6 +169,10 @@ export function getSetTieBreakerInstruction<
   // Original accounts.
   const originalAccounts = {
     ncnConfig: { value: input.ncnConfig ?? null, isWritable: false },
+    restakingConfig: {
+      value: input.restakingConfig ?? null,
+      isWritable: false,
+    },
     ballotBox: { value: input.ballotBox ?? null, isWritable: true },
     ncn: { value: input.ncn ?? null, isWritable: false },
     tieBreakerAdmin: {
@@ -183,6 +196,7 @@ export function getSetTieBreakerInstruction<
   const instruction = {
     accounts: [
       getAccountMeta(accounts.ncnConfig),
+      getAccountMeta(accounts.restakingConfig),
       getAccountMeta(accounts.ballotBox),
       getAccountMeta(accounts.ncn),
       getAccountMeta(accounts.tieBreakerAdmin),
@@ -195,6 +209,7 @@ export function getSetTieBreakerInstruction<
   } as SetTieBreakerInstruction<
     TProgramAddress,
     TAccountNcnConfig,
+    TAccountRestakingConfig,
     TAccountBallotBox,
     TAccountNcn,
     TAccountTieBreakerAdmin,
@@ -211,10 +226,11 @@ export type ParsedSetTieBreakerInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     ncnConfig: TAccountMetas[0];
-    ballotBox: TAccountMetas[1];
-    ncn: TAccountMetas[2];
-    tieBreakerAdmin: TAccountMetas[3];
-    restakingProgram: TAccountMetas[4];
+    restakingConfig: TAccountMetas[1];
+    ballotBox: TAccountMetas[2];
+    ncn: TAccountMetas[3];
+    tieBreakerAdmin: TAccountMetas[4];
+    restakingProgram: TAccountMetas[5];
   };
   data: SetTieBreakerInstructionData;
 };
@@ -227,7 +243,7 @@ export function parseSetTieBreakerInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedSetTieBreakerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -241,6 +257,7 @@ export function parseSetTieBreakerInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       ncnConfig: getNextAccount(),
+      restakingConfig: getNextAccount(),
       ballotBox: getNextAccount(),
       ncn: getNextAccount(),
       tieBreakerAdmin: getNextAccount(),
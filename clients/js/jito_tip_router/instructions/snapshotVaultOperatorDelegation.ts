@@ -56,6 +56,7 @@ export type SnapshotVaultOperatorDelegationInstruction<
   TAccountWeightTable extends string | IAccountMeta<string> = string,
   TAccountEpochSnapshot extends string | IAccountMeta<string> = string,
   TAccountOperatorSnapshot extends string | IAccountMeta<string> = string,
+  TAccountVaultSnapshot extends string | IAccountMeta<string> = string,
   TAccountVaultProgram extends string | IAccountMeta<string> = string,
   TAccountRestakingProgram extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
@@ -94,6 +95,9 @@ export type SnapshotVaultOperatorDelegationInstruction<
       TAccountOperatorSnapshot extends string
         ? WritableAccount<TAccountOperatorSnapshot>
         : TAccountOperatorSnapshot,
+      TAccountVaultSnapshot extends string
+        ? WritableAccount<TAccountVaultSnapshot>
+        : TAccountVaultSnapshot,
       TAccountVaultProgram extends string
         ? ReadonlyAccount<TAccountVaultProgram>
         : TAccountVaultProgram,
@@ -155,6 +159,7 @@ export type SnapshotVaultOperatorDelegationInput<
   TAccountWeightTable extends string = string,
   TAccountEpochSnapshot extends string = string,
   TAccountOperatorSnapshot extends string = string,
+  TAccountVaultSnapshot extends string = string,
   TAccountVaultProgram extends string = string,
   TAccountRestakingProgram extends string = string,
 > = {
@@ -169,6 +174,7 @@ export type SnapshotVaultOperatorDelegationInput<
   weightTable: Address<TAccountWeightTable>;
   epochSnapshot: Address<TAccountEpochSnapshot>;
   operatorSnapshot: Address<TAccountOperatorSnapshot>;
+  vaultSnapshot: Address<TAccountVaultSnapshot>;
   vaultProgram: Address<TAccountVaultProgram>;
   restakingProgram: Address<TAccountRestakingProgram>;
   firstSlotOfNcnEpoch: SnapshotVaultOperatorDelegationInstructionDataArgs['firstSlotOfNcnEpoch'];
@@ -186,6 +192,7 @@ export function getSnapshotVaultOperatorDelegationInstruction<
   TAccountWeightTable extends string,
   TAccountEpochSnapshot extends string,
   TAccountOperatorSnapshot extends string,
+  TAccountVaultSnapshot extends string,
   TAccountVaultProgram extends string,
   TAccountRestakingProgram extends string,
   TProgramAddress extends Address = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
@@ -202,6 +209,7 @@ export function getSnapshotVaultOperatorDelegationInstruction<
     TAccountWeightTable,
     TAccountEpochSnapshot,
     TAccountOperatorSnapshot,
+    TAccountVaultSnapshot,
     TAccountVaultProgram,
     TAccountRestakingProgram
   >,
@@ -219,6 +227,7 @@ export function getSnapshotVaultOperatorDelegationInstruction<
   TAccountWeightTable,
   TAccountEpochSnapshot,
   TAccountOperatorSnapshot,
+  TAccountVaultSnapshot,
   TAccountVaultProgram,
   TAccountRestakingProgram
 > {
@@ -248,6 +257,7 @@ export function getSnapshotVaultOperatorDelegationInstruction<
       value: input.operatorSnapshot ?? null,
       isWritable: true,
     },
+    vaultSnapshot: { value: input.vaultSnapshot ?? null, isWritable: true },
     vaultProgram: { value: input.vaultProgram ?? null, isWritable: false },
     restakingProgram: {
       value: input.restakingProgram ?? null,
@@ -276,6 +286,7 @@ export function getSnapshotVaultOperatorDelegationInstruction<
       getAccountMeta(accounts.weightTable),
       getAccountMeta(accounts.epochSnapshot),
       getAccountMeta(accounts.operatorSnapshot),
+      getAccountMeta(accounts.vaultSnapshot),
       getAccountMeta(accounts.vaultProgram),
       getAccountMeta(accounts.restakingProgram),
     ],
@@ -296,6 +307,7 @@ export function getSnapshotVaultOperatorDelegationInstruction<
     TAccountWeightTable,
     TAccountEpochSnapshot,
     TAccountOperatorSnapshot,
+    TAccountVaultSnapshot,
     TAccountVaultProgram,
     TAccountRestakingProgram
   >;
@@ -320,8 +332,9 @@ export type ParsedSnapshotVaultOperatorDelegationInstruction<
     weightTable: TAccountMetas[8];
     epochSnapshot: TAccountMetas[9];
     operatorSnapshot: TAccountMetas[10];
-    vaultProgram: TAccountMetas[11];
-    restakingProgram: TAccountMetas[12];
+    vaultSnapshot: TAccountMetas[11];
+    vaultProgram: TAccountMetas[12];
+    restakingProgram: TAccountMetas[13];
   };
   data: SnapshotVaultOperatorDelegationInstructionData;
 };
@@ -334,7 +347,7 @@ export function parseSnapshotVaultOperatorDelegationInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedSnapshotVaultOperatorDelegationInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 13) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -358,6 +371,7 @@ export function parseSnapshotVaultOperatorDelegationInstruction<
       weightTable: getNextAccount(),
       epochSnapshot: getNextAccount(),
       operatorSnapshot: getNextAccount(),
+      vaultSnapshot: getNextAccount(),
       vaultProgram: getNextAccount(),
       restakingProgram: getNextAccount(),
     },
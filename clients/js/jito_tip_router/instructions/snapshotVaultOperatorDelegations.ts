@@ -0,0 +1,307 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_TIP_ROUTER_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SNAPSHOT_VAULT_OPERATOR_DELEGATIONS_DISCRIMINATOR = 21;
+
+export function getSnapshotVaultOperatorDelegationsDiscriminatorBytes() {
+  return getU8Encoder().encode(
+    SNAPSHOT_VAULT_OPERATOR_DELEGATIONS_DISCRIMINATOR
+  );
+}
+
+export type SnapshotVaultOperatorDelegationsInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountNcnConfig extends string | IAccountMeta<string> = string,
+  TAccountRestakingConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountWeightTable extends string | IAccountMeta<string> = string,
+  TAccountEpochSnapshot extends string | IAccountMeta<string> = string,
+  TAccountOperatorSnapshot extends string | IAccountMeta<string> = string,
+  TAccountVaultProgram extends string | IAccountMeta<string> = string,
+  TAccountRestakingProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcnConfig extends string
+        ? ReadonlyAccount<TAccountNcnConfig>
+        : TAccountNcnConfig,
+      TAccountRestakingConfig extends string
+        ? ReadonlyAccount<TAccountRestakingConfig>
+        : TAccountRestakingConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountWeightTable extends string
+        ? ReadonlyAccount<TAccountWeightTable>
+        : TAccountWeightTable,
+      TAccountEpochSnapshot extends string
+        ? WritableAccount<TAccountEpochSnapshot>
+        : TAccountEpochSnapshot,
+      TAccountOperatorSnapshot extends string
+        ? WritableAccount<TAccountOperatorSnapshot>
+        : TAccountOperatorSnapshot,
+      TAccountVaultProgram extends string
+        ? ReadonlyAccount<TAccountVaultProgram>
+        : TAccountVaultProgram,
+      TAccountRestakingProgram extends string
+        ? ReadonlyAccount<TAccountRestakingProgram>
+        : TAccountRestakingProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SnapshotVaultOperatorDelegationsInstructionData = {
+  discriminator: number;
+  firstSlotOfNcnEpoch: Option<bigint>;
+};
+
+export type SnapshotVaultOperatorDelegationsInstructionDataArgs = {
+  firstSlotOfNcnEpoch: OptionOrNullable<number | bigint>;
+};
+
+export function getSnapshotVaultOperatorDelegationsInstructionDataEncoder(): Encoder<SnapshotVaultOperatorDelegationsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['firstSlotOfNcnEpoch', getOptionEncoder(getU64Encoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SNAPSHOT_VAULT_OPERATOR_DELEGATIONS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSnapshotVaultOperatorDelegationsInstructionDataDecoder(): Decoder<SnapshotVaultOperatorDelegationsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['firstSlotOfNcnEpoch', getOptionDecoder(getU64Decoder())],
+  ]);
+}
+
+export function getSnapshotVaultOperatorDelegationsInstructionDataCodec(): Codec<
+  SnapshotVaultOperatorDelegationsInstructionDataArgs,
+  SnapshotVaultOperatorDelegationsInstructionData
+> {
+  return combineCodec(
+    getSnapshotVaultOperatorDelegationsInstructionDataEncoder(),
+    getSnapshotVaultOperatorDelegationsInstructionDataDecoder()
+  );
+}
+
+export type SnapshotVaultOperatorDelegationsInput<
+  TAccountNcnConfig extends string = string,
+  TAccountRestakingConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountWeightTable extends string = string,
+  TAccountEpochSnapshot extends string = string,
+  TAccountOperatorSnapshot extends string = string,
+  TAccountVaultProgram extends string = string,
+  TAccountRestakingProgram extends string = string,
+> = {
+  ncnConfig: Address<TAccountNcnConfig>;
+  restakingConfig: Address<TAccountRestakingConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  weightTable: Address<TAccountWeightTable>;
+  epochSnapshot: Address<TAccountEpochSnapshot>;
+  operatorSnapshot: Address<TAccountOperatorSnapshot>;
+  vaultProgram: Address<TAccountVaultProgram>;
+  restakingProgram: Address<TAccountRestakingProgram>;
+  firstSlotOfNcnEpoch: SnapshotVaultOperatorDelegationsInstructionDataArgs['firstSlotOfNcnEpoch'];
+};
+
+export function getSnapshotVaultOperatorDelegationsInstruction<
+  TAccountNcnConfig extends string,
+  TAccountRestakingConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountWeightTable extends string,
+  TAccountEpochSnapshot extends string,
+  TAccountOperatorSnapshot extends string,
+  TAccountVaultProgram extends string,
+  TAccountRestakingProgram extends string,
+  TProgramAddress extends Address = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+>(
+  input: SnapshotVaultOperatorDelegationsInput<
+    TAccountNcnConfig,
+    TAccountRestakingConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountWeightTable,
+    TAccountEpochSnapshot,
+    TAccountOperatorSnapshot,
+    TAccountVaultProgram,
+    TAccountRestakingProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SnapshotVaultOperatorDelegationsInstruction<
+  TProgramAddress,
+  TAccountNcnConfig,
+  TAccountRestakingConfig,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountWeightTable,
+  TAccountEpochSnapshot,
+  TAccountOperatorSnapshot,
+  TAccountVaultProgram,
+  TAccountRestakingProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_TIP_ROUTER_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncnConfig: { value: input.ncnConfig ?? null, isWritable: false },
+    restakingConfig: {
+      value: input.restakingConfig ?? null,
+      isWritable: false,
+    },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    weightTable: { value: input.weightTable ?? null, isWritable: false },
+    epochSnapshot: { value: input.epochSnapshot ?? null, isWritable: true },
+    operatorSnapshot: {
+      value: input.operatorSnapshot ?? null,
+      isWritable: true,
+    },
+    vaultProgram: { value: input.vaultProgram ?? null, isWritable: false },
+    restakingProgram: {
+      value: input.restakingProgram ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncnConfig),
+      getAccountMeta(accounts.restakingConfig),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.weightTable),
+      getAccountMeta(accounts.epochSnapshot),
+      getAccountMeta(accounts.operatorSnapshot),
+      getAccountMeta(accounts.vaultProgram),
+      getAccountMeta(accounts.restakingProgram),
+    ],
+    programAddress,
+    data: getSnapshotVaultOperatorDelegationsInstructionDataEncoder().encode(
+      args as SnapshotVaultOperatorDelegationsInstructionDataArgs
+    ),
+  } as SnapshotVaultOperatorDelegationsInstruction<
+    TProgramAddress,
+    TAccountNcnConfig,
+    TAccountRestakingConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountWeightTable,
+    TAccountEpochSnapshot,
+    TAccountOperatorSnapshot,
+    TAccountVaultProgram,
+    TAccountRestakingProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedSnapshotVaultOperatorDelegationsInstruction<
+  TProgram extends string = typeof JITO_TIP_ROUTER_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncnConfig: TAccountMetas[0];
+    restakingConfig: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    operator: TAccountMetas[3];
+    weightTable: TAccountMetas[4];
+    epochSnapshot: TAccountMetas[5];
+    operatorSnapshot: TAccountMetas[6];
+    vaultProgram: TAccountMetas[7];
+    restakingProgram: TAccountMetas[8];
+  };
+  data: SnapshotVaultOperatorDelegationsInstructionData;
+};
+
+export function parseSnapshotVaultOperatorDelegationsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSnapshotVaultOperatorDelegationsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 9) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncnConfig: getNextAccount(),
+      restakingConfig: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      weightTable: getNextAccount(),
+      epochSnapshot: getNextAccount(),
+      operatorSnapshot: getNextAccount(),
+      vaultProgram: getNextAccount(),
+      restakingProgram: getNextAccount(),
+    },
+    data: getSnapshotVaultOperatorDelegationsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -15,18 +15,33 @@ import {
 import {
   type ParsedAdminUpdateWeightTableInstruction,
   type ParsedCastVoteInstruction,
+  type ParsedCloseBallotBoxInstruction,
+  type ParsedCloseEpochRewardRouterInstruction,
+  type ParsedCloseEpochSnapshotInstruction,
+  type ParsedCloseOperatorSnapshotInstruction,
+  type ParsedCloseVaultSnapshotInstruction,
+  type ParsedCloseWeightTableInstruction,
+  type ParsedDeregisterMintInstruction,
+  type ParsedDistributeOperatorRewardsInstruction,
   type ParsedInitializeBallotBoxInstruction,
+  type ParsedInitializeEpochRewardRouterInstruction,
   type ParsedInitializeEpochSnapshotInstruction,
   type ParsedInitializeNCNConfigInstruction,
   type ParsedInitializeOperatorSnapshotInstruction,
   type ParsedInitializeTrackedMintsInstruction,
+  type ParsedInitializeVaultSnapshotInstruction,
   type ParsedInitializeWeightTableInstruction,
+  type ParsedMigrateNcnConfigInstruction,
+  type ParsedReallocTrackedMintsInstruction,
   type ParsedRegisterMintInstruction,
+  type ParsedRouteNcnRewardsInstruction,
   type ParsedSetConfigFeesInstruction,
   type ParsedSetMerkleRootInstruction,
   type ParsedSetNewAdminInstruction,
+  type ParsedSetParametersInstruction,
   type ParsedSetTieBreakerInstruction,
   type ParsedSnapshotVaultOperatorDelegationInstruction,
+  type ParsedSnapshotVaultOperatorDelegationsInstruction,
 } from '../instructions';
 
 export const JITO_TIP_ROUTER_PROGRAM_ADDRESS =
@@ -34,8 +49,10 @@ export const JITO_TIP_ROUTER_PROGRAM_ADDRESS =
 
 export enum JitoTipRouterAccount {
   BallotBox,
+  EpochRewardRouter,
   EpochSnapshot,
   OperatorSnapshot,
+  VaultSnapshot,
   NcnConfig,
   TrackedMints,
   WeightTable,
@@ -56,6 +73,21 @@ export enum JitoTipRouterInstruction {
   CastVote,
   SetMerkleRoot,
   SetTieBreaker,
+  DeregisterMint,
+  ReallocTrackedMints,
+  SetParameters,
+  CloseWeightTable,
+  CloseEpochSnapshot,
+  CloseOperatorSnapshot,
+  CloseBallotBox,
+  SnapshotVaultOperatorDelegations,
+  InitializeVaultSnapshot,
+  CloseVaultSnapshot,
+  InitializeEpochRewardRouter,
+  RouteNcnRewards,
+  DistributeOperatorRewards,
+  MigrateNcnConfig,
+  CloseEpochRewardRouter,
 }
 
 export function identifyJitoTipRouterInstruction(
@@ -104,6 +136,51 @@ export function identifyJitoTipRouterInstruction(
   if (containsBytes(data, getU8Encoder().encode(13), 0)) {
     return JitoTipRouterInstruction.SetTieBreaker;
   }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return JitoTipRouterInstruction.DeregisterMint;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return JitoTipRouterInstruction.ReallocTrackedMints;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return JitoTipRouterInstruction.SetParameters;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return JitoTipRouterInstruction.CloseWeightTable;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return JitoTipRouterInstruction.CloseEpochSnapshot;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return JitoTipRouterInstruction.CloseOperatorSnapshot;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return JitoTipRouterInstruction.CloseBallotBox;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return JitoTipRouterInstruction.SnapshotVaultOperatorDelegations;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return JitoTipRouterInstruction.InitializeVaultSnapshot;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return JitoTipRouterInstruction.CloseVaultSnapshot;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return JitoTipRouterInstruction.InitializeEpochRewardRouter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return JitoTipRouterInstruction.RouteNcnRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return JitoTipRouterInstruction.DistributeOperatorRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return JitoTipRouterInstruction.MigrateNcnConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return JitoTipRouterInstruction.CloseEpochRewardRouter;
+  }
   throw new Error(
     'The provided instruction could not be identified as a jitoTipRouter instruction.'
   );
@@ -153,4 +230,49 @@ export type ParsedJitoTipRouterInstruction<
     } & ParsedSetMerkleRootInstruction<TProgram>)
   | ({
       instructionType: JitoTipRouterInstruction.SetTieBreaker;
-    } & ParsedSetTieBreakerInstruction<TProgram>);
+    } & ParsedSetTieBreakerInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.DeregisterMint;
+    } & ParsedDeregisterMintInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.ReallocTrackedMints;
+    } & ParsedReallocTrackedMintsInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.SetParameters;
+    } & ParsedSetParametersInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.CloseWeightTable;
+    } & ParsedCloseWeightTableInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.CloseEpochSnapshot;
+    } & ParsedCloseEpochSnapshotInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.CloseOperatorSnapshot;
+    } & ParsedCloseOperatorSnapshotInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.CloseBallotBox;
+    } & ParsedCloseBallotBoxInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.SnapshotVaultOperatorDelegations;
+    } & ParsedSnapshotVaultOperatorDelegationsInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.InitializeVaultSnapshot;
+    } & ParsedInitializeVaultSnapshotInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.CloseVaultSnapshot;
+    } & ParsedCloseVaultSnapshotInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.InitializeEpochRewardRouter;
+    } & ParsedInitializeEpochRewardRouterInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.RouteNcnRewards;
+    } & ParsedRouteNcnRewardsInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.DistributeOperatorRewards;
+    } & ParsedDistributeOperatorRewardsInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.MigrateNcnConfig;
+    } & ParsedMigrateNcnConfigInstruction<TProgram>)
+  | ({
+      instructionType: JitoTipRouterInstruction.CloseEpochRewardRouter;
+    } & ParsedCloseEpochRewardRouterInstruction<TProgram>);
@@ -21,7 +21,8 @@ import {
 } from '@solana/web3.js';
 
 export type Fee = {
-  wallet: Address;
+  daoFeeWallet: Address;
+  ncnFeeWallet: Address;
   daoShareBps: bigint;
   ncnShareBps: bigint;
   blockEngineFeeBps: bigint;
@@ -29,7 +30,8 @@ export type Fee = {
 };
 
 export type FeeArgs = {
-  wallet: Address;
+  daoFeeWallet: Address;
+  ncnFeeWallet: Address;
   daoShareBps: number | bigint;
   ncnShareBps: number | bigint;
   blockEngineFeeBps: number | bigint;
@@ -38,7 +40,8 @@ export type FeeArgs = {
 
 export function getFeeEncoder(): Encoder<FeeArgs> {
   return getStructEncoder([
-    ['wallet', getAddressEncoder()],
+    ['daoFeeWallet', getAddressEncoder()],
+    ['ncnFeeWallet', getAddressEncoder()],
     ['daoShareBps', getU64Encoder()],
     ['ncnShareBps', getU64Encoder()],
     ['blockEngineFeeBps', getU64Encoder()],
@@ -48,7 +51,8 @@ export function getFeeEncoder(): Encoder<FeeArgs> {
 
 export function getFeeDecoder(): Decoder<Fee> {
   return getStructDecoder([
-    ['wallet', getAddressDecoder()],
+    ['daoFeeWallet', getAddressDecoder()],
+    ['ncnFeeWallet', getAddressDecoder()],
     ['daoShareBps', getU64Decoder()],
     ['ncnShareBps', getU64Decoder()],
     ['blockEngineFeeBps', getU64Decoder()],
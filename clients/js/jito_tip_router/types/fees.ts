@@ -8,22 +8,41 @@
 
 import {
   combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   type Codec,
   type Decoder,
   type Encoder,
 } from '@solana/web3.js';
 import { getFeeDecoder, getFeeEncoder, type Fee, type FeeArgs } from '.';
 
-export type Fees = { fee1: Fee; fee2: Fee };
+export type Fees = {
+  fee1: Fee;
+  fee2: Fee;
+  activationDelayEpochs: bigint;
+  feeHistoryLen: bigint;
+  feeHistory: Array<Fee>;
+};
 
-export type FeesArgs = { fee1: FeeArgs; fee2: FeeArgs };
+export type FeesArgs = {
+  fee1: FeeArgs;
+  fee2: FeeArgs;
+  activationDelayEpochs: number | bigint;
+  feeHistoryLen: number | bigint;
+  feeHistory: Array<FeeArgs>;
+};
 
 export function getFeesEncoder(): Encoder<FeesArgs> {
   return getStructEncoder([
     ['fee1', getFeeEncoder()],
     ['fee2', getFeeEncoder()],
+    ['activationDelayEpochs', getU64Encoder()],
+    ['feeHistoryLen', getU64Encoder()],
+    ['feeHistory', getArrayEncoder(getFeeEncoder(), { size: 8 })],
   ]);
 }
 
@@ -31,6 +50,9 @@ export function getFeesDecoder(): Decoder<Fees> {
   return getStructDecoder([
     ['fee1', getFeeDecoder()],
     ['fee2', getFeeDecoder()],
+    ['activationDelayEpochs', getU64Decoder()],
+    ['feeHistoryLen', getU64Decoder()],
+    ['feeHistory', getArrayDecoder(getFeeDecoder(), { size: 8 })],
   ]);
 }
 
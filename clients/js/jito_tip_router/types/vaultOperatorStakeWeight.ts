@@ -20,6 +20,8 @@ import {
   getU128Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type Codec,
   type Decoder,
@@ -31,6 +33,7 @@ export type VaultOperatorStakeWeight = {
   vault: Address;
   stakeWeight: bigint;
   vaultIndex: bigint;
+  exclusionReason: number;
   reserved: ReadonlyUint8Array;
 };
 
@@ -38,6 +41,7 @@ export type VaultOperatorStakeWeightArgs = {
   vault: Address;
   stakeWeight: number | bigint;
   vaultIndex: number | bigint;
+  exclusionReason: number;
   reserved: ReadonlyUint8Array;
 };
 
@@ -46,7 +50,8 @@ export function getVaultOperatorStakeWeightEncoder(): Encoder<VaultOperatorStake
     ['vault', getAddressEncoder()],
     ['stakeWeight', getU128Encoder()],
     ['vaultIndex', getU64Encoder()],
-    ['reserved', fixEncoderSize(getBytesEncoder(), 32)],
+    ['exclusionReason', getU8Encoder()],
+    ['reserved', fixEncoderSize(getBytesEncoder(), 31)],
   ]);
 }
 
@@ -55,7 +60,8 @@ export function getVaultOperatorStakeWeightDecoder(): Decoder<VaultOperatorStake
     ['vault', getAddressDecoder()],
     ['stakeWeight', getU128Decoder()],
     ['vaultIndex', getU64Decoder()],
-    ['reserved', fixDecoderSize(getBytesDecoder(), 32)],
+    ['exclusionReason', getU8Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 31)],
   ]);
 }
 
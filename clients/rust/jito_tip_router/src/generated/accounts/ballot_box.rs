@@ -22,8 +22,13 @@ pub struct BallotBox {
     pub bump: u8,
     pub slot_created: u64,
     pub slot_consensus_reached: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub payer: Pubkey,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 96],
     pub operators_voted: u64,
     pub unique_ballots: u64,
     pub winning_ballot: Ballot,
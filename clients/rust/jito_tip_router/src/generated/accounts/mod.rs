@@ -5,13 +5,15 @@
 //! <https://github.com/kinobi-so/kinobi>
 
 pub(crate) mod r#ballot_box;
+pub(crate) mod r#epoch_reward_router;
 pub(crate) mod r#epoch_snapshot;
 pub(crate) mod r#ncn_config;
 pub(crate) mod r#operator_snapshot;
 pub(crate) mod r#tracked_mints;
+pub(crate) mod r#vault_snapshot;
 pub(crate) mod r#weight_table;
 
 pub use self::{
-    r#ballot_box::*, r#epoch_snapshot::*, r#ncn_config::*, r#operator_snapshot::*,
-    r#tracked_mints::*, r#weight_table::*,
+    r#ballot_box::*, r#epoch_reward_router::*, r#epoch_snapshot::*, r#ncn_config::*,
+    r#operator_snapshot::*, r#tracked_mints::*, r#vault_snapshot::*, r#weight_table::*,
 };
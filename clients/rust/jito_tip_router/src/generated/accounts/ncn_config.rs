@@ -32,8 +32,11 @@ pub struct NcnConfig {
     pub epochs_before_stall: u64,
     pub fees: Fees,
     pub bump: u8,
+    pub epochs_before_close: u64,
+    pub snapshot_window_slots: u64,
+    pub snapshot_grace_epochs: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 127],
+    pub reserved: [u8; 103],
 }
 
 impl NcnConfig {
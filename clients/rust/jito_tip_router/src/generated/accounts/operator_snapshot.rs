@@ -35,9 +35,21 @@ pub struct OperatorSnapshot {
     pub vault_operator_delegations_registered: u64,
     pub valid_operator_vault_delegations: u64,
     pub stake_weight: u128,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub payer: Pubkey,
+    pub exclusion_reason: u8,
+    pub rewards_routed: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator_fee_wallet: Pubkey,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 256],
-    pub vault_operator_stake_weight: [VaultOperatorStakeWeight; 32],
+    pub reserved: [u8; 183],
+    pub vault_operator_stake_weight: [VaultOperatorStakeWeight; 64],
 }
 
 impl OperatorSnapshot {
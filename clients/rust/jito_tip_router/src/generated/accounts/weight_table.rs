@@ -21,8 +21,13 @@ pub struct WeightTable {
     pub ncn_epoch: u64,
     pub slot_created: u64,
     pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub payer: Pubkey,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 96],
     pub table: [WeightEntry; 32],
 }
 
@@ -135,6 +135,60 @@ pub enum JitoTipRouterError {
     /// 8740 - Operator admin needs to sign its vote
     #[error("Operator admin needs to sign its vote")]
     OperatorAdminInvalid = 0x2224,
+    /// 8741 - Mint is already deregistered
+    #[error("Mint is already deregistered")]
+    MintAlreadyDeregistered = 0x2225,
+    /// 8742 - Vault index is invalid
+    #[error("Vault index is invalid")]
+    VaultIndexInvalid = 0x2226,
+    /// 8743 - Vault is still active for the NCN
+    #[error("Vault is still active for the NCN")]
+    VaultStillActive = 0x2227,
+    /// 8744 - Invalid exchange rate
+    #[error("Invalid exchange rate")]
+    InvalidExchangeRate = 0x2228,
+    /// 8745 - Epochs before close must be greater than zero
+    #[error("Epochs before close must be greater than zero")]
+    InvalidEpochsBeforeClose = 0x2229,
+    /// 8746 - Account cannot be closed yet
+    #[error("Account cannot be closed yet")]
+    CannotCloseAccount = 0x222A,
+    /// 8747 - Incorrect payer
+    #[error("Incorrect payer")]
+    IncorrectPayer = 0x222B,
+    /// 8748 - Snapshot window must be greater than zero
+    #[error("Snapshot window must be greater than zero")]
+    InvalidSnapshotWindow = 0x222C,
+    /// 8749 - Snapshot window for this epoch has closed
+    #[error("Snapshot window for this epoch has closed")]
+    SnapshotWindowClosed = 0x222D,
+    /// 8750 - Grace period for past epoch snapshots has expired
+    #[error("Grace period for past epoch snapshots has expired")]
+    SnapshotGracePeriodExpired = 0x222E,
+    /// 8751 - Invalid exclusion reason
+    #[error("Invalid exclusion reason")]
+    InvalidExclusionReason = 0x222F,
+    /// 8752 - Fee wallet does not match the epoch fees
+    #[error("Fee wallet does not match the epoch fees")]
+    IncorrectFeeWallet = 0x2230,
+    /// 8753 - Fee activation delay must be greater than zero
+    #[error("Fee activation delay must be greater than zero")]
+    InvalidFeeActivationDelay = 0x2231,
+    /// 8754 - Operator snapshot does not belong to the epoch snapshot
+    #[error("Operator snapshot does not belong to the epoch snapshot")]
+    OperatorSnapshotMismatch = 0x2232,
+    /// 8755 - Vault snapshot does not belong to the epoch snapshot
+    #[error("Vault snapshot does not belong to the epoch snapshot")]
+    VaultSnapshotMismatch = 0x2233,
+    /// 8756 - Not enough compute units to snapshot a vault delegation
+    #[error("Not enough compute units to snapshot a vault delegation")]
+    NotEnoughComputeUnits = 0x2234,
+    /// 8757 - Rewards of the epoch have not been fully distributed
+    #[error("Rewards of the epoch have not been fully distributed")]
+    RewardsNotDistributed = 0x2235,
+    /// 8758 - Tip distribution epoch is not part of the NCN epoch
+    #[error("Tip distribution epoch is not part of the NCN epoch")]
+    InvalidTipDistributionEpoch = 0x2236,
 }
 
 impl solana_program::program_error::PrintProgramError for JitoTipRouterError {
@@ -0,0 +1,610 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct DeregisterMint {
+    pub restaking_config: solana_program::pubkey::Pubkey,
+
+    pub tracked_mints: solana_program::pubkey::Pubkey,
+
+    pub ncn: solana_program::pubkey::Pubkey,
+
+    pub weight_table: solana_program::pubkey::Pubkey,
+
+    pub vault: solana_program::pubkey::Pubkey,
+
+    pub vault_ncn_ticket: solana_program::pubkey::Pubkey,
+
+    pub ncn_vault_ticket: solana_program::pubkey::Pubkey,
+
+    pub restaking_program_id: solana_program::pubkey::Pubkey,
+
+    pub vault_program_id: solana_program::pubkey::Pubkey,
+}
+
+impl DeregisterMint {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.restaking_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tracked_mints,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.weight_table,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vault_ncn_ticket,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn_vault_ticket,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.restaking_program_id,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vault_program_id,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = DeregisterMintInstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct DeregisterMintInstructionData {
+    discriminator: u8,
+}
+
+impl DeregisterMintInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 14 }
+    }
+}
+
+impl Default for DeregisterMintInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `DeregisterMint`.
+///
+/// ### Accounts:
+///
+///   0. `[]` restaking_config
+///   1. `[writable]` tracked_mints
+///   2. `[]` ncn
+///   3. `[]` weight_table
+///   4. `[]` vault
+///   5. `[]` vault_ncn_ticket
+///   6. `[]` ncn_vault_ticket
+///   7. `[]` restaking_program_id
+///   8. `[]` vault_program_id
+#[derive(Clone, Debug, Default)]
+pub struct DeregisterMintBuilder {
+    restaking_config: Option<solana_program::pubkey::Pubkey>,
+    tracked_mints: Option<solana_program::pubkey::Pubkey>,
+    ncn: Option<solana_program::pubkey::Pubkey>,
+    weight_table: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    vault_ncn_ticket: Option<solana_program::pubkey::Pubkey>,
+    ncn_vault_ticket: Option<solana_program::pubkey::Pubkey>,
+    restaking_program_id: Option<solana_program::pubkey::Pubkey>,
+    vault_program_id: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl DeregisterMintBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn restaking_config(
+        &mut self,
+        restaking_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.restaking_config = Some(restaking_config);
+        self
+    }
+    #[inline(always)]
+    pub fn tracked_mints(&mut self, tracked_mints: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tracked_mints = Some(tracked_mints);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn weight_table(&mut self, weight_table: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.weight_table = Some(weight_table);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_ncn_ticket(
+        &mut self,
+        vault_ncn_ticket: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_ncn_ticket = Some(vault_ncn_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_vault_ticket(
+        &mut self,
+        ncn_vault_ticket: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.ncn_vault_ticket = Some(ncn_vault_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn restaking_program_id(
+        &mut self,
+        restaking_program_id: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.restaking_program_id = Some(restaking_program_id);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_program_id(
+        &mut self,
+        vault_program_id: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_program_id = Some(vault_program_id);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = DeregisterMint {
+            restaking_config: self.restaking_config.expect("restaking_config is not set"),
+            tracked_mints: self.tracked_mints.expect("tracked_mints is not set"),
+            ncn: self.ncn.expect("ncn is not set"),
+            weight_table: self.weight_table.expect("weight_table is not set"),
+            vault: self.vault.expect("vault is not set"),
+            vault_ncn_ticket: self.vault_ncn_ticket.expect("vault_ncn_ticket is not set"),
+            ncn_vault_ticket: self.ncn_vault_ticket.expect("ncn_vault_ticket is not set"),
+            restaking_program_id: self
+                .restaking_program_id
+                .expect("restaking_program_id is not set"),
+            vault_program_id: self.vault_program_id.expect("vault_program_id is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `deregister_mint` CPI accounts.
+pub struct DeregisterMintCpiAccounts<'a, 'b> {
+    pub restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tracked_mints: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub weight_table: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_ncn_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub restaking_program_id: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_program_id: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `deregister_mint` CPI instruction.
+pub struct DeregisterMintCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tracked_mints: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub weight_table: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_ncn_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub restaking_program_id: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_program_id: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> DeregisterMintCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: DeregisterMintCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            restaking_config: accounts.restaking_config,
+            tracked_mints: accounts.tracked_mints,
+            ncn: accounts.ncn,
+            weight_table: accounts.weight_table,
+            vault: accounts.vault,
+            vault_ncn_ticket: accounts.vault_ncn_ticket,
+            ncn_vault_ticket: accounts.ncn_vault_ticket,
+            restaking_program_id: accounts.restaking_program_id,
+            vault_program_id: accounts.vault_program_id,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.restaking_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tracked_mints.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.weight_table.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vault_ncn_ticket.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn_vault_ticket.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.restaking_program_id.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vault_program_id.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = DeregisterMintInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.restaking_config.clone());
+        account_infos.push(self.tracked_mints.clone());
+        account_infos.push(self.ncn.clone());
+        account_infos.push(self.weight_table.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.vault_ncn_ticket.clone());
+        account_infos.push(self.ncn_vault_ticket.clone());
+        account_infos.push(self.restaking_program_id.clone());
+        account_infos.push(self.vault_program_id.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `DeregisterMint` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` restaking_config
+///   1. `[writable]` tracked_mints
+///   2. `[]` ncn
+///   3. `[]` weight_table
+///   4. `[]` vault
+///   5. `[]` vault_ncn_ticket
+///   6. `[]` ncn_vault_ticket
+///   7. `[]` restaking_program_id
+///   8. `[]` vault_program_id
+#[derive(Clone, Debug)]
+pub struct DeregisterMintCpiBuilder<'a, 'b> {
+    instruction: Box<DeregisterMintCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> DeregisterMintCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(DeregisterMintCpiBuilderInstruction {
+            __program: program,
+            restaking_config: None,
+            tracked_mints: None,
+            ncn: None,
+            weight_table: None,
+            vault: None,
+            vault_ncn_ticket: None,
+            ncn_vault_ticket: None,
+            restaking_program_id: None,
+            vault_program_id: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn restaking_config(
+        &mut self,
+        restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.restaking_config = Some(restaking_config);
+        self
+    }
+    #[inline(always)]
+    pub fn tracked_mints(
+        &mut self,
+        tracked_mints: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tracked_mints = Some(tracked_mints);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn weight_table(
+        &mut self,
+        weight_table: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.weight_table = Some(weight_table);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_ncn_ticket(
+        &mut self,
+        vault_ncn_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_ncn_ticket = Some(vault_ncn_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_vault_ticket(
+        &mut self,
+        ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ncn_vault_ticket = Some(ncn_vault_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn restaking_program_id(
+        &mut self,
+        restaking_program_id: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.restaking_program_id = Some(restaking_program_id);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_program_id(
+        &mut self,
+        vault_program_id: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_program_id = Some(vault_program_id);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = DeregisterMintCpi {
+            __program: self.instruction.__program,
+
+            restaking_config: self
+                .instruction
+                .restaking_config
+                .expect("restaking_config is not set"),
+
+            tracked_mints: self
+                .instruction
+                .tracked_mints
+                .expect("tracked_mints is not set"),
+
+            ncn: self.instruction.ncn.expect("ncn is not set"),
+
+            weight_table: self
+                .instruction
+                .weight_table
+                .expect("weight_table is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            vault_ncn_ticket: self
+                .instruction
+                .vault_ncn_ticket
+                .expect("vault_ncn_ticket is not set"),
+
+            ncn_vault_ticket: self
+                .instruction
+                .ncn_vault_ticket
+                .expect("ncn_vault_ticket is not set"),
+
+            restaking_program_id: self
+                .instruction
+                .restaking_program_id
+                .expect("restaking_program_id is not set"),
+
+            vault_program_id: self
+                .instruction
+                .vault_program_id
+                .expect("vault_program_id is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DeregisterMintCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    restaking_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tracked_mints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    weight_table: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_ncn_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_vault_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    restaking_program_id: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_program_id: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
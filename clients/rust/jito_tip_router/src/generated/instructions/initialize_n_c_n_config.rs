@@ -16,7 +16,9 @@ pub struct InitializeNCNConfig {
 
     pub ncn_admin: solana_program::pubkey::Pubkey,
 
-    pub fee_wallet: solana_program::pubkey::Pubkey,
+    pub dao_fee_wallet: solana_program::pubkey::Pubkey,
+
+    pub ncn_fee_wallet: solana_program::pubkey::Pubkey,
 
     pub tie_breaker_admin: solana_program::pubkey::Pubkey,
 
@@ -38,7 +40,7 @@ impl InitializeNCNConfig {
         args: InitializeNCNConfigInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.restaking_config,
             false,
@@ -55,7 +57,11 @@ impl InitializeNCNConfig {
             true,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.fee_wallet,
+            self.dao_fee_wallet,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn_fee_wallet,
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
@@ -118,17 +124,19 @@ pub struct InitializeNCNConfigInstructionArgs {
 ///   1. `[writable]` ncn_config
 ///   2. `[]` ncn
 ///   3. `[signer]` ncn_admin
-///   4. `[]` fee_wallet
-///   5. `[]` tie_breaker_admin
-///   6. `[]` restaking_program
-///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[]` dao_fee_wallet
+///   5. `[]` ncn_fee_wallet
+///   6. `[]` tie_breaker_admin
+///   7. `[]` restaking_program
+///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct InitializeNCNConfigBuilder {
     restaking_config: Option<solana_program::pubkey::Pubkey>,
     ncn_config: Option<solana_program::pubkey::Pubkey>,
     ncn: Option<solana_program::pubkey::Pubkey>,
     ncn_admin: Option<solana_program::pubkey::Pubkey>,
-    fee_wallet: Option<solana_program::pubkey::Pubkey>,
+    dao_fee_wallet: Option<solana_program::pubkey::Pubkey>,
+    ncn_fee_wallet: Option<solana_program::pubkey::Pubkey>,
     tie_breaker_admin: Option<solana_program::pubkey::Pubkey>,
     restaking_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
@@ -166,8 +174,13 @@ impl InitializeNCNConfigBuilder {
         self
     }
     #[inline(always)]
-    pub fn fee_wallet(&mut self, fee_wallet: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.fee_wallet = Some(fee_wallet);
+    pub fn dao_fee_wallet(&mut self, dao_fee_wallet: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.dao_fee_wallet = Some(dao_fee_wallet);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_fee_wallet(&mut self, ncn_fee_wallet: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ncn_fee_wallet = Some(ncn_fee_wallet);
         self
     }
     #[inline(always)]
@@ -232,7 +245,8 @@ impl InitializeNCNConfigBuilder {
             ncn_config: self.ncn_config.expect("ncn_config is not set"),
             ncn: self.ncn.expect("ncn is not set"),
             ncn_admin: self.ncn_admin.expect("ncn_admin is not set"),
-            fee_wallet: self.fee_wallet.expect("fee_wallet is not set"),
+            dao_fee_wallet: self.dao_fee_wallet.expect("dao_fee_wallet is not set"),
+            ncn_fee_wallet: self.ncn_fee_wallet.expect("ncn_fee_wallet is not set"),
             tie_breaker_admin: self
                 .tie_breaker_admin
                 .expect("tie_breaker_admin is not set"),
@@ -266,7 +280,9 @@ pub struct InitializeNCNConfigCpiAccounts<'a, 'b> {
 
     pub ncn_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
+    pub dao_fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub tie_breaker_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -288,7 +304,9 @@ pub struct InitializeNCNConfigCpi<'a, 'b> {
 
     pub ncn_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
+    pub dao_fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub tie_breaker_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -311,7 +329,8 @@ impl<'a, 'b> InitializeNCNConfigCpi<'a, 'b> {
             ncn_config: accounts.ncn_config,
             ncn: accounts.ncn,
             ncn_admin: accounts.ncn_admin,
-            fee_wallet: accounts.fee_wallet,
+            dao_fee_wallet: accounts.dao_fee_wallet,
+            ncn_fee_wallet: accounts.ncn_fee_wallet,
             tie_breaker_admin: accounts.tie_breaker_admin,
             restaking_program: accounts.restaking_program,
             system_program: accounts.system_program,
@@ -351,7 +370,7 @@ impl<'a, 'b> InitializeNCNConfigCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.restaking_config.key,
             false,
@@ -369,7 +388,11 @@ impl<'a, 'b> InitializeNCNConfigCpi<'a, 'b> {
             true,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.fee_wallet.key,
+            *self.dao_fee_wallet.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn_fee_wallet.key,
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
@@ -402,13 +425,14 @@ impl<'a, 'b> InitializeNCNConfigCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.restaking_config.clone());
         account_infos.push(self.ncn_config.clone());
         account_infos.push(self.ncn.clone());
         account_infos.push(self.ncn_admin.clone());
-        account_infos.push(self.fee_wallet.clone());
+        account_infos.push(self.dao_fee_wallet.clone());
+        account_infos.push(self.ncn_fee_wallet.clone());
         account_infos.push(self.tie_breaker_admin.clone());
         account_infos.push(self.restaking_program.clone());
         account_infos.push(self.system_program.clone());
@@ -432,10 +456,11 @@ impl<'a, 'b> InitializeNCNConfigCpi<'a, 'b> {
 ///   1. `[writable]` ncn_config
 ///   2. `[]` ncn
 ///   3. `[signer]` ncn_admin
-///   4. `[]` fee_wallet
-///   5. `[]` tie_breaker_admin
-///   6. `[]` restaking_program
-///   7. `[]` system_program
+///   4. `[]` dao_fee_wallet
+///   5. `[]` ncn_fee_wallet
+///   6. `[]` tie_breaker_admin
+///   7. `[]` restaking_program
+///   8. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct InitializeNCNConfigCpiBuilder<'a, 'b> {
     instruction: Box<InitializeNCNConfigCpiBuilderInstruction<'a, 'b>>,
@@ -449,7 +474,8 @@ impl<'a, 'b> InitializeNCNConfigCpiBuilder<'a, 'b> {
             ncn_config: None,
             ncn: None,
             ncn_admin: None,
-            fee_wallet: None,
+            dao_fee_wallet: None,
+            ncn_fee_wallet: None,
             tie_breaker_admin: None,
             restaking_program: None,
             system_program: None,
@@ -490,11 +516,19 @@ impl<'a, 'b> InitializeNCNConfigCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn fee_wallet(
+    pub fn dao_fee_wallet(
+        &mut self,
+        dao_fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.dao_fee_wallet = Some(dao_fee_wallet);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_fee_wallet(
         &mut self,
-        fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
+        ncn_fee_wallet: &'b solana_program::account_info::AccountInfo<'a>,
     ) -> &mut Self {
-        self.instruction.fee_wallet = Some(fee_wallet);
+        self.instruction.ncn_fee_wallet = Some(ncn_fee_wallet);
         self
     }
     #[inline(always)]
@@ -608,7 +642,15 @@ impl<'a, 'b> InitializeNCNConfigCpiBuilder<'a, 'b> {
 
             ncn_admin: self.instruction.ncn_admin.expect("ncn_admin is not set"),
 
-            fee_wallet: self.instruction.fee_wallet.expect("fee_wallet is not set"),
+            dao_fee_wallet: self
+                .instruction
+                .dao_fee_wallet
+                .expect("dao_fee_wallet is not set"),
+
+            ncn_fee_wallet: self
+                .instruction
+                .ncn_fee_wallet
+                .expect("ncn_fee_wallet is not set"),
 
             tie_breaker_admin: self
                 .instruction
@@ -640,7 +682,8 @@ struct InitializeNCNConfigCpiBuilderInstruction<'a, 'b> {
     ncn_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    fee_wallet: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    dao_fee_wallet: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_fee_wallet: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     tie_breaker_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     restaking_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -6,23 +6,43 @@
 
 pub(crate) mod r#admin_update_weight_table;
 pub(crate) mod r#cast_vote;
+pub(crate) mod r#close_ballot_box;
+pub(crate) mod r#close_epoch_reward_router;
+pub(crate) mod r#close_epoch_snapshot;
+pub(crate) mod r#close_operator_snapshot;
+pub(crate) mod r#close_vault_snapshot;
+pub(crate) mod r#close_weight_table;
+pub(crate) mod r#deregister_mint;
+pub(crate) mod r#distribute_operator_rewards;
 pub(crate) mod r#initialize_ballot_box;
+pub(crate) mod r#initialize_epoch_reward_router;
 pub(crate) mod r#initialize_epoch_snapshot;
 pub(crate) mod r#initialize_n_c_n_config;
 pub(crate) mod r#initialize_operator_snapshot;
 pub(crate) mod r#initialize_tracked_mints;
+pub(crate) mod r#initialize_vault_snapshot;
 pub(crate) mod r#initialize_weight_table;
+pub(crate) mod r#migrate_ncn_config;
+pub(crate) mod r#realloc_tracked_mints;
 pub(crate) mod r#register_mint;
+pub(crate) mod r#route_ncn_rewards;
 pub(crate) mod r#set_config_fees;
 pub(crate) mod r#set_merkle_root;
 pub(crate) mod r#set_new_admin;
+pub(crate) mod r#set_parameters;
 pub(crate) mod r#set_tie_breaker;
 pub(crate) mod r#snapshot_vault_operator_delegation;
+pub(crate) mod r#snapshot_vault_operator_delegations;
 
 pub use self::{
-    r#admin_update_weight_table::*, r#cast_vote::*, r#initialize_ballot_box::*,
-    r#initialize_epoch_snapshot::*, r#initialize_n_c_n_config::*,
-    r#initialize_operator_snapshot::*, r#initialize_tracked_mints::*, r#initialize_weight_table::*,
-    r#register_mint::*, r#set_config_fees::*, r#set_merkle_root::*, r#set_new_admin::*,
-    r#set_tie_breaker::*, r#snapshot_vault_operator_delegation::*,
+    r#admin_update_weight_table::*, r#cast_vote::*, r#close_ballot_box::*,
+    r#close_epoch_reward_router::*, r#close_epoch_snapshot::*, r#close_operator_snapshot::*,
+    r#close_vault_snapshot::*, r#close_weight_table::*, r#deregister_mint::*,
+    r#distribute_operator_rewards::*, r#initialize_ballot_box::*,
+    r#initialize_epoch_reward_router::*, r#initialize_epoch_snapshot::*,
+    r#initialize_n_c_n_config::*, r#initialize_operator_snapshot::*, r#initialize_tracked_mints::*,
+    r#initialize_vault_snapshot::*, r#initialize_weight_table::*, r#migrate_ncn_config::*,
+    r#realloc_tracked_mints::*, r#register_mint::*, r#route_ncn_rewards::*, r#set_config_fees::*,
+    r#set_merkle_root::*, r#set_new_admin::*, r#set_parameters::*, r#set_tie_breaker::*,
+    r#snapshot_vault_operator_delegation::*, r#snapshot_vault_operator_delegations::*,
 };
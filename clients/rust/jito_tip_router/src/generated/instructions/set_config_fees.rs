@@ -89,7 +89,8 @@ pub struct SetConfigFeesInstructionArgs {
     pub new_dao_fee_bps: Option<u64>,
     pub new_ncn_fee_bps: Option<u64>,
     pub new_block_engine_fee_bps: Option<u64>,
-    pub new_fee_wallet: Option<Pubkey>,
+    pub new_dao_fee_wallet: Option<Pubkey>,
+    pub new_ncn_fee_wallet: Option<Pubkey>,
 }
 
 /// Instruction builder for `SetConfigFees`.
@@ -111,7 +112,8 @@ pub struct SetConfigFeesBuilder {
     new_dao_fee_bps: Option<u64>,
     new_ncn_fee_bps: Option<u64>,
     new_block_engine_fee_bps: Option<u64>,
-    new_fee_wallet: Option<Pubkey>,
+    new_dao_fee_wallet: Option<Pubkey>,
+    new_ncn_fee_wallet: Option<Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -170,8 +172,14 @@ impl SetConfigFeesBuilder {
     }
     /// `[optional argument]`
     #[inline(always)]
-    pub fn new_fee_wallet(&mut self, new_fee_wallet: Pubkey) -> &mut Self {
-        self.new_fee_wallet = Some(new_fee_wallet);
+    pub fn new_dao_fee_wallet(&mut self, new_dao_fee_wallet: Pubkey) -> &mut Self {
+        self.new_dao_fee_wallet = Some(new_dao_fee_wallet);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_ncn_fee_wallet(&mut self, new_ncn_fee_wallet: Pubkey) -> &mut Self {
+        self.new_ncn_fee_wallet = Some(new_ncn_fee_wallet);
         self
     }
     /// Add an additional account to the instruction.
@@ -207,7 +215,8 @@ impl SetConfigFeesBuilder {
             new_dao_fee_bps: self.new_dao_fee_bps.clone(),
             new_ncn_fee_bps: self.new_ncn_fee_bps.clone(),
             new_block_engine_fee_bps: self.new_block_engine_fee_bps.clone(),
-            new_fee_wallet: self.new_fee_wallet.clone(),
+            new_dao_fee_wallet: self.new_dao_fee_wallet.clone(),
+            new_ncn_fee_wallet: self.new_ncn_fee_wallet.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -376,7 +385,8 @@ impl<'a, 'b> SetConfigFeesCpiBuilder<'a, 'b> {
             new_dao_fee_bps: None,
             new_ncn_fee_bps: None,
             new_block_engine_fee_bps: None,
-            new_fee_wallet: None,
+            new_dao_fee_wallet: None,
+            new_ncn_fee_wallet: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -438,8 +448,14 @@ impl<'a, 'b> SetConfigFeesCpiBuilder<'a, 'b> {
     }
     /// `[optional argument]`
     #[inline(always)]
-    pub fn new_fee_wallet(&mut self, new_fee_wallet: Pubkey) -> &mut Self {
-        self.instruction.new_fee_wallet = Some(new_fee_wallet);
+    pub fn new_dao_fee_wallet(&mut self, new_dao_fee_wallet: Pubkey) -> &mut Self {
+        self.instruction.new_dao_fee_wallet = Some(new_dao_fee_wallet);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_ncn_fee_wallet(&mut self, new_ncn_fee_wallet: Pubkey) -> &mut Self {
+        self.instruction.new_ncn_fee_wallet = Some(new_ncn_fee_wallet);
         self
     }
     /// Add an additional account to the instruction.
@@ -487,7 +503,8 @@ impl<'a, 'b> SetConfigFeesCpiBuilder<'a, 'b> {
             new_dao_fee_bps: self.instruction.new_dao_fee_bps.clone(),
             new_ncn_fee_bps: self.instruction.new_ncn_fee_bps.clone(),
             new_block_engine_fee_bps: self.instruction.new_block_engine_fee_bps.clone(),
-            new_fee_wallet: self.instruction.new_fee_wallet.clone(),
+            new_dao_fee_wallet: self.instruction.new_dao_fee_wallet.clone(),
+            new_ncn_fee_wallet: self.instruction.new_ncn_fee_wallet.clone(),
         };
         let instruction = SetConfigFeesCpi {
             __program: self.instruction.__program,
@@ -527,7 +544,8 @@ struct SetConfigFeesCpiBuilderInstruction<'a, 'b> {
     new_dao_fee_bps: Option<u64>,
     new_ncn_fee_bps: Option<u64>,
     new_block_engine_fee_bps: Option<u64>,
-    new_fee_wallet: Option<Pubkey>,
+    new_dao_fee_wallet: Option<Pubkey>,
+    new_ncn_fee_wallet: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
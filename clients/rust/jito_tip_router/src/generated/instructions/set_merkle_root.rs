@@ -10,6 +10,8 @@ use borsh::{BorshDeserialize, BorshSerialize};
 pub struct SetMerkleRoot {
     pub ncn_config: solana_program::pubkey::Pubkey,
 
+    pub restaking_config: solana_program::pubkey::Pubkey,
+
     pub ncn: solana_program::pubkey::Pubkey,
 
     pub ballot_box: solana_program::pubkey::Pubkey,
@@ -38,11 +40,15 @@ impl SetMerkleRoot {
         args: SetMerkleRootInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.ncn_config,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.restaking_config,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.ncn, false,
         ));
@@ -108,6 +114,7 @@ pub struct SetMerkleRootInstructionArgs {
     pub max_total_claim: u64,
     pub max_num_nodes: u64,
     pub epoch: u64,
+    pub tip_distribution_epoch: u64,
 }
 
 /// Instruction builder for `SetMerkleRoot`.
@@ -115,16 +122,18 @@ pub struct SetMerkleRootInstructionArgs {
 /// ### Accounts:
 ///
 ///   0. `[writable]` ncn_config
-///   1. `[]` ncn
-///   2. `[]` ballot_box
-///   3. `[]` vote_account
-///   4. `[writable]` tip_distribution_account
-///   5. `[]` tip_distribution_config
-///   6. `[]` tip_distribution_program
-///   7. `[]` restaking_program
+///   1. `[]` restaking_config
+///   2. `[]` ncn
+///   3. `[]` ballot_box
+///   4. `[]` vote_account
+///   5. `[writable]` tip_distribution_account
+///   6. `[]` tip_distribution_config
+///   7. `[]` tip_distribution_program
+///   8. `[]` restaking_program
 #[derive(Clone, Debug, Default)]
 pub struct SetMerkleRootBuilder {
     ncn_config: Option<solana_program::pubkey::Pubkey>,
+    restaking_config: Option<solana_program::pubkey::Pubkey>,
     ncn: Option<solana_program::pubkey::Pubkey>,
     ballot_box: Option<solana_program::pubkey::Pubkey>,
     vote_account: Option<solana_program::pubkey::Pubkey>,
@@ -137,6 +146,7 @@ pub struct SetMerkleRootBuilder {
     max_total_claim: Option<u64>,
     max_num_nodes: Option<u64>,
     epoch: Option<u64>,
+    tip_distribution_epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -150,6 +160,14 @@ impl SetMerkleRootBuilder {
         self
     }
     #[inline(always)]
+    pub fn restaking_config(
+        &mut self,
+        restaking_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.restaking_config = Some(restaking_config);
+        self
+    }
+    #[inline(always)]
     pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
         self.ncn = Some(ncn);
         self
@@ -221,6 +239,11 @@ impl SetMerkleRootBuilder {
         self.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn tip_distribution_epoch(&mut self, tip_distribution_epoch: u64) -> &mut Self {
+        self.tip_distribution_epoch = Some(tip_distribution_epoch);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -243,6 +266,7 @@ impl SetMerkleRootBuilder {
     pub fn instruction(&self) -> solana_program::instruction::Instruction {
         let accounts = SetMerkleRoot {
             ncn_config: self.ncn_config.expect("ncn_config is not set"),
+            restaking_config: self.restaking_config.expect("restaking_config is not set"),
             ncn: self.ncn.expect("ncn is not set"),
             ballot_box: self.ballot_box.expect("ballot_box is not set"),
             vote_account: self.vote_account.expect("vote_account is not set"),
@@ -271,6 +295,10 @@ impl SetMerkleRootBuilder {
                 .clone()
                 .expect("max_num_nodes is not set"),
             epoch: self.epoch.clone().expect("epoch is not set"),
+            tip_distribution_epoch: self
+                .tip_distribution_epoch
+                .clone()
+                .expect("tip_distribution_epoch is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -281,6 +309,8 @@ impl SetMerkleRootBuilder {
 pub struct SetMerkleRootCpiAccounts<'a, 'b> {
     pub ncn_config: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ballot_box: &'b solana_program::account_info::AccountInfo<'a>,
@@ -303,6 +333,8 @@ pub struct SetMerkleRootCpi<'a, 'b> {
 
     pub ncn_config: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ballot_box: &'b solana_program::account_info::AccountInfo<'a>,
@@ -329,6 +361,7 @@ impl<'a, 'b> SetMerkleRootCpi<'a, 'b> {
         Self {
             __program: program,
             ncn_config: accounts.ncn_config,
+            restaking_config: accounts.restaking_config,
             ncn: accounts.ncn,
             ballot_box: accounts.ballot_box,
             vote_account: accounts.vote_account,
@@ -372,11 +405,15 @@ impl<'a, 'b> SetMerkleRootCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.ncn_config.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.restaking_config.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.ncn.key,
             false,
@@ -421,9 +458,10 @@ impl<'a, 'b> SetMerkleRootCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.ncn_config.clone());
+        account_infos.push(self.restaking_config.clone());
         account_infos.push(self.ncn.clone());
         account_infos.push(self.ballot_box.clone());
         account_infos.push(self.vote_account.clone());
@@ -448,13 +486,14 @@ impl<'a, 'b> SetMerkleRootCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[writable]` ncn_config
-///   1. `[]` ncn
-///   2. `[]` ballot_box
-///   3. `[]` vote_account
-///   4. `[writable]` tip_distribution_account
-///   5. `[]` tip_distribution_config
-///   6. `[]` tip_distribution_program
-///   7. `[]` restaking_program
+///   1. `[]` restaking_config
+///   2. `[]` ncn
+///   3. `[]` ballot_box
+///   4. `[]` vote_account
+///   5. `[writable]` tip_distribution_account
+///   6. `[]` tip_distribution_config
+///   7. `[]` tip_distribution_program
+///   8. `[]` restaking_program
 #[derive(Clone, Debug)]
 pub struct SetMerkleRootCpiBuilder<'a, 'b> {
     instruction: Box<SetMerkleRootCpiBuilderInstruction<'a, 'b>>,
@@ -465,6 +504,7 @@ impl<'a, 'b> SetMerkleRootCpiBuilder<'a, 'b> {
         let instruction = Box::new(SetMerkleRootCpiBuilderInstruction {
             __program: program,
             ncn_config: None,
+            restaking_config: None,
             ncn: None,
             ballot_box: None,
             vote_account: None,
@@ -477,6 +517,7 @@ impl<'a, 'b> SetMerkleRootCpiBuilder<'a, 'b> {
             max_total_claim: None,
             max_num_nodes: None,
             epoch: None,
+            tip_distribution_epoch: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -490,6 +531,14 @@ impl<'a, 'b> SetMerkleRootCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn restaking_config(
+        &mut self,
+        restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.restaking_config = Some(restaking_config);
+        self
+    }
+    #[inline(always)]
     pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.ncn = Some(ncn);
         self
@@ -567,6 +616,11 @@ impl<'a, 'b> SetMerkleRootCpiBuilder<'a, 'b> {
         self.instruction.epoch = Some(epoch);
         self
     }
+    #[inline(always)]
+    pub fn tip_distribution_epoch(&mut self, tip_distribution_epoch: u64) -> &mut Self {
+        self.instruction.tip_distribution_epoch = Some(tip_distribution_epoch);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -626,12 +680,22 @@ impl<'a, 'b> SetMerkleRootCpiBuilder<'a, 'b> {
                 .clone()
                 .expect("max_num_nodes is not set"),
             epoch: self.instruction.epoch.clone().expect("epoch is not set"),
+            tip_distribution_epoch: self
+                .instruction
+                .tip_distribution_epoch
+                .clone()
+                .expect("tip_distribution_epoch is not set"),
         };
         let instruction = SetMerkleRootCpi {
             __program: self.instruction.__program,
 
             ncn_config: self.instruction.ncn_config.expect("ncn_config is not set"),
 
+            restaking_config: self
+                .instruction
+                .restaking_config
+                .expect("restaking_config is not set"),
+
             ncn: self.instruction.ncn.expect("ncn is not set"),
 
             ballot_box: self.instruction.ballot_box.expect("ballot_box is not set"),
@@ -673,6 +737,7 @@ impl<'a, 'b> SetMerkleRootCpiBuilder<'a, 'b> {
 struct SetMerkleRootCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     ncn_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    restaking_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ballot_box: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vote_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -685,6 +750,7 @@ struct SetMerkleRootCpiBuilderInstruction<'a, 'b> {
     max_total_claim: Option<u64>,
     max_num_nodes: Option<u64>,
     epoch: Option<u64>,
+    tip_distribution_epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
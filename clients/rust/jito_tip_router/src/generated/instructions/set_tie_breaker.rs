@@ -10,6 +10,8 @@ use borsh::{BorshDeserialize, BorshSerialize};
 pub struct SetTieBreaker {
     pub ncn_config: solana_program::pubkey::Pubkey,
 
+    pub restaking_config: solana_program::pubkey::Pubkey,
+
     pub ballot_box: solana_program::pubkey::Pubkey,
 
     pub ncn: solana_program::pubkey::Pubkey,
@@ -32,11 +34,15 @@ impl SetTieBreaker {
         args: SetTieBreakerInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.ncn_config,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.restaking_config,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.ballot_box,
             false,
@@ -94,13 +100,15 @@ pub struct SetTieBreakerInstructionArgs {
 /// ### Accounts:
 ///
 ///   0. `[]` ncn_config
-///   1. `[writable]` ballot_box
-///   2. `[]` ncn
-///   3. `[signer]` tie_breaker_admin
-///   4. `[]` restaking_program
+///   1. `[]` restaking_config
+///   2. `[writable]` ballot_box
+///   3. `[]` ncn
+///   4. `[signer]` tie_breaker_admin
+///   5. `[]` restaking_program
 #[derive(Clone, Debug, Default)]
 pub struct SetTieBreakerBuilder {
     ncn_config: Option<solana_program::pubkey::Pubkey>,
+    restaking_config: Option<solana_program::pubkey::Pubkey>,
     ballot_box: Option<solana_program::pubkey::Pubkey>,
     ncn: Option<solana_program::pubkey::Pubkey>,
     tie_breaker_admin: Option<solana_program::pubkey::Pubkey>,
@@ -120,6 +128,14 @@ impl SetTieBreakerBuilder {
         self
     }
     #[inline(always)]
+    pub fn restaking_config(
+        &mut self,
+        restaking_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.restaking_config = Some(restaking_config);
+        self
+    }
+    #[inline(always)]
     pub fn ballot_box(&mut self, ballot_box: solana_program::pubkey::Pubkey) -> &mut Self {
         self.ballot_box = Some(ballot_box);
         self
@@ -177,6 +193,7 @@ impl SetTieBreakerBuilder {
     pub fn instruction(&self) -> solana_program::instruction::Instruction {
         let accounts = SetTieBreaker {
             ncn_config: self.ncn_config.expect("ncn_config is not set"),
+            restaking_config: self.restaking_config.expect("restaking_config is not set"),
             ballot_box: self.ballot_box.expect("ballot_box is not set"),
             ncn: self.ncn.expect("ncn is not set"),
             tie_breaker_admin: self
@@ -202,6 +219,8 @@ impl SetTieBreakerBuilder {
 pub struct SetTieBreakerCpiAccounts<'a, 'b> {
     pub ncn_config: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub ballot_box: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
@@ -218,6 +237,8 @@ pub struct SetTieBreakerCpi<'a, 'b> {
 
     pub ncn_config: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub ballot_box: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
@@ -238,6 +259,7 @@ impl<'a, 'b> SetTieBreakerCpi<'a, 'b> {
         Self {
             __program: program,
             ncn_config: accounts.ncn_config,
+            restaking_config: accounts.restaking_config,
             ballot_box: accounts.ballot_box,
             ncn: accounts.ncn,
             tie_breaker_admin: accounts.tie_breaker_admin,
@@ -278,11 +300,15 @@ impl<'a, 'b> SetTieBreakerCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.ncn_config.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.restaking_config.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.ballot_box.key,
             false,
@@ -315,9 +341,10 @@ impl<'a, 'b> SetTieBreakerCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.ncn_config.clone());
+        account_infos.push(self.restaking_config.clone());
         account_infos.push(self.ballot_box.clone());
         account_infos.push(self.ncn.clone());
         account_infos.push(self.tie_breaker_admin.clone());
@@ -339,10 +366,11 @@ impl<'a, 'b> SetTieBreakerCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[]` ncn_config
-///   1. `[writable]` ballot_box
-///   2. `[]` ncn
-///   3. `[signer]` tie_breaker_admin
-///   4. `[]` restaking_program
+///   1. `[]` restaking_config
+///   2. `[writable]` ballot_box
+///   3. `[]` ncn
+///   4. `[signer]` tie_breaker_admin
+///   5. `[]` restaking_program
 #[derive(Clone, Debug)]
 pub struct SetTieBreakerCpiBuilder<'a, 'b> {
     instruction: Box<SetTieBreakerCpiBuilderInstruction<'a, 'b>>,
@@ -353,6 +381,7 @@ impl<'a, 'b> SetTieBreakerCpiBuilder<'a, 'b> {
         let instruction = Box::new(SetTieBreakerCpiBuilderInstruction {
             __program: program,
             ncn_config: None,
+            restaking_config: None,
             ballot_box: None,
             ncn: None,
             tie_breaker_admin: None,
@@ -372,6 +401,14 @@ impl<'a, 'b> SetTieBreakerCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn restaking_config(
+        &mut self,
+        restaking_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.restaking_config = Some(restaking_config);
+        self
+    }
+    #[inline(always)]
     pub fn ballot_box(
         &mut self,
         ballot_box: &'b solana_program::account_info::AccountInfo<'a>,
@@ -464,6 +501,11 @@ impl<'a, 'b> SetTieBreakerCpiBuilder<'a, 'b> {
 
             ncn_config: self.instruction.ncn_config.expect("ncn_config is not set"),
 
+            restaking_config: self
+                .instruction
+                .restaking_config
+                .expect("restaking_config is not set"),
+
             ballot_box: self.instruction.ballot_box.expect("ballot_box is not set"),
 
             ncn: self.instruction.ncn.expect("ncn is not set"),
@@ -490,6 +532,7 @@ impl<'a, 'b> SetTieBreakerCpiBuilder<'a, 'b> {
 struct SetTieBreakerCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     ncn_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    restaking_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ballot_box: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     tie_breaker_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
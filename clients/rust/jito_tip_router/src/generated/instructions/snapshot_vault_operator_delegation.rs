@@ -30,6 +30,8 @@ pub struct SnapshotVaultOperatorDelegation {
 
     pub operator_snapshot: solana_program::pubkey::Pubkey,
 
+    pub vault_snapshot: solana_program::pubkey::Pubkey,
+
     pub vault_program: solana_program::pubkey::Pubkey,
 
     pub restaking_program: solana_program::pubkey::Pubkey,
@@ -48,7 +50,7 @@ impl SnapshotVaultOperatorDelegation {
         args: SnapshotVaultOperatorDelegationInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.ncn_config,
             false,
@@ -91,6 +93,10 @@ impl SnapshotVaultOperatorDelegation {
             self.operator_snapshot,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_snapshot,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.vault_program,
             false,
@@ -152,8 +158,9 @@ pub struct SnapshotVaultOperatorDelegationInstructionArgs {
 ///   8. `[]` weight_table
 ///   9. `[writable]` epoch_snapshot
 ///   10. `[writable]` operator_snapshot
-///   11. `[]` vault_program
-///   12. `[]` restaking_program
+///   11. `[writable]` vault_snapshot
+///   12. `[]` vault_program
+///   13. `[]` restaking_program
 #[derive(Clone, Debug, Default)]
 pub struct SnapshotVaultOperatorDelegationBuilder {
     ncn_config: Option<solana_program::pubkey::Pubkey>,
@@ -167,6 +174,7 @@ pub struct SnapshotVaultOperatorDelegationBuilder {
     weight_table: Option<solana_program::pubkey::Pubkey>,
     epoch_snapshot: Option<solana_program::pubkey::Pubkey>,
     operator_snapshot: Option<solana_program::pubkey::Pubkey>,
+    vault_snapshot: Option<solana_program::pubkey::Pubkey>,
     vault_program: Option<solana_program::pubkey::Pubkey>,
     restaking_program: Option<solana_program::pubkey::Pubkey>,
     first_slot_of_ncn_epoch: Option<u64>,
@@ -248,6 +256,11 @@ impl SnapshotVaultOperatorDelegationBuilder {
         self
     }
     #[inline(always)]
+    pub fn vault_snapshot(&mut self, vault_snapshot: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault_snapshot = Some(vault_snapshot);
+        self
+    }
+    #[inline(always)]
     pub fn vault_program(&mut self, vault_program: solana_program::pubkey::Pubkey) -> &mut Self {
         self.vault_program = Some(vault_program);
         self
@@ -302,6 +315,7 @@ impl SnapshotVaultOperatorDelegationBuilder {
             operator_snapshot: self
                 .operator_snapshot
                 .expect("operator_snapshot is not set"),
+            vault_snapshot: self.vault_snapshot.expect("vault_snapshot is not set"),
             vault_program: self.vault_program.expect("vault_program is not set"),
             restaking_program: self
                 .restaking_program
@@ -339,6 +353,8 @@ pub struct SnapshotVaultOperatorDelegationCpiAccounts<'a, 'b> {
 
     pub operator_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub vault_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub vault_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub restaking_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -371,6 +387,8 @@ pub struct SnapshotVaultOperatorDelegationCpi<'a, 'b> {
 
     pub operator_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub vault_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub vault_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub restaking_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -397,6 +415,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
             weight_table: accounts.weight_table,
             epoch_snapshot: accounts.epoch_snapshot,
             operator_snapshot: accounts.operator_snapshot,
+            vault_snapshot: accounts.vault_snapshot,
             vault_program: accounts.vault_program,
             restaking_program: accounts.restaking_program,
             __args: args,
@@ -435,7 +454,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.ncn_config.key,
             false,
@@ -480,6 +499,10 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
             *self.operator_snapshot.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_snapshot.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.vault_program.key,
             false,
@@ -506,7 +529,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.ncn_config.clone());
         account_infos.push(self.restaking_config.clone());
@@ -519,6 +542,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
         account_infos.push(self.weight_table.clone());
         account_infos.push(self.epoch_snapshot.clone());
         account_infos.push(self.operator_snapshot.clone());
+        account_infos.push(self.vault_snapshot.clone());
         account_infos.push(self.vault_program.clone());
         account_infos.push(self.restaking_program.clone());
         remaining_accounts
@@ -548,8 +572,9 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
 ///   8. `[]` weight_table
 ///   9. `[writable]` epoch_snapshot
 ///   10. `[writable]` operator_snapshot
-///   11. `[]` vault_program
-///   12. `[]` restaking_program
+///   11. `[writable]` vault_snapshot
+///   12. `[]` vault_program
+///   13. `[]` restaking_program
 #[derive(Clone, Debug)]
 pub struct SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
     instruction: Box<SnapshotVaultOperatorDelegationCpiBuilderInstruction<'a, 'b>>,
@@ -570,6 +595,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
             weight_table: None,
             epoch_snapshot: None,
             operator_snapshot: None,
+            vault_snapshot: None,
             vault_program: None,
             restaking_program: None,
             first_slot_of_ncn_epoch: None,
@@ -660,6 +686,14 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn vault_snapshot(
+        &mut self,
+        vault_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_snapshot = Some(vault_snapshot);
+        self
+    }
+    #[inline(always)]
     pub fn vault_program(
         &mut self,
         vault_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -771,6 +805,11 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
                 .operator_snapshot
                 .expect("operator_snapshot is not set"),
 
+            vault_snapshot: self
+                .instruction
+                .vault_snapshot
+                .expect("vault_snapshot is not set"),
+
             vault_program: self
                 .instruction
                 .vault_program
@@ -803,6 +842,7 @@ struct SnapshotVaultOperatorDelegationCpiBuilderInstruction<'a, 'b> {
     weight_table: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     restaking_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     first_slot_of_ncn_epoch: Option<u64>,
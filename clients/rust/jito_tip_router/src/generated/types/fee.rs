@@ -14,7 +14,12 @@ pub struct Fee {
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
-    pub wallet: Pubkey,
+    pub dao_fee_wallet: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn_fee_wallet: Pubkey,
     pub dao_share_bps: u64,
     pub ncn_share_bps: u64,
     pub block_engine_fee_bps: u64,
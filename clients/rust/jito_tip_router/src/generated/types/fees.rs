@@ -13,4 +13,7 @@ use crate::generated::types::Fee;
 pub struct Fees {
     pub fee1: Fee,
     pub fee2: Fee,
+    pub activation_delay_epochs: u64,
+    pub fee_history_len: u64,
+    pub fee_history: [Fee; 8],
 }
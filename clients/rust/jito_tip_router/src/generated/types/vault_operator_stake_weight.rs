@@ -17,5 +17,6 @@ pub struct VaultOperatorStakeWeight {
     pub vault: Pubkey,
     pub stake_weight: u128,
     pub vault_index: u64,
-    pub reserved: [u8; 32],
+    pub exclusion_reason: u8,
+    pub reserved: [u8; 31],
 }
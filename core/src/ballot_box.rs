@@ -189,7 +189,10 @@ pub struct BallotBox {
     slot_created: PodU64,
     slot_consensus_reached: PodU64,
 
-    reserved: [u8; 128],
+    /// Account that paid for the ballot box, refunded when it is closed
+    payer: Pubkey,
+
+    reserved: [u8; 96],
 
     operators_voted: PodU64,
     unique_ballots: PodU64,
@@ -206,7 +209,7 @@ impl Discriminator for BallotBox {
 }
 
 impl BallotBox {
-    pub fn new(ncn: Pubkey, epoch: u64, bump: u8, current_slot: u64) -> Self {
+    pub fn new(ncn: Pubkey, epoch: u64, bump: u8, current_slot: u64, payer: Pubkey) -> Self {
         Self {
             ncn,
             epoch: PodU64::from(epoch),
@@ -219,12 +222,20 @@ impl BallotBox {
             //TODO fix 32 -> MAX_OPERATORS
             operator_votes: [OperatorVote::default(); 32],
             ballot_tallies: [BallotTally::default(); 32],
-            reserved: [0; 128],
+            payer,
+            reserved: [0; 96],
         }
     }
 
-    pub fn initialize(&mut self, ncn: Pubkey, epoch: u64, bump: u8, current_slot: u64) {
-        *self = Self::new(ncn, epoch, bump, current_slot);
+    pub fn initialize(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        bump: u8,
+        current_slot: u64,
+        payer: Pubkey,
+    ) {
+        *self = Self::new(ncn, epoch, bump, current_slot, payer);
     }
 
     pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
@@ -287,6 +298,10 @@ impl BallotBox {
         self.epoch.into()
     }
 
+    pub const fn payer(&self) -> Pubkey {
+        self.payer
+    }
+
     pub fn slot_consensus_reached(&self) -> u64 {
         self.slot_consensus_reached.into()
     }
@@ -540,7 +555,7 @@ mod tests {
         // Create merkle tree of merkle trees
 
         // Intialize ballot box
-        let ballot_box = BallotBox::new(Pubkey::default(), 0, 0, 0);
+        let ballot_box = BallotBox::new(Pubkey::default(), 0, 0, 0, Pubkey::default());
 
         // Set winning merkle root, don't care about anything else
         ballot_box
@@ -556,7 +571,7 @@ mod tests {
         let epoch = 1;
         let stake_weight: u128 = 1000;
         let valid_slots_after_consensus = 10;
-        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot);
+        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot, Pubkey::default());
         let ballot = Ballot::new([1; 32]);
 
         // Test initial cast vote
@@ -636,7 +651,7 @@ mod tests {
 
     #[test]
     fn test_increment_or_create_ballot_tally() {
-        let mut ballot_box = BallotBox::new(Pubkey::new_unique(), 1, 1, 1);
+        let mut ballot_box = BallotBox::new(Pubkey::new_unique(), 1, 1, 1, Pubkey::default());
         let ballot = Ballot::new([1u8; 32]);
         let stake_weight = 100;
 
@@ -690,7 +705,7 @@ mod tests {
         let epoch = 1;
         let stake_weight: u128 = 1000;
         let total_stake_weight: u128 = 1000;
-        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot);
+        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot, Pubkey::default());
         let ballot = Ballot::new([1; 32]);
 
         // Test no consensus when below threshold
@@ -734,7 +749,7 @@ mod tests {
         assert_eq!(ballot_box.get_winning_ballot().unwrap(), ballot);
 
         // Test with multiple competing ballots
-        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot);
+        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot, Pubkey::default());
         let ballot1 = Ballot::new([1; 32]);
         let ballot2 = Ballot::new([2; 32]);
         let ballot3 = Ballot::new([3; 32]);
@@ -770,7 +785,7 @@ mod tests {
         let ncn = Pubkey::new_unique();
         let epoch = 0;
         let current_slot = 1000;
-        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot);
+        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot, Pubkey::default());
 
         // Create some initial ballots
         let ballot1 = Ballot::new([1; 32]);
@@ -801,7 +816,7 @@ mod tests {
         assert_eq!(ballot_box.get_winning_ballot().unwrap(), ballot1);
 
         // Test setting tie breaker with invalid merkle root
-        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot);
+        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot, Pubkey::default());
         ballot_box
             .increment_or_create_ballot_tally(&ballot1, stake_weight)
             .unwrap();
@@ -811,7 +826,7 @@ mod tests {
         );
 
         // Test setting tie breaker when consensus already reached
-        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot);
+        let mut ballot_box = BallotBox::new(ncn, epoch, 0, current_slot, Pubkey::default());
         ballot_box
             .increment_or_create_ballot_tally(&ballot1, stake_weight * 2)
             .unwrap();
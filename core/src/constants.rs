@@ -5,6 +5,8 @@ use crate::error::TipRouterError;
 pub const MAX_FEE_BPS: u64 = 10_000;
 pub const MAX_OPERATORS: usize = 256;
 pub const MAX_VAULT_OPERATOR_DELEGATIONS: usize = 64;
+pub const MAX_TRACKED_MINTS: usize = 64;
+pub const MAX_FEE_HISTORY: usize = 8;
 const PRECISE_CONSENSUS_NUMERATOR: u128 = 2;
 const PRECISE_CONSENSUS_DENOMINATOR: u128 = 3;
 pub fn precise_consensus() -> Result<PreciseNumber, TipRouterError> {
@@ -18,3 +20,12 @@ pub fn precise_consensus() -> Result<PreciseNumber, TipRouterError> {
 }
 
 pub const DEFAULT_CONSENSUS_REACHED_SLOT: u64 = u64::MAX;
+pub const DEFAULT_EPOCHS_BEFORE_CLOSE: u64 = 10;
+/// Snapshots can be taken at any slot of the NCN epoch until a window is configured
+pub const DEFAULT_SNAPSHOT_WINDOW_SLOTS: u64 = u64::MAX;
+pub const DEFAULT_SNAPSHOT_GRACE_EPOCHS: u64 = 1;
+/// Fee changes take effect this many epochs after they are set, giving stakers notice
+pub const DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS: u64 = 3;
+/// Compute units kept in reserve for each vault of a batched delegation snapshot. The batch
+/// stops before a vault when fewer remain, so it never fails for lack of compute.
+pub const SNAPSHOT_COMPUTE_UNITS_PER_VAULT: u64 = 50_000;
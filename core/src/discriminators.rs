@@ -7,7 +7,9 @@ pub enum Discriminators {
     WeightTable = 0x10,
     EpochSnapshot = 0x11,
     OperatorSnapshot = 0x12,
+    VaultSnapshot = 0x13,
     // Voting
     BallotBox = 0x20,
     // Distribution
+    EpochRewardRouter = 0x30,
 }
@@ -0,0 +1,409 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::{ShankAccount, ShankType};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::MAX_FEE_BPS, discriminators::Discriminators,
+    epoch_snapshot::VaultOperatorStakeWeight, error::TipRouterError, fees::Fee,
+};
+
+/// Receives the NCN share of tips for an epoch and routes it to the fee wallets,
+/// operators and vaults
+// PDA'd ["epoch_reward_router", NCN, NCN_EPOCH_SLOT]
+#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct EpochRewardRouter {
+    ncn: Pubkey,
+
+    /// The NCN epoch whose rewards are routed through this account
+    ncn_epoch: PodU64,
+
+    /// Bump seed for the PDA
+    bump: u8,
+
+    slot_created: PodU64,
+
+    /// Lamports routed so far, before any fees
+    total_rewards: PodU64,
+
+    /// Lamports paid to the DAO fee wallet
+    dao_rewards: PodU64,
+
+    /// Lamports paid to the NCN fee wallet
+    ncn_rewards: PodU64,
+
+    /// Lamports set aside for operators and their vaults
+    operator_rewards: PodU64,
+
+    /// Portion of `operator_rewards` already paid out
+    operator_rewards_distributed: PodU64,
+
+    /// Operators of the epoch snapshot, recorded when rewards are routed
+    operator_count: PodU64,
+
+    /// Account that paid for the router
+    payer: Pubkey,
+
+    /// Reserved space
+    reserved: [u8; 120],
+}
+
+impl Discriminator for EpochRewardRouter {
+    const DISCRIMINATOR: u8 = Discriminators::EpochRewardRouter as u8;
+}
+
+impl EpochRewardRouter {
+    pub fn new(ncn: Pubkey, ncn_epoch: u64, bump: u8, current_slot: u64, payer: Pubkey) -> Self {
+        Self {
+            ncn,
+            ncn_epoch: PodU64::from(ncn_epoch),
+            bump,
+            slot_created: PodU64::from(current_slot),
+            total_rewards: PodU64::from(0),
+            dao_rewards: PodU64::from(0),
+            ncn_rewards: PodU64::from(0),
+            operator_rewards: PodU64::from(0),
+            operator_rewards_distributed: PodU64::from(0),
+            operator_count: PodU64::from(0),
+            payer,
+            reserved: [0; 120],
+        }
+    }
+
+    pub fn seeds(ncn: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
+        Vec::from_iter(
+            [
+                b"epoch_reward_router".to_vec(),
+                ncn.to_bytes().to_vec(),
+                ncn_epoch.to_le_bytes().to_vec(),
+            ]
+            .iter()
+            .cloned(),
+        )
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        ncn_epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, ncn_epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        ncn_epoch: u64,
+        epoch_reward_router: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if epoch_reward_router.owner.ne(program_id) {
+            msg!("Epoch Reward Router account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if epoch_reward_router.data_is_empty() {
+            msg!("Epoch Reward Router account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !epoch_reward_router.is_writable {
+            msg!("Epoch Reward Router account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if epoch_reward_router.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Epoch Reward Router account discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if epoch_reward_router
+            .key
+            .ne(&Self::find_program_address(program_id, ncn, ncn_epoch).0)
+        {
+            msg!("Epoch Reward Router account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+
+    pub const fn ncn(&self) -> Pubkey {
+        self.ncn
+    }
+
+    pub fn ncn_epoch(&self) -> u64 {
+        self.ncn_epoch.into()
+    }
+
+    pub fn total_rewards(&self) -> u64 {
+        self.total_rewards.into()
+    }
+
+    pub fn dao_rewards(&self) -> u64 {
+        self.dao_rewards.into()
+    }
+
+    pub fn ncn_rewards(&self) -> u64 {
+        self.ncn_rewards.into()
+    }
+
+    pub fn operator_rewards(&self) -> u64 {
+        self.operator_rewards.into()
+    }
+
+    pub fn operator_rewards_distributed(&self) -> u64 {
+        self.operator_rewards_distributed.into()
+    }
+
+    pub fn operator_count(&self) -> u64 {
+        self.operator_count.into()
+    }
+
+    pub fn set_operator_count(&mut self, operator_count: u64) {
+        self.operator_count = PodU64::from(operator_count);
+    }
+
+    pub const fn payer(&self) -> Pubkey {
+        self.payer
+    }
+
+    /// Lamports held for operators that have not been paid out yet
+    pub fn undistributed_operator_rewards(&self) -> Result<u64, TipRouterError> {
+        self.operator_rewards()
+            .checked_sub(self.operator_rewards_distributed())
+            .ok_or(TipRouterError::ArithmeticOverflow)
+    }
+
+    /// Whether the router still holds rewards of its epoch, given the lamports it holds above
+    /// rent: lamports that were never routed, or operator rewards not paid out yet.
+    /// Operator entitlements round down, so up to one lamport per operator is never paid out
+    /// and is not counted.
+    pub fn has_pending_rewards(&self, rewards_held: u64) -> Result<bool, TipRouterError> {
+        let undistributed_operator_rewards = self.undistributed_operator_rewards()?;
+
+        if rewards_held != undistributed_operator_rewards {
+            return Ok(true);
+        }
+
+        Ok(undistributed_operator_rewards >= self.operator_count().max(1))
+    }
+
+    /// Takes the DAO and NCN fees out of newly received rewards and sets the remainder
+    /// aside for operators. Returns the `(dao_rewards, ncn_rewards)` to pay out.
+    pub fn route_incoming_rewards(
+        &mut self,
+        rewards: u64,
+        fee: &Fee,
+    ) -> Result<(u64, u64), TipRouterError> {
+        // The block engine fee was already taken before the tips reached the NCN
+        let split = fee.split_after_block_engine_fee(rewards)?;
+        let (dao_rewards, ncn_rewards, operator_rewards) = (split.dao, split.ncn, split.remainder);
+
+        self.total_rewards = PodU64::from(
+            self.total_rewards()
+                .checked_add(rewards)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        self.dao_rewards = PodU64::from(
+            self.dao_rewards()
+                .checked_add(dao_rewards)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        self.ncn_rewards = PodU64::from(
+            self.ncn_rewards()
+                .checked_add(ncn_rewards)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        self.operator_rewards = PodU64::from(
+            self.operator_rewards()
+                .checked_add(operator_rewards)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok((dao_rewards, ncn_rewards))
+    }
+
+    /// Share of all operator rewards routed so far that an operator is entitled to
+    pub fn operator_reward_entitlement(
+        &self,
+        operator_stake_weight: u128,
+        total_stake_weight: u128,
+    ) -> Result<u64, TipRouterError> {
+        if total_stake_weight == 0 {
+            return Ok(0);
+        }
+
+        let entitlement = (self.operator_rewards() as u128)
+            .checked_mul(operator_stake_weight)
+            .and_then(|x| x.checked_div(total_stake_weight))
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        u64::try_from(entitlement).map_err(|_| TipRouterError::ArithmeticOverflow)
+    }
+
+    pub fn record_operator_distribution(&mut self, rewards: u64) -> Result<(), TipRouterError> {
+        let distributed = self
+            .operator_rewards_distributed()
+            .checked_add(rewards)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        if distributed > self.operator_rewards() {
+            return Err(TipRouterError::ArithmeticOverflow);
+        }
+
+        self.operator_rewards_distributed = PodU64::from(distributed);
+        Ok(())
+    }
+
+    fn bps_of(amount: u64, bps: u64) -> Result<u64, TipRouterError> {
+        let result = (amount as u128)
+            .checked_mul(bps as u128)
+            .and_then(|x| x.checked_div(MAX_FEE_BPS as u128))
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        u64::try_from(result).map_err(|_| TipRouterError::ArithmeticOverflow)
+    }
+}
+
+/// Splits an operator's rewards into its fee and per-vault rewards, pro rata by vault
+/// stake weight. Rounding dust goes to the operator.
+pub fn split_operator_rewards(
+    rewards: u64,
+    operator_fee_bps: u16,
+    vault_stake_weights: &[VaultOperatorStakeWeight],
+    operator_stake_weight: u128,
+) -> Result<(u64, Vec<u64>), TipRouterError> {
+    if operator_stake_weight == 0 {
+        return Ok((rewards, vec![0; vault_stake_weights.len()]));
+    }
+
+    let operator_fee = EpochRewardRouter::bps_of(rewards, operator_fee_bps as u64)?;
+    let vault_pool = rewards
+        .checked_sub(operator_fee)
+        .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+    let vault_rewards = vault_stake_weights
+        .iter()
+        .map(|vault| {
+            let share = (vault_pool as u128)
+                .checked_mul(vault.stake_weight())
+                .and_then(|x| x.checked_div(operator_stake_weight))
+                .ok_or(TipRouterError::ArithmeticOverflow)?;
+            u64::try_from(share).map_err(|_| TipRouterError::ArithmeticOverflow)
+        })
+        .collect::<Result<Vec<u64>, TipRouterError>>()?;
+
+    let paid_to_vaults = vault_rewards
+        .iter()
+        .try_fold(0u64, |acc, r| acc.checked_add(*r))
+        .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+    let operator_rewards = rewards
+        .checked_sub(paid_to_vaults)
+        .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+    Ok((operator_rewards, vault_rewards))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::epoch_snapshot::ExclusionReason;
+
+    fn stake_weight(stake_weight: u128, vault_index: u64) -> VaultOperatorStakeWeight {
+        VaultOperatorStakeWeight::new(
+            Pubkey::new_unique(),
+            stake_weight,
+            vault_index,
+            ExclusionReason::NotExcluded,
+        )
+    }
+
+    #[test]
+    fn test_route_incoming_rewards() {
+        let mut router =
+            EpochRewardRouter::new(Pubkey::new_unique(), 5, 0, 0, Pubkey::new_unique());
+        let fee = Fee::new(Pubkey::new_unique(), Pubkey::new_unique(), 1_000, 500, 0, 5);
+
+        let (dao_rewards, ncn_rewards) = router.route_incoming_rewards(1_000_000, &fee).unwrap();
+        assert_eq!(dao_rewards, 100_000);
+        assert_eq!(ncn_rewards, 50_000);
+        assert_eq!(router.operator_rewards(), 850_000);
+
+        router.route_incoming_rewards(1_000, &fee).unwrap();
+        assert_eq!(router.total_rewards(), 1_001_000);
+        assert_eq!(router.dao_rewards(), 100_100);
+        assert_eq!(router.ncn_rewards(), 50_050);
+        assert_eq!(router.operator_rewards(), 850_850);
+        assert_eq!(router.undistributed_operator_rewards().unwrap(), 850_850);
+    }
+
+    #[test]
+    fn test_has_pending_rewards() {
+        let mut router =
+            EpochRewardRouter::new(Pubkey::new_unique(), 5, 0, 0, Pubkey::new_unique());
+        assert!(!router.has_pending_rewards(0).unwrap());
+
+        // Lamports that arrived but were not routed
+        assert!(router.has_pending_rewards(1_000).unwrap());
+
+        let fee = Fee::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0, 5);
+        router.route_incoming_rewards(1_000, &fee).unwrap();
+        router.set_operator_count(3);
+        assert!(router.has_pending_rewards(1_000).unwrap());
+
+        // Rounding dust of at most one lamport per operator is left behind
+        router.record_operator_distribution(333).unwrap();
+        router.record_operator_distribution(333).unwrap();
+        assert!(router.has_pending_rewards(334).unwrap());
+        router.record_operator_distribution(332).unwrap();
+        assert!(!router.has_pending_rewards(2).unwrap());
+        assert!(router.has_pending_rewards(3).unwrap());
+    }
+
+    #[test]
+    fn test_operator_reward_entitlement() {
+        let mut router =
+            EpochRewardRouter::new(Pubkey::new_unique(), 5, 0, 0, Pubkey::new_unique());
+        let fee = Fee::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0, 5);
+        router.route_incoming_rewards(1_000, &fee).unwrap();
+
+        assert_eq!(router.operator_reward_entitlement(1, 3).unwrap(), 333);
+        assert_eq!(router.operator_reward_entitlement(2, 3).unwrap(), 666);
+        assert_eq!(router.operator_reward_entitlement(1, 0).unwrap(), 0);
+
+        router.record_operator_distribution(333).unwrap();
+        router.record_operator_distribution(666).unwrap();
+        assert_eq!(router.undistributed_operator_rewards().unwrap(), 1);
+        assert_eq!(
+            router.record_operator_distribution(2),
+            Err(TipRouterError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn test_split_operator_rewards() {
+        let vaults = [
+            stake_weight(100, 0),
+            stake_weight(200, 1),
+            stake_weight(0, 2),
+        ];
+
+        let (operator_rewards, vault_rewards) =
+            split_operator_rewards(1_000, 1_000, &vaults, 300).unwrap();
+
+        // 10% operator fee, the remaining 900 is split 1:2 between the staked vaults
+        assert_eq!(vault_rewards, vec![300, 600, 0]);
+        assert_eq!(operator_rewards, 100);
+
+        // Rounding dust is paid to the operator
+        let (operator_rewards, vault_rewards) =
+            split_operator_rewards(10, 0, &vaults, 300).unwrap();
+        assert_eq!(vault_rewards, vec![3, 6, 0]);
+        assert_eq!(operator_rewards, 1);
+
+        let (operator_rewards, vault_rewards) = split_operator_rewards(10, 0, &vaults, 0).unwrap();
+        assert_eq!(vault_rewards, vec![0, 0, 0]);
+        assert_eq!(operator_rewards, 10);
+    }
+}
@@ -4,12 +4,14 @@ use jito_bytemuck::{
     AccountDeserialize, Discriminator,
 };
 use jito_vault_core::vault_operator_delegation::VaultOperatorDelegation;
+use meta_merkle_tree::generated_merkle_tree::{NcnFeeMeta, OperatorFeeMeta, VaultFeeMeta};
 use shank::{ShankAccount, ShankType};
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
-    discriminators::Discriminators, error::TipRouterError, fees::Fees, weight_table::WeightTable,
+    discriminators::Discriminators, error::TipRouterError, fees::Fee, weight_entry::WeightEntry,
+    weight_table::WeightTable,
 };
 
 // PDA'd ["epoch_snapshot", NCN, NCN_EPOCH_SLOT]
@@ -30,7 +32,8 @@ pub struct EpochSnapshot {
     slot_created: PodU64,
     slot_finalized: PodU64,
 
-    ncn_fees: Fees,
+    /// The fee in effect for `ncn_epoch`
+    fee: Fee,
 
     operator_count: PodU64,
     vault_count: PodU64,
@@ -41,8 +44,11 @@ pub struct EpochSnapshot {
     ///TODO What happens if `finalized() && total_votes() == 0`?
     stake_weight: PodU128,
 
+    /// Account that paid for the snapshot, refunded when it is closed
+    payer: Pubkey,
+
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 96],
 }
 
 impl Discriminator for EpochSnapshot {
@@ -55,9 +61,10 @@ impl EpochSnapshot {
         ncn_epoch: u64,
         bump: u8,
         current_slot: u64,
-        ncn_fees: Fees,
+        fee: Fee,
         operator_count: u64,
         vault_count: u64,
+        payer: Pubkey,
     ) -> Self {
         Self {
             ncn,
@@ -65,13 +72,14 @@ impl EpochSnapshot {
             slot_created: PodU64::from(current_slot),
             slot_finalized: PodU64::from(0),
             bump,
-            ncn_fees,
+            fee,
             operator_count: PodU64::from(operator_count),
             vault_count: PodU64::from(vault_count),
             operators_registered: PodU64::from(0),
             valid_operator_vault_delegations: PodU64::from(0),
             stake_weight: PodU128::from(0),
-            reserved: [0; 128],
+            payer,
+            reserved: [0; 96],
         }
     }
 
@@ -129,10 +137,26 @@ impl EpochSnapshot {
         Ok(())
     }
 
+    pub fn ncn_epoch(&self) -> u64 {
+        self.ncn_epoch.into()
+    }
+
+    pub fn slot_created(&self) -> u64 {
+        self.slot_created.into()
+    }
+
+    pub const fn fee(&self) -> &Fee {
+        &self.fee
+    }
+
     pub fn operator_count(&self) -> u64 {
         self.operator_count.into()
     }
 
+    pub const fn payer(&self) -> Pubkey {
+        self.payer
+    }
+
     pub fn vault_count(&self) -> u64 {
         self.vault_count.into()
     }
@@ -153,6 +177,82 @@ impl EpochSnapshot {
         self.operators_registered() == self.operator_count()
     }
 
+    /// The fees of this epoch for off-chain reward trees, with the fee wallet, fee and stake
+    /// weight of every operator that secured the NCN and of the vaults delegating to it.
+    /// `vault_snapshots` supply the vault fee wallets and must cover every vault with stake
+    /// weight in `operator_snapshots`.
+    pub fn ncn_fee_meta(
+        &self,
+        operator_snapshots: &[OperatorSnapshot],
+        vault_snapshots: &[VaultSnapshot],
+    ) -> Result<NcnFeeMeta, TipRouterError> {
+        let ncn_epoch = self.ncn_epoch();
+
+        let mut operator_fees = Vec::with_capacity(operator_snapshots.len());
+        for operator_snapshot in operator_snapshots.iter() {
+            if operator_snapshot.ncn.ne(&self.ncn)
+                || u64::from(operator_snapshot.ncn_epoch) != ncn_epoch
+            {
+                msg!(
+                    "Operator snapshot for {} is not part of this epoch snapshot",
+                    operator_snapshot.operator
+                );
+                return Err(TipRouterError::OperatorSnapshotMismatch);
+            }
+
+            if operator_snapshot.stake_weight() == 0 {
+                continue;
+            }
+
+            let mut vault_fees = Vec::new();
+            for vault_operator_stake_weight in operator_snapshot
+                .vault_operator_stake_weights()
+                .iter()
+                .filter(|vault| vault.stake_weight() > 0)
+            {
+                let vault = vault_operator_stake_weight.vault();
+                let Some(vault_snapshot) = vault_snapshots
+                    .iter()
+                    .find(|vault_snapshot| vault_snapshot.vault.eq(&vault))
+                else {
+                    msg!("No vault snapshot for {}", vault);
+                    return Err(TipRouterError::VaultSnapshotMismatch);
+                };
+                if vault_snapshot.ncn.ne(&self.ncn) || vault_snapshot.ncn_epoch() != ncn_epoch {
+                    msg!(
+                        "Vault snapshot for {} is not part of this epoch snapshot",
+                        vault
+                    );
+                    return Err(TipRouterError::VaultSnapshotMismatch);
+                }
+
+                vault_fees.push(VaultFeeMeta {
+                    vault,
+                    vault_fee_wallet: vault_snapshot.fee_wallet(),
+                    stake_weight: vault_operator_stake_weight.stake_weight(),
+                });
+            }
+
+            operator_fees.push(OperatorFeeMeta {
+                operator: operator_snapshot.operator(),
+                operator_fee_wallet: operator_snapshot.operator_fee_wallet(),
+                operator_fee_bps: operator_snapshot.operator_fee_bps(),
+                stake_weight: operator_snapshot.stake_weight(),
+                vault_fees,
+            });
+        }
+
+        Ok(NcnFeeMeta {
+            dao_fee_wallet: self.fee.dao_fee_wallet(),
+            ncn_fee_wallet: self.fee.ncn_fee_wallet(),
+            dao_share_bps: self.fee.dao_share_bps(),
+            ncn_share_bps: self.fee.ncn_share_bps(),
+            block_engine_fee_bps: self.fee.block_engine_fee_bps(),
+            total_stake_weight: self.stake_weight(),
+            operator_fees,
+        })
+    }
+
     pub fn increment_operator_registration(
         &mut self,
         current_slot: u64,
@@ -189,6 +289,34 @@ impl EpochSnapshot {
     }
 }
 
+/// Why an operator or vault delegation contributes no stake weight to a snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ExclusionReason {
+    NotExcluded = 0,
+    NcnOptInInactive = 1,
+    OperatorOptInInactive = 2,
+    VaultTicketInactive = 3,
+    DelegationMissing = 4,
+    ZeroSecurity = 5,
+}
+
+impl TryFrom<u8> for ExclusionReason {
+    type Error = TipRouterError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::NotExcluded),
+            1 => Ok(Self::NcnOptInInactive),
+            2 => Ok(Self::OperatorOptInInactive),
+            3 => Ok(Self::VaultTicketInactive),
+            4 => Ok(Self::DelegationMissing),
+            5 => Ok(Self::ZeroSecurity),
+            _ => Err(TipRouterError::InvalidExclusionReason),
+        }
+    }
+}
+
 // PDA'd ["operator_snapshot", OPERATOR, NCN, NCN_EPOCH_SLOT]
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod, AccountDeserialize, ShankAccount)]
 #[repr(C)]
@@ -212,10 +340,15 @@ pub struct OperatorSnapshot {
     valid_operator_vault_delegations: PodU64,
 
     stake_weight: PodU128,
-    reserved: [u8; 256],
-
-    //TODO change to 64
-    vault_operator_stake_weight: [VaultOperatorStakeWeight; 32],
+    payer: Pubkey,
+    exclusion_reason: u8,
+    /// Lamports routed to the operator and its vaults
+    rewards_routed: PodU64,
+    /// Paid the operator's share of rewards, the operator admin when the snapshot was taken
+    operator_fee_wallet: Pubkey,
+    reserved: [u8; 183],
+
+    vault_operator_stake_weight: [VaultOperatorStakeWeight; 64],
 }
 
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod, ShankType)]
@@ -224,7 +357,8 @@ pub struct VaultOperatorStakeWeight {
     vault: Pubkey,
     stake_weight: PodU128,
     vault_index: PodU64,
-    reserved: [u8; 32],
+    exclusion_reason: u8,
+    reserved: [u8; 31],
 }
 
 impl Default for VaultOperatorStakeWeight {
@@ -233,18 +367,25 @@ impl Default for VaultOperatorStakeWeight {
             vault: Pubkey::default(),
             vault_index: PodU64::from(u64::MAX),
             stake_weight: PodU128::from(0),
-            reserved: [0; 32],
+            exclusion_reason: ExclusionReason::NotExcluded as u8,
+            reserved: [0; 31],
         }
     }
 }
 
 impl VaultOperatorStakeWeight {
-    pub fn new(vault: Pubkey, stake_weight: u128, vault_index: u64) -> Self {
+    pub fn new(
+        vault: Pubkey,
+        stake_weight: u128,
+        vault_index: u64,
+        exclusion_reason: ExclusionReason,
+    ) -> Self {
         Self {
             vault,
             vault_index: PodU64::from(vault_index),
             stake_weight: PodU128::from(stake_weight),
-            reserved: [0; 32],
+            exclusion_reason: exclusion_reason as u8,
+            reserved: [0; 31],
         }
     }
 
@@ -252,6 +393,10 @@ impl VaultOperatorStakeWeight {
         self.vault_index() == u64::MAX
     }
 
+    pub const fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
     pub fn vault_index(&self) -> u64 {
         self.vault_index.into()
     }
@@ -259,6 +404,10 @@ impl VaultOperatorStakeWeight {
     pub fn stake_weight(&self) -> u128 {
         self.stake_weight.into()
     }
+
+    pub fn exclusion_reason(&self) -> Result<ExclusionReason, TipRouterError> {
+        ExclusionReason::try_from(self.exclusion_reason)
+    }
 }
 
 impl Discriminator for OperatorSnapshot {
@@ -279,7 +428,9 @@ impl OperatorSnapshot {
         ncn_operator_index: u64,
         operator_index: u64,
         operator_fee_bps: u16,
+        operator_fee_wallet: Pubkey,
         vault_operator_delegation_count: u64,
+        payer: Pubkey,
     ) -> Result<Self, TipRouterError> {
         if vault_operator_delegation_count > Self::MAX_VAULT_OPERATOR_STAKE_WEIGHT as u64 {
             return Err(TipRouterError::TooManyVaultOperatorDelegations);
@@ -300,8 +451,13 @@ impl OperatorSnapshot {
             vault_operator_delegations_registered: PodU64::from(0),
             valid_operator_vault_delegations: PodU64::from(0),
             stake_weight: PodU128::from(0),
-            reserved: [0; 256],
-            vault_operator_stake_weight: [VaultOperatorStakeWeight::default(); 32],
+            payer,
+            exclusion_reason: ExclusionReason::NotExcluded as u8,
+            rewards_routed: PodU64::from(0),
+            operator_fee_wallet,
+            reserved: [0; 183],
+            vault_operator_stake_weight: [VaultOperatorStakeWeight::default();
+                Self::MAX_VAULT_OPERATOR_STAKE_WEIGHT],
         })
     }
 
@@ -315,7 +471,9 @@ impl OperatorSnapshot {
         ncn_operator_index: u64,
         operator_index: u64,
         operator_fee_bps: u16,
+        operator_fee_wallet: Pubkey,
         vault_count: u64,
+        payer: Pubkey,
     ) -> Result<Self, TipRouterError> {
         Self::new(
             operator,
@@ -327,10 +485,13 @@ impl OperatorSnapshot {
             ncn_operator_index,
             operator_index,
             operator_fee_bps,
+            operator_fee_wallet,
             vault_count,
+            payer,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new_inactive(
         operator: Pubkey,
         ncn: Pubkey,
@@ -339,6 +500,8 @@ impl OperatorSnapshot {
         current_slot: u64,
         ncn_operator_index: u64,
         operator_index: u64,
+        payer: Pubkey,
+        exclusion_reason: ExclusionReason,
     ) -> Result<Self, TipRouterError> {
         let mut snapshot = Self::new(
             operator,
@@ -350,10 +513,13 @@ impl OperatorSnapshot {
             ncn_operator_index,
             operator_index,
             0,
+            Pubkey::default(),
             0,
+            payer,
         )?;
 
         snapshot.slot_finalized = PodU64::from(current_slot);
+        snapshot.exclusion_reason = exclusion_reason as u8;
         Ok(snapshot)
     }
 
@@ -416,10 +582,34 @@ impl OperatorSnapshot {
         Ok(())
     }
 
+    pub const fn operator(&self) -> Pubkey {
+        self.operator
+    }
+
+    pub fn slot_created(&self) -> u64 {
+        self.slot_created.into()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active.into()
+    }
+
+    pub fn operator_fee_bps(&self) -> u16 {
+        self.operator_fee_bps.into()
+    }
+
+    pub const fn operator_fee_wallet(&self) -> Pubkey {
+        self.operator_fee_wallet
+    }
+
     pub fn vault_operator_delegation_count(&self) -> u64 {
         self.vault_operator_delegation_count.into()
     }
 
+    pub const fn payer(&self) -> Pubkey {
+        self.payer
+    }
+
     pub fn vault_operator_delegations_registered(&self) -> u64 {
         self.vault_operator_delegations_registered.into()
     }
@@ -436,6 +626,30 @@ impl OperatorSnapshot {
         self.vault_operator_delegations_registered() == self.vault_operator_delegation_count()
     }
 
+    pub fn exclusion_reason(&self) -> Result<ExclusionReason, TipRouterError> {
+        ExclusionReason::try_from(self.exclusion_reason)
+    }
+
+    pub fn rewards_routed(&self) -> u64 {
+        self.rewards_routed.into()
+    }
+
+    pub fn increment_rewards_routed(&mut self, rewards: u64) -> Result<(), TipRouterError> {
+        self.rewards_routed = PodU64::from(
+            self.rewards_routed()
+                .checked_add(rewards)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        Ok(())
+    }
+
+    /// Stake weights registered so far, in registration order
+    pub fn vault_operator_stake_weights(&self) -> &[VaultOperatorStakeWeight] {
+        let registered = (self.vault_operator_delegations_registered() as usize)
+            .min(Self::MAX_VAULT_OPERATOR_STAKE_WEIGHT);
+        &self.vault_operator_stake_weight[..registered]
+    }
+
     pub fn contains_vault_index(&self, vault_index: u64) -> bool {
         self.vault_operator_stake_weight
             .iter()
@@ -447,9 +661,10 @@ impl OperatorSnapshot {
         vault: Pubkey,
         vault_index: u64,
         stake_weight: u128,
+        exclusion_reason: ExclusionReason,
     ) -> Result<(), TipRouterError> {
         if self.vault_operator_delegations_registered()
-            > Self::MAX_VAULT_OPERATOR_STAKE_WEIGHT as u64
+            >= Self::MAX_VAULT_OPERATOR_STAKE_WEIGHT as u64
         {
             return Err(TipRouterError::TooManyVaultOperatorDelegations);
         }
@@ -459,7 +674,7 @@ impl OperatorSnapshot {
         }
 
         self.vault_operator_stake_weight[self.vault_operator_delegations_registered() as usize] =
-            VaultOperatorStakeWeight::new(vault, stake_weight, vault_index);
+            VaultOperatorStakeWeight::new(vault, stake_weight, vault_index, exclusion_reason);
 
         Ok(())
     }
@@ -470,12 +685,18 @@ impl OperatorSnapshot {
         vault: Pubkey,
         vault_index: u64,
         stake_weight: u128,
+        exclusion_reason: ExclusionReason,
     ) -> Result<(), TipRouterError> {
         if self.finalized() {
             return Err(TipRouterError::VaultOperatorDelegationFinalized);
         }
 
-        self.insert_vault_operator_stake_weight(vault, vault_index, stake_weight)?;
+        self.insert_vault_operator_stake_weight(
+            vault,
+            vault_index,
+            stake_weight,
+            exclusion_reason,
+        )?;
 
         self.vault_operator_delegations_registered = PodU64::from(
             self.vault_operator_delegations_registered()
@@ -499,6 +720,11 @@ impl OperatorSnapshot {
 
         if self.finalized() {
             self.slot_finalized = PodU64::from(current_slot);
+
+            // Every delegation was registered but none carried any stake weight
+            if self.stake_weight() == 0 {
+                self.exclusion_reason = ExclusionReason::ZeroSecurity as u8;
+            }
         }
 
         Ok(())
@@ -513,19 +739,701 @@ impl OperatorSnapshot {
             .delegation_state
             .total_security()?;
 
+        let weight = weight_table.get_weight(st_mint)?;
+
+        Ok(Self::calculate_stake_weight(total_security, weight)?)
+    }
+
+    /// Stake weight is `total_security * weight / WeightEntry::DECIMAL_PRECISION`, rounded down,
+    /// which denominates it in the reference asset of the weight table
+    pub fn calculate_stake_weight(
+        total_security: u64,
+        weight: u128,
+    ) -> Result<u128, TipRouterError> {
         let precise_total_security = PreciseNumber::new(total_security as u128)
             .ok_or(TipRouterError::NewPreciseNumberError)?;
 
-        let precise_weight = weight_table.get_precise_weight(st_mint)?;
+        let precise_weight =
+            PreciseNumber::new(weight).ok_or(TipRouterError::NewPreciseNumberError)?;
+
+        let precise_decimal_precision = PreciseNumber::new(WeightEntry::DECIMAL_PRECISION)
+            .ok_or(TipRouterError::NewPreciseNumberError)?;
 
         let precise_total_stake_weight = precise_total_security
             .checked_mul(&precise_weight)
-            .ok_or(TipRouterError::ArithmeticOverflow)?;
+            .ok_or(TipRouterError::ArithmeticOverflow)?
+            .checked_div(&precise_decimal_precision)
+            .ok_or(TipRouterError::DenominatorIsZero)?;
 
         let total_stake_weight = precise_total_stake_weight
+            .floor()
+            .ok_or(TipRouterError::ArithmeticOverflow)?
             .to_imprecise()
             .ok_or(TipRouterError::CastToImpreciseNumberError)?;
 
         Ok(total_stake_weight)
     }
 }
+
+// PDA'd ["vault_snapshot", VAULT, NCN, NCN_EPOCH_SLOT]
+#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct VaultSnapshot {
+    vault: Pubkey,
+    ncn: Pubkey,
+    ncn_epoch: PodU64,
+    bump: u8,
+
+    slot_created: PodU64,
+    slot_last_updated: PodU64,
+
+    vault_index: PodU64,
+    st_mint: Pubkey,
+
+    /// Operator delegations registered for this vault
+    operators_registered: PodU64,
+    /// Registered operator delegations with a non-zero stake weight
+    valid_operator_delegations: PodU64,
+
+    /// Stake weight of the vault summed across all operators
+    stake_weight: PodU128,
+
+    /// Account that paid for the snapshot, refunded when it is closed
+    payer: Pubkey,
+
+    /// Paid the vault's share of rewards, the vault fee wallet when the snapshot was taken
+    fee_wallet: Pubkey,
+
+    reserved: [u8; 96],
+}
+
+impl Discriminator for VaultSnapshot {
+    const DISCRIMINATOR: u8 = Discriminators::VaultSnapshot as u8;
+}
+
+impl VaultSnapshot {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        vault: Pubkey,
+        ncn: Pubkey,
+        ncn_epoch: u64,
+        bump: u8,
+        current_slot: u64,
+        vault_index: u64,
+        st_mint: Pubkey,
+        fee_wallet: Pubkey,
+        payer: Pubkey,
+    ) -> Self {
+        Self {
+            vault,
+            ncn,
+            ncn_epoch: PodU64::from(ncn_epoch),
+            bump,
+            slot_created: PodU64::from(current_slot),
+            slot_last_updated: PodU64::from(current_slot),
+            vault_index: PodU64::from(vault_index),
+            st_mint,
+            operators_registered: PodU64::from(0),
+            valid_operator_delegations: PodU64::from(0),
+            stake_weight: PodU128::from(0),
+            payer,
+            fee_wallet,
+            reserved: [0; 96],
+        }
+    }
+
+    pub fn seeds(vault: &Pubkey, ncn: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
+        Vec::from_iter(
+            [
+                b"vault_snapshot".to_vec(),
+                vault.to_bytes().to_vec(),
+                ncn.to_bytes().to_vec(),
+                ncn_epoch.to_le_bytes().to_vec(),
+            ]
+            .iter()
+            .cloned(),
+        )
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        ncn: &Pubkey,
+        ncn_epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, ncn, ncn_epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        ncn: &Pubkey,
+        ncn_epoch: u64,
+        vault_snapshot: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if vault_snapshot.owner.ne(program_id) {
+            msg!("Vault Snapshot account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_snapshot.data_is_empty() {
+            msg!("Vault Snapshot account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !vault_snapshot.is_writable {
+            msg!("Vault Snapshot account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if vault_snapshot.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Vault Snapshot account discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if vault_snapshot
+            .key
+            .ne(&Self::find_program_address(program_id, vault, ncn, ncn_epoch).0)
+        {
+            msg!("Vault Snapshot account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+
+    pub const fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    pub fn ncn_epoch(&self) -> u64 {
+        self.ncn_epoch.into()
+    }
+
+    pub fn slot_last_updated(&self) -> u64 {
+        self.slot_last_updated.into()
+    }
+
+    pub fn vault_index(&self) -> u64 {
+        self.vault_index.into()
+    }
+
+    pub const fn st_mint(&self) -> Pubkey {
+        self.st_mint
+    }
+
+    pub const fn fee_wallet(&self) -> Pubkey {
+        self.fee_wallet
+    }
+
+    pub fn operators_registered(&self) -> u64 {
+        self.operators_registered.into()
+    }
+
+    pub fn valid_operator_delegations(&self) -> u64 {
+        self.valid_operator_delegations.into()
+    }
+
+    pub fn stake_weight(&self) -> u128 {
+        self.stake_weight.into()
+    }
+
+    pub const fn payer(&self) -> Pubkey {
+        self.payer
+    }
+
+    pub fn increment_operator_registration(
+        &mut self,
+        current_slot: u64,
+        stake_weight: u128,
+    ) -> Result<(), TipRouterError> {
+        self.operators_registered = PodU64::from(
+            self.operators_registered()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        if stake_weight > 0 {
+            self.valid_operator_delegations = PodU64::from(
+                self.valid_operator_delegations()
+                    .checked_add(1)
+                    .ok_or(TipRouterError::ArithmeticOverflow)?,
+            );
+        }
+
+        self.stake_weight = PodU128::from(
+            self.stake_weight()
+                .checked_add(stake_weight)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        self.slot_last_updated = PodU64::from(current_slot);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::epoch_reward_router::{split_operator_rewards, EpochRewardRouter};
+
+    #[test]
+    fn test_calculate_stake_weight() {
+        // A weight of 1.0 leaves the security unchanged
+        assert_eq!(
+            OperatorSnapshot::calculate_stake_weight(1_000, WeightEntry::DECIMAL_PRECISION)
+                .unwrap(),
+            1_000
+        );
+
+        // 1.5 SOL per token
+        let weight = WeightEntry::weight_from_exchange_rate(1.5).unwrap();
+        assert_eq!(
+            OperatorSnapshot::calculate_stake_weight(1_000, weight).unwrap(),
+            1_500
+        );
+
+        // Fractional results are rounded down
+        let weight = WeightEntry::weight_from_exchange_rate(1.0234).unwrap();
+        assert_eq!(
+            OperatorSnapshot::calculate_stake_weight(1_000, weight).unwrap(),
+            1_023
+        );
+
+        assert_eq!(
+            OperatorSnapshot::calculate_stake_weight(0, weight).unwrap(),
+            0
+        );
+        assert_eq!(
+            OperatorSnapshot::calculate_stake_weight(1_000, 0).unwrap(),
+            0
+        );
+
+        // Full u64 security at a large exchange rate does not overflow
+        let weight = WeightEntry::weight_from_exchange_rate(1_000.0).unwrap();
+        assert_eq!(
+            OperatorSnapshot::calculate_stake_weight(u64::MAX, weight).unwrap(),
+            u64::MAX as u128 * 1_000
+        );
+    }
+
+    fn test_operator_snapshot(vault_operator_delegation_count: u64) -> OperatorSnapshot {
+        OperatorSnapshot::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            true,
+            0,
+            0,
+            0,
+            Pubkey::new_unique(),
+            vault_operator_delegation_count,
+            Pubkey::new_unique(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_operator_snapshot_capacity() {
+        let max = OperatorSnapshot::MAX_VAULT_OPERATOR_STAKE_WEIGHT as u64;
+
+        let result = OperatorSnapshot::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            true,
+            0,
+            0,
+            0,
+            Pubkey::new_unique(),
+            max + 1,
+            Pubkey::new_unique(),
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            TipRouterError::TooManyVaultOperatorDelegations
+        );
+
+        let mut snapshot = test_operator_snapshot(max);
+        for vault_index in 0..max {
+            snapshot
+                .increment_vault_operator_delegation_registration(
+                    0,
+                    Pubkey::new_unique(),
+                    vault_index,
+                    1,
+                    ExclusionReason::NotExcluded,
+                )
+                .unwrap();
+        }
+
+        assert!(snapshot.finalized());
+        assert_eq!(snapshot.vault_operator_delegations_registered(), max);
+        assert_eq!(snapshot.valid_operator_vault_delegations(), max);
+        assert_eq!(snapshot.stake_weight(), max as u128);
+
+        // A full snapshot rejects further entries instead of indexing out of bounds
+        assert_eq!(
+            snapshot.insert_vault_operator_stake_weight(
+                Pubkey::new_unique(),
+                max,
+                1,
+                ExclusionReason::NotExcluded
+            ),
+            Err(TipRouterError::TooManyVaultOperatorDelegations)
+        );
+        assert_eq!(
+            snapshot.increment_vault_operator_delegation_registration(
+                0,
+                Pubkey::new_unique(),
+                max,
+                1,
+                ExclusionReason::NotExcluded
+            ),
+            Err(TipRouterError::VaultOperatorDelegationFinalized)
+        );
+    }
+
+    #[test]
+    fn test_insert_duplicate_vault_index() {
+        let mut snapshot = test_operator_snapshot(2);
+        snapshot
+            .increment_vault_operator_delegation_registration(
+                0,
+                Pubkey::new_unique(),
+                7,
+                1,
+                ExclusionReason::NotExcluded,
+            )
+            .unwrap();
+
+        assert_eq!(
+            snapshot.increment_vault_operator_delegation_registration(
+                0,
+                Pubkey::new_unique(),
+                7,
+                1,
+                ExclusionReason::NotExcluded
+            ),
+            Err(TipRouterError::DuplicateVaultOperatorDelegation)
+        );
+    }
+
+    #[test]
+    fn test_exclusion_reason() {
+        let mut snapshot = test_operator_snapshot(2);
+        assert_eq!(
+            snapshot.exclusion_reason(),
+            Ok(ExclusionReason::NotExcluded)
+        );
+
+        snapshot
+            .increment_vault_operator_delegation_registration(
+                0,
+                Pubkey::new_unique(),
+                0,
+                0,
+                ExclusionReason::DelegationMissing,
+            )
+            .unwrap();
+        snapshot
+            .increment_vault_operator_delegation_registration(
+                0,
+                Pubkey::new_unique(),
+                1,
+                0,
+                ExclusionReason::VaultTicketInactive,
+            )
+            .unwrap();
+
+        let stake_weights = snapshot.vault_operator_stake_weights();
+        assert_eq!(
+            stake_weights[0].exclusion_reason(),
+            Ok(ExclusionReason::DelegationMissing)
+        );
+        assert_eq!(
+            stake_weights[1].exclusion_reason(),
+            Ok(ExclusionReason::VaultTicketInactive)
+        );
+
+        // An operator whose delegations all carry zero weight is marked as such
+        assert!(snapshot.finalized());
+        assert_eq!(
+            snapshot.exclusion_reason(),
+            Ok(ExclusionReason::ZeroSecurity)
+        );
+
+        let inactive = OperatorSnapshot::new_inactive(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            0,
+            Pubkey::new_unique(),
+            ExclusionReason::OperatorOptInInactive,
+        )
+        .unwrap();
+        assert_eq!(
+            inactive.exclusion_reason(),
+            Ok(ExclusionReason::OperatorOptInInactive)
+        );
+
+        assert_eq!(
+            ExclusionReason::try_from(6),
+            Err(TipRouterError::InvalidExclusionReason)
+        );
+    }
+
+    fn fee_meta_operator_snapshot(
+        ncn: Pubkey,
+        operator_fee_bps: u16,
+        vault_stake_weights: &[(&VaultSnapshot, u128)],
+    ) -> OperatorSnapshot {
+        let mut snapshot = OperatorSnapshot::new(
+            Pubkey::new_unique(),
+            ncn,
+            5,
+            0,
+            0,
+            true,
+            0,
+            0,
+            operator_fee_bps,
+            Pubkey::new_unique(),
+            vault_stake_weights.len() as u64,
+            Pubkey::new_unique(),
+        )
+        .unwrap();
+        for (vault_snapshot, stake_weight) in vault_stake_weights.iter() {
+            snapshot
+                .increment_vault_operator_delegation_registration(
+                    0,
+                    vault_snapshot.vault(),
+                    vault_snapshot.vault_index(),
+                    *stake_weight,
+                    ExclusionReason::NotExcluded,
+                )
+                .unwrap();
+        }
+        snapshot
+    }
+
+    fn fee_meta_vault_snapshot(ncn: Pubkey, vault_index: u64) -> VaultSnapshot {
+        VaultSnapshot::new(
+            Pubkey::new_unique(),
+            ncn,
+            5,
+            0,
+            0,
+            vault_index,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        )
+    }
+
+    #[test]
+    fn test_ncn_fee_meta() {
+        let ncn = Pubkey::new_unique();
+        let fee = Fee::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 300, 5);
+        let mut epoch_snapshot = EpochSnapshot::new(ncn, 5, 0, 0, fee, 2, 2, Pubkey::new_unique());
+
+        let vault_snapshots = [
+            fee_meta_vault_snapshot(ncn, 0),
+            fee_meta_vault_snapshot(ncn, 1),
+        ];
+        let operator_snapshots = [
+            fee_meta_operator_snapshot(
+                ncn,
+                1_000,
+                &[(&vault_snapshots[0], 100), (&vault_snapshots[1], 0)],
+            ),
+            fee_meta_operator_snapshot(ncn, 500, &[(&vault_snapshots[1], 0)]),
+        ];
+        for operator_snapshot in operator_snapshots.iter() {
+            epoch_snapshot
+                .increment_operator_registration(0, 0, operator_snapshot.stake_weight())
+                .unwrap();
+        }
+
+        let ncn_fee_meta = epoch_snapshot
+            .ncn_fee_meta(&operator_snapshots, &vault_snapshots)
+            .unwrap();
+        assert_eq!(ncn_fee_meta.dao_fee_wallet, fee.dao_fee_wallet());
+        assert_eq!(ncn_fee_meta.ncn_fee_wallet, fee.ncn_fee_wallet());
+        assert_eq!(ncn_fee_meta.dao_share_bps, 100);
+        assert_eq!(ncn_fee_meta.ncn_share_bps, 200);
+        assert_eq!(ncn_fee_meta.block_engine_fee_bps, 300);
+        assert_eq!(ncn_fee_meta.total_stake_weight, 100);
+
+        // Operators and vaults without stake weight earn nothing
+        assert_eq!(ncn_fee_meta.operator_fees.len(), 1);
+        let operator_fee_meta = &ncn_fee_meta.operator_fees[0];
+        assert_eq!(operator_fee_meta.operator, operator_snapshots[0].operator());
+        assert_eq!(
+            operator_fee_meta.operator_fee_wallet,
+            operator_snapshots[0].operator_fee_wallet()
+        );
+        assert_eq!(operator_fee_meta.operator_fee_bps, 1_000);
+        assert_eq!(operator_fee_meta.stake_weight, 100);
+        assert_eq!(
+            operator_fee_meta.vault_fees,
+            vec![VaultFeeMeta {
+                vault: vault_snapshots[0].vault(),
+                vault_fee_wallet: vault_snapshots[0].fee_wallet(),
+                stake_weight: 100,
+            }]
+        );
+
+        let other_epoch = EpochSnapshot::new(ncn, 6, 0, 0, fee, 2, 2, Pubkey::new_unique());
+        assert_eq!(
+            other_epoch.ncn_fee_meta(&operator_snapshots, &vault_snapshots),
+            Err(TipRouterError::OperatorSnapshotMismatch)
+        );
+
+        // Every vault with stake weight needs a snapshot of the same epoch
+        assert_eq!(
+            epoch_snapshot.ncn_fee_meta(&operator_snapshots, &vault_snapshots[1..]),
+            Err(TipRouterError::VaultSnapshotMismatch)
+        );
+        let other_ncn_vault_snapshot = VaultSnapshot {
+            ncn: Pubkey::new_unique(),
+            ..vault_snapshots[0]
+        };
+        assert_eq!(
+            epoch_snapshot.ncn_fee_meta(&operator_snapshots, &[other_ncn_vault_snapshot]),
+            Err(TipRouterError::VaultSnapshotMismatch)
+        );
+    }
+
+    #[test]
+    fn test_ncn_fee_meta_matches_reward_router() {
+        let ncn = Pubkey::new_unique();
+        let fee = Fee::new(Pubkey::new_unique(), Pubkey::new_unique(), 270, 15, 300, 5);
+        let mut epoch_snapshot = EpochSnapshot::new(ncn, 5, 0, 0, fee, 3, 3, Pubkey::new_unique());
+
+        let vault_snapshots = [
+            fee_meta_vault_snapshot(ncn, 0),
+            fee_meta_vault_snapshot(ncn, 1),
+            fee_meta_vault_snapshot(ncn, 2),
+        ];
+        let operator_snapshots = [
+            fee_meta_operator_snapshot(
+                ncn,
+                1_000,
+                &[(&vault_snapshots[0], 1_003), (&vault_snapshots[1], 2_501)],
+            ),
+            fee_meta_operator_snapshot(
+                ncn,
+                333,
+                &[
+                    (&vault_snapshots[0], 707),
+                    (&vault_snapshots[1], 0),
+                    (&vault_snapshots[2], 1_999),
+                ],
+            ),
+            fee_meta_operator_snapshot(ncn, 10_000, &[(&vault_snapshots[2], 11)]),
+        ];
+        for operator_snapshot in operator_snapshots.iter() {
+            epoch_snapshot
+                .increment_operator_registration(0, 0, operator_snapshot.stake_weight())
+                .unwrap();
+        }
+
+        let rewards = 1_234_567_891;
+        let claims = epoch_snapshot
+            .ncn_fee_meta(&operator_snapshots, &vault_snapshots)
+            .unwrap()
+            .fee_claims(rewards)
+            .unwrap();
+
+        // Pay the same rewards through the on-chain reward router
+        let mut router = EpochRewardRouter::new(ncn, 5, 0, 0, Pubkey::new_unique());
+        let (dao_rewards, ncn_rewards) = router.route_incoming_rewards(rewards, &fee).unwrap();
+
+        let mut paid: HashMap<Pubkey, u64> = HashMap::new();
+        *paid.entry(fee.dao_fee_wallet()).or_default() += dao_rewards;
+        *paid.entry(fee.ncn_fee_wallet()).or_default() += ncn_rewards;
+        for operator_snapshot in operator_snapshots.iter() {
+            let entitlement = router
+                .operator_reward_entitlement(
+                    operator_snapshot.stake_weight(),
+                    epoch_snapshot.stake_weight(),
+                )
+                .unwrap();
+            let vault_stake_weights: Vec<VaultOperatorStakeWeight> = operator_snapshot
+                .vault_operator_stake_weights()
+                .iter()
+                .filter(|vault| vault.stake_weight() > 0)
+                .copied()
+                .collect();
+            let (operator_rewards, vault_rewards) = split_operator_rewards(
+                entitlement,
+                operator_snapshot.operator_fee_bps(),
+                &vault_stake_weights,
+                operator_snapshot.stake_weight(),
+            )
+            .unwrap();
+
+            *paid
+                .entry(operator_snapshot.operator_fee_wallet())
+                .or_default() += operator_rewards;
+            for (vault_stake_weight, vault_rewards) in vault_stake_weights.iter().zip(vault_rewards)
+            {
+                let vault_snapshot = vault_snapshots
+                    .iter()
+                    .find(|vault_snapshot| vault_snapshot.vault() == vault_stake_weight.vault())
+                    .unwrap();
+                *paid.entry(vault_snapshot.fee_wallet()).or_default() += vault_rewards;
+            }
+        }
+        paid.retain(|_, amount| *amount > 0);
+
+        assert_eq!(claims.len(), paid.len());
+        assert_eq!(claims.into_iter().collect::<HashMap<_, _>>(), paid);
+    }
+
+    #[test]
+    fn test_vault_snapshot_increment_operator_registration() {
+        let mut vault_snapshot = VaultSnapshot::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            0,
+            10,
+            3,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        vault_snapshot
+            .increment_operator_registration(11, 1_000)
+            .unwrap();
+        vault_snapshot
+            .increment_operator_registration(12, 0)
+            .unwrap();
+        vault_snapshot
+            .increment_operator_registration(13, 500)
+            .unwrap();
+
+        assert_eq!(vault_snapshot.operators_registered(), 3);
+        assert_eq!(vault_snapshot.valid_operator_delegations(), 2);
+        assert_eq!(vault_snapshot.stake_weight(), 1_500);
+        assert_eq!(vault_snapshot.slot_last_updated(), 13);
+
+        vault_snapshot.stake_weight = PodU128::from(u128::MAX);
+        assert_eq!(
+            vault_snapshot.increment_operator_registration(14, 1),
+            Err(TipRouterError::ArithmeticOverflow)
+        );
+    }
+}
@@ -89,6 +89,42 @@ pub enum TipRouterError {
     InvalidMerkleProof,
     #[error("Operator admin needs to sign its vote")]
     OperatorAdminInvalid,
+    #[error("Mint is already deregistered")]
+    MintAlreadyDeregistered,
+    #[error("Vault index is invalid")]
+    VaultIndexInvalid,
+    #[error("Vault is still active for the NCN")]
+    VaultStillActive,
+    #[error("Invalid exchange rate")]
+    InvalidExchangeRate,
+    #[error("Epochs before close must be greater than zero")]
+    InvalidEpochsBeforeClose,
+    #[error("Account cannot be closed yet")]
+    CannotCloseAccount,
+    #[error("Incorrect payer")]
+    IncorrectPayer,
+    #[error("Snapshot window must be greater than zero")]
+    InvalidSnapshotWindow,
+    #[error("Snapshot window for this epoch has closed")]
+    SnapshotWindowClosed,
+    #[error("Grace period for past epoch snapshots has expired")]
+    SnapshotGracePeriodExpired,
+    #[error("Invalid exclusion reason")]
+    InvalidExclusionReason,
+    #[error("Fee wallet does not match the epoch fees")]
+    IncorrectFeeWallet,
+    #[error("Fee activation delay must be greater than zero")]
+    InvalidFeeActivationDelay,
+    #[error("Operator snapshot does not belong to the epoch snapshot")]
+    OperatorSnapshotMismatch,
+    #[error("Vault snapshot does not belong to the epoch snapshot")]
+    VaultSnapshotMismatch,
+    #[error("Not enough compute units to snapshot a vault delegation")]
+    NotEnoughComputeUnits,
+    #[error("Rewards of the epoch have not been fully distributed")]
+    RewardsNotDistributed,
+    #[error("Tip distribution epoch is not part of the NCN epoch")]
+    InvalidTipDistributionEpoch,
 }
 
 impl<T> DecodeError<T> for TipRouterError {
@@ -4,7 +4,10 @@ use shank::ShankType;
 use solana_program::{msg, pubkey::Pubkey};
 use spl_math::precise_number::PreciseNumber;
 
-use crate::{constants::MAX_FEE_BPS, error::TipRouterError};
+use crate::{
+    constants::{DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS, MAX_FEE_BPS, MAX_FEE_HISTORY},
+    error::TipRouterError,
+};
 
 /// Fee account. Allows for fee updates to take place in a future epoch without requiring an update.
 /// This is important so all operators calculate the same Merkle root regardless of when fee changes take place.
@@ -13,18 +16,26 @@ use crate::{constants::MAX_FEE_BPS, error::TipRouterError};
 pub struct Fees {
     fee_1: Fee,
     fee_2: Fee,
+    /// Number of epochs between a fee change and the epoch it takes effect
+    activation_delay_epochs: PodU64,
+    /// Number of entries in use in `fee_history`
+    fee_history_len: PodU64,
+    /// Superseded fees, most recent first
+    fee_history: [Fee; MAX_FEE_HISTORY],
 }
 
 impl Fees {
     pub fn new(
-        wallet: Pubkey,
+        dao_fee_wallet: Pubkey,
+        ncn_fee_wallet: Pubkey,
         dao_fee_share_bps: u64,
         ncn_fee_share_bps: u64,
         block_engine_fee_bps: u64,
         current_epoch: u64,
     ) -> Self {
         let fee = Fee::new(
-            wallet,
+            dao_fee_wallet,
+            ncn_fee_wallet,
             dao_fee_share_bps,
             ncn_fee_share_bps,
             block_engine_fee_bps,
@@ -33,24 +44,80 @@ impl Fees {
         Self {
             fee_1: fee,
             fee_2: fee,
+            activation_delay_epochs: PodU64::from(DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS),
+            fee_history_len: PodU64::from(0),
+            fee_history: [Fee::zeroed(); MAX_FEE_HISTORY],
         }
     }
 
-    fn current_fee(&self, current_epoch: u64) -> &Fee {
-        // If either fee is not yet active, return the other one
-        if self.fee_1.activation_epoch() > current_epoch {
-            return &self.fee_2;
+    /// Fees of a config created before the DAO and NCN fee wallets were separated, with the
+    /// default activation delay and no history
+    pub fn from_legacy(fee_1: LegacyFee, fee_2: LegacyFee) -> Self {
+        Self {
+            fee_1: fee_1.into(),
+            fee_2: fee_2.into(),
+            activation_delay_epochs: PodU64::from(DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS),
+            fee_history_len: PodU64::from(0),
+            fee_history: [Fee::zeroed(); MAX_FEE_HISTORY],
         }
-        if self.fee_2.activation_epoch() > current_epoch {
-            return &self.fee_1;
+    }
+
+    /// The fee that applied at `epoch`: the most recently activated fee at that epoch, from
+    /// either slot or the history. Epochs older than anything on record get the oldest fee.
+    pub fn current_fee(&self, epoch: u64) -> &Fee {
+        // Slots come last so they win ties with archived fees, fee_1 wins ties with fee_2
+        let fees = || self.fee_history().iter().chain([&self.fee_2, &self.fee_1]);
+
+        fees()
+            .filter(|fee| fee.activation_epoch() <= epoch)
+            .max_by_key(|fee| fee.activation_epoch())
+            .or_else(|| fees().min_by_key(|fee| fee.activation_epoch()))
+            .unwrap_or(&self.fee_1)
+    }
+
+    pub fn activation_delay_epochs(&self) -> u64 {
+        self.activation_delay_epochs.into()
+    }
+
+    pub fn set_activation_delay_epochs(
+        &mut self,
+        activation_delay_epochs: u64,
+    ) -> Result<(), TipRouterError> {
+        // Fees must never change for an epoch that is already underway
+        if activation_delay_epochs == 0 {
+            return Err(TipRouterError::InvalidFeeActivationDelay);
+        }
+        self.activation_delay_epochs = PodU64::from(activation_delay_epochs);
+        Ok(())
+    }
+
+    /// Superseded fees still on record, most recent first
+    pub fn fee_history(&self) -> &[Fee] {
+        let len = (u64::from(self.fee_history_len) as usize).min(MAX_FEE_HISTORY);
+        &self.fee_history[..len]
+    }
+
+    /// Moves the fee about to be overwritten by [`Self::set_new_fees`] into the history.
+    /// Pending fees never took effect and are dropped, as are fees identical to the other slot.
+    fn archive_updatable_fee(&mut self, current_epoch: u64) {
+        if self.fee_1.activation_epoch() == self.fee_2.activation_epoch() {
+            return;
         }
 
-        // Otherwise return the one with higher activation epoch
-        if self.fee_1.activation_epoch() >= self.fee_2.activation_epoch() {
-            &self.fee_1
-        } else {
-            &self.fee_2
+        let fee = *self.get_updatable_fee_mut(current_epoch);
+        if fee.activation_epoch() > current_epoch {
+            return;
         }
+
+        self.fee_history.copy_within(..MAX_FEE_HISTORY - 1, 1);
+        self.fee_history[0] = fee;
+
+        let len = self
+            .fee_history()
+            .len()
+            .saturating_add(1)
+            .min(MAX_FEE_HISTORY);
+        self.fee_history_len = PodU64::from(len as u64);
     }
 
     pub fn check_fees_okay(&self, current_epoch: u64) -> Result<(), TipRouterError> {
@@ -100,7 +167,7 @@ impl Fees {
             .ok_or(TipRouterError::NewPreciseNumberError)?;
 
         let dao_fee = fee
-            .ncn_share_bps()
+            .dao_share_bps()
             .checked_mul(MAX_FEE_BPS)
             .ok_or(TipRouterError::ArithmeticOverflow)?;
 
@@ -150,8 +217,31 @@ impl Fees {
             .ok_or(TipRouterError::DenominatorIsZero)
     }
 
-    pub fn fee_wallet(&self, current_epoch: u64) -> Pubkey {
-        self.current_fee(current_epoch).wallet
+    /// [`Fee::split`] with the fee in effect at `current_epoch`
+    pub fn split(
+        &self,
+        total_lamports: u64,
+        current_epoch: u64,
+    ) -> Result<FeeSplit, TipRouterError> {
+        self.current_fee(current_epoch).split(total_lamports)
+    }
+
+    /// [`Fee::split_after_block_engine_fee`] with the fee in effect at `current_epoch`
+    pub fn split_after_block_engine_fee(
+        &self,
+        lamports: u64,
+        current_epoch: u64,
+    ) -> Result<FeeSplit, TipRouterError> {
+        self.current_fee(current_epoch)
+            .split_after_block_engine_fee(lamports)
+    }
+
+    pub fn dao_fee_wallet(&self, current_epoch: u64) -> Pubkey {
+        self.current_fee(current_epoch).dao_fee_wallet
+    }
+
+    pub fn ncn_fee_wallet(&self, current_epoch: u64) -> Pubkey {
+        self.current_fee(current_epoch).ncn_fee_wallet
     }
 
     fn get_updatable_fee_mut(&mut self, current_epoch: u64) -> &mut Fee {
@@ -176,10 +266,16 @@ impl Fees {
         new_dao_fee_bps: Option<u64>,
         new_ncn_fee_bps: Option<u64>,
         new_block_engine_fee_bps: Option<u64>,
-        new_wallet: Option<Pubkey>,
+        new_dao_fee_wallet: Option<Pubkey>,
+        new_ncn_fee_wallet: Option<Pubkey>,
         current_epoch: u64,
     ) -> Result<(), TipRouterError> {
+        let activation_epoch = current_epoch
+            .checked_add(self.activation_delay_epochs())
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
         let current_fees = *self.current_fee(current_epoch);
+        self.archive_updatable_fee(current_epoch);
         let new_fees = self.get_updatable_fee_mut(current_epoch);
         *new_fees = current_fees;
 
@@ -205,25 +301,45 @@ impl Fees {
             }
             new_fees.set_block_engine_fee_bps(new_block_engine_fee_bps);
         }
-        if let Some(new_wallet) = new_wallet {
-            new_fees.wallet = new_wallet;
+        if let Some(new_dao_fee_wallet) = new_dao_fee_wallet {
+            new_fees.dao_fee_wallet = new_dao_fee_wallet;
+        }
+        if let Some(new_ncn_fee_wallet) = new_ncn_fee_wallet {
+            new_fees.ncn_fee_wallet = new_ncn_fee_wallet;
         }
 
-        let next_epoch = current_epoch
-            .checked_add(1)
-            .ok_or(TipRouterError::ArithmeticOverflow)?;
-
-        new_fees.set_activation_epoch(next_epoch);
+        new_fees.set_activation_epoch(activation_epoch);
 
-        self.check_fees_okay(next_epoch)?;
+        self.check_fees_okay(activation_epoch)?;
 
         Ok(())
     }
 }
 
-#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
+/// Exact lamport amounts produced by [`Fee::split`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeSplit {
+    pub block_engine: u64,
+    pub dao: u64,
+    pub ncn: u64,
+    /// Left for operators and vaults, including all rounding dust
+    pub remainder: u64,
+}
+
+impl FeeSplit {
+    pub fn total(&self) -> Option<u64> {
+        self.block_engine
+            .checked_add(self.dao)?
+            .checked_add(self.ncn)?
+            .checked_add(self.remainder)
+    }
+}
+
+/// [`Fee`] as laid out before the DAO and NCN fee wallets were separated, one wallet was paid
+/// both fees. Only read when migrating a config, see [`Fees::from_legacy`].
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
 #[repr(C)]
-pub struct Fee {
+pub struct LegacyFee {
     wallet: Pubkey,
     dao_share_bps: PodU64,
     ncn_share_bps: PodU64,
@@ -231,7 +347,7 @@ pub struct Fee {
     activation_epoch: PodU64,
 }
 
-impl Fee {
+impl LegacyFee {
     pub fn new(
         wallet: Pubkey,
         dao_share_bps: u64,
@@ -247,6 +363,58 @@ impl Fee {
             activation_epoch: PodU64::from(epoch),
         }
     }
+}
+
+impl From<LegacyFee> for Fee {
+    fn from(fee: LegacyFee) -> Self {
+        Self {
+            dao_fee_wallet: fee.wallet,
+            ncn_fee_wallet: fee.wallet,
+            dao_share_bps: fee.dao_share_bps,
+            ncn_share_bps: fee.ncn_share_bps,
+            block_engine_fee_bps: fee.block_engine_fee_bps,
+            activation_epoch: fee.activation_epoch,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
+#[repr(C)]
+pub struct Fee {
+    dao_fee_wallet: Pubkey,
+    ncn_fee_wallet: Pubkey,
+    dao_share_bps: PodU64,
+    ncn_share_bps: PodU64,
+    block_engine_fee_bps: PodU64,
+    activation_epoch: PodU64,
+}
+
+impl Fee {
+    pub fn new(
+        dao_fee_wallet: Pubkey,
+        ncn_fee_wallet: Pubkey,
+        dao_share_bps: u64,
+        ncn_share_bps: u64,
+        block_engine_fee_bps: u64,
+        epoch: u64,
+    ) -> Self {
+        Self {
+            dao_fee_wallet,
+            ncn_fee_wallet,
+            dao_share_bps: PodU64::from(dao_share_bps),
+            ncn_share_bps: PodU64::from(ncn_share_bps),
+            block_engine_fee_bps: PodU64::from(block_engine_fee_bps),
+            activation_epoch: PodU64::from(epoch),
+        }
+    }
+
+    pub fn dao_fee_wallet(&self) -> Pubkey {
+        self.dao_fee_wallet
+    }
+
+    pub fn ncn_fee_wallet(&self) -> Pubkey {
+        self.ncn_fee_wallet
+    }
 
     pub fn dao_share_bps(&self) -> u64 {
         self.dao_share_bps.into()
@@ -264,6 +432,65 @@ impl Fee {
         self.activation_epoch.into()
     }
 
+    /// Splits `total_lamports` of tips into exact lamport amounts for each recipient.
+    ///
+    /// The block engine fee is taken first, then the DAO and NCN fees are taken from what
+    /// is left, matching [`Fees::precise_dao_fee`] and [`Fees::precise_ncn_fee`]. Every fee is
+    /// rounded down and all rounding dust stays in [`FeeSplit::remainder`].
+    pub fn split(&self, total_lamports: u64) -> Result<FeeSplit, TipRouterError> {
+        let block_engine =
+            Self::fee_portion(total_lamports, self.block_engine_fee_bps(), MAX_FEE_BPS)?;
+
+        let after_block_engine = total_lamports
+            .checked_sub(block_engine)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        let split = self.split_after_block_engine_fee(after_block_engine)?;
+
+        Ok(FeeSplit {
+            block_engine,
+            ..split
+        })
+    }
+
+    /// Same as [`Self::split`] for lamports the block engine fee was already taken from
+    pub fn split_after_block_engine_fee(&self, lamports: u64) -> Result<FeeSplit, TipRouterError> {
+        let remaining_bps = MAX_FEE_BPS
+            .checked_sub(self.block_engine_fee_bps())
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        // lamports * (share_bps * MAX_FEE_BPS / remaining_bps) / MAX_FEE_BPS, without the
+        // intermediate rounding of the precise fee
+        let dao = Self::fee_portion(lamports, self.dao_share_bps(), remaining_bps)?;
+        let ncn = Self::fee_portion(lamports, self.ncn_share_bps(), remaining_bps)?;
+
+        let remainder = lamports
+            .checked_sub(dao)
+            .and_then(|x| x.checked_sub(ncn))
+            .ok_or(TipRouterError::FeeCapExceeded)?;
+
+        Ok(FeeSplit {
+            block_engine: 0,
+            dao,
+            ncn,
+            remainder,
+        })
+    }
+
+    fn fee_portion(
+        lamports: u64,
+        numerator_bps: u64,
+        denominator_bps: u64,
+    ) -> Result<u64, TipRouterError> {
+        let portion = (lamports as u128)
+            .checked_mul(numerator_bps as u128)
+            .ok_or(TipRouterError::ArithmeticOverflow)?
+            .checked_div(denominator_bps as u128)
+            .ok_or(TipRouterError::DenominatorIsZero)?;
+
+        u64::try_from(portion).map_err(|_| TipRouterError::ArithmeticOverflow)
+    }
+
     fn set_dao_share_bps(&mut self, value: u64) {
         self.dao_share_bps = PodU64::from(value);
     }
@@ -289,76 +516,151 @@ mod tests {
 
     #[test]
     fn test_update_fees() {
-        let mut fees = Fees::new(Pubkey::new_unique(), 100, 200, 300, 5);
-        let new_wallet = Pubkey::new_unique();
+        let mut fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 300, 5);
+        let ncn_fee_wallet = fees.ncn_fee_wallet(10);
+        let new_dao_fee_wallet = Pubkey::new_unique();
 
-        fees.set_new_fees(Some(400), None, None, Some(new_wallet), 10)
+        fees.set_new_fees(Some(400), None, None, Some(new_dao_fee_wallet), None, 10)
             .unwrap();
         assert_eq!(fees.fee_1.dao_share_bps(), 400);
-        assert_eq!(fees.fee_1.wallet, new_wallet);
-        assert_eq!(fees.fee_1.activation_epoch(), 11);
+        assert_eq!(fees.fee_1.dao_fee_wallet, new_dao_fee_wallet);
+        assert_eq!(fees.fee_1.ncn_fee_wallet, ncn_fee_wallet);
+        assert_eq!(fees.fee_1.activation_epoch(), 13);
     }
 
     #[test]
     fn test_update_all_fees() {
-        let mut fees = Fees::new(Pubkey::new_unique(), 0, 0, 0, 5);
+        let mut fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0, 5);
 
-        fees.set_new_fees(Some(100), Some(200), Some(300), None, 10)
+        fees.set_new_fees(Some(100), Some(200), Some(300), None, None, 10)
             .unwrap();
         assert_eq!(fees.fee_1.dao_share_bps(), 100);
         assert_eq!(fees.fee_1.ncn_share_bps(), 200);
         assert_eq!(fees.fee_1.block_engine_fee_bps(), 300);
-        assert_eq!(fees.fee_1.activation_epoch(), 11);
+        assert_eq!(fees.fee_1.activation_epoch(), 13);
     }
 
     #[test]
     fn test_update_fees_no_changes() {
-        let original = Fee::new(Pubkey::new_unique(), 100, 200, 300, 5);
-        let mut fees = Fees::new(Pubkey::new_unique(), 100, 200, 300, 5);
+        let original = Fee::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 300, 5);
+        let mut fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 300, 5);
         fees.fee_1 = original;
 
-        fees.set_new_fees(None, None, None, None, 10).unwrap();
+        fees.set_new_fees(None, None, None, None, None, 10).unwrap();
         assert_eq!(fees.fee_1.dao_share_bps(), original.dao_share_bps());
         assert_eq!(fees.fee_1.ncn_share_bps(), original.ncn_share_bps());
         assert_eq!(
             fees.fee_1.block_engine_fee_bps(),
             original.block_engine_fee_bps()
         );
-        assert_eq!(fees.fee_1.wallet, original.wallet);
-        assert_eq!(fees.fee_1.activation_epoch(), 11);
+        assert_eq!(fees.fee_1.dao_fee_wallet, original.dao_fee_wallet);
+        assert_eq!(fees.fee_1.ncn_fee_wallet, original.ncn_fee_wallet);
+        assert_eq!(fees.fee_1.activation_epoch(), 13);
     }
 
     #[test]
     fn test_update_fees_errors() {
-        let mut fees = Fees::new(Pubkey::new_unique(), 100, 200, 300, 5);
+        let mut fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 300, 5);
 
         assert_eq!(
-            fees.set_new_fees(Some(10001), None, None, None, 10),
+            fees.set_new_fees(Some(10001), None, None, None, None, 10),
             Err(TipRouterError::FeeCapExceeded)
         );
 
-        let mut fees = Fees::new(Pubkey::new_unique(), 100, 200, 300, 5);
+        let mut fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 300, 5);
 
         assert_eq!(
-            fees.set_new_fees(None, None, None, None, u64::MAX),
+            fees.set_new_fees(None, None, None, None, None, u64::MAX),
             Err(TipRouterError::ArithmeticOverflow)
         );
 
-        let mut fees = Fees::new(Pubkey::new_unique(), 100, 200, 300, 5);
+        let mut fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 300, 5);
 
         assert_eq!(
-            fees.set_new_fees(None, None, Some(MAX_FEE_BPS), None, 10),
+            fees.set_new_fees(None, None, Some(MAX_FEE_BPS), None, None, 10),
             Err(TipRouterError::FeeCapExceeded)
         );
     }
 
+    #[test]
+    fn test_activation_delay() {
+        let mut fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 300, 5);
+        assert_eq!(
+            fees.activation_delay_epochs(),
+            DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS
+        );
+
+        assert_eq!(
+            fees.set_activation_delay_epochs(0),
+            Err(TipRouterError::InvalidFeeActivationDelay)
+        );
+
+        fees.set_activation_delay_epochs(5).unwrap();
+        fees.set_new_fees(Some(400), None, None, None, None, 10)
+            .unwrap();
+
+        // Old fees apply until the delay has passed
+        for epoch in 10..15 {
+            assert_eq!(fees.dao_fee(epoch).unwrap(), 100 * 10_000 / 9_700);
+        }
+        assert_eq!(fees.current_fee(15).dao_share_bps(), 400);
+    }
+
+    #[test]
+    fn test_fee_history() {
+        let mut fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0, 0);
+        fees.set_activation_delay_epochs(1).unwrap();
+        assert!(fees.fee_history().is_empty());
+
+        // Fee n is active from epoch n
+        for epoch in 0..20 {
+            fees.set_new_fees(Some(epoch + 1), None, None, None, None, epoch)
+                .unwrap();
+        }
+
+        assert_eq!(fees.fee_history().len(), MAX_FEE_HISTORY);
+        assert_eq!(fees.fee_history()[0].activation_epoch(), 18);
+
+        // Every epoch still on record resolves to the fee active at that time
+        let oldest_epoch = 20 - MAX_FEE_HISTORY as u64 - 1;
+        for epoch in oldest_epoch..=20 {
+            assert_eq!(fees.current_fee(epoch).dao_share_bps(), epoch);
+            assert_eq!(fees.current_fee(epoch).activation_epoch(), epoch);
+        }
+
+        // Older epochs fall back to the oldest fee on record
+        assert_eq!(fees.current_fee(0).activation_epoch(), oldest_epoch);
+    }
+
+    #[test]
+    fn test_pending_fee_not_archived() {
+        let mut fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 0, 0, 0);
+
+        fees.set_new_fees(Some(200), None, None, None, None, 10)
+            .unwrap();
+        // Replaces the pending fee, which never took effect
+        fees.set_new_fees(Some(300), None, None, None, None, 11)
+            .unwrap();
+
+        assert!(fees.fee_history().is_empty());
+        assert_eq!(fees.current_fee(13).dao_share_bps(), 100);
+        assert_eq!(fees.current_fee(14).dao_share_bps(), 300);
+    }
+
     #[test]
     fn test_check_fees_okay() {
-        let fees = Fees::new(Pubkey::new_unique(), 0, 0, 0, 5);
+        let fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0, 5);
 
         fees.check_fees_okay(5).unwrap();
 
-        let fees = Fees::new(Pubkey::new_unique(), 0, 0, MAX_FEE_BPS, 5);
+        let fees = Fees::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            0,
+            MAX_FEE_BPS,
+            5,
+        );
 
         assert_eq!(
             fees.check_fees_okay(5),
@@ -368,7 +670,7 @@ mod tests {
 
     #[test]
     fn test_current_fee() {
-        let mut fees = Fees::new(Pubkey::new_unique(), 100, 200, 300, 5);
+        let mut fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 300, 5);
 
         assert_eq!(fees.current_fee(5).activation_epoch(), 5);
 
@@ -383,9 +685,98 @@ mod tests {
         assert_eq!(fees.current_fee(15).activation_epoch(), 15);
     }
 
+    #[test]
+    fn test_precise_fees() {
+        let fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 0, 5);
+
+        let dao_fee = fees.precise_dao_fee(5).unwrap();
+        let ncn_fee = fees.precise_ncn_fee(5).unwrap();
+
+        assert_eq!(
+            dao_fee.to_imprecise().unwrap(),
+            fees.dao_fee(5).unwrap() as u128
+        );
+        assert_eq!(
+            ncn_fee.to_imprecise().unwrap(),
+            fees.ncn_fee(5).unwrap() as u128
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        let fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 1_000, 500, 0, 5);
+
+        let split = fees.split(1_000_000, 5).unwrap();
+        assert_eq!(
+            split,
+            FeeSplit {
+                block_engine: 0,
+                dao: 100_000,
+                ncn: 50_000,
+                remainder: 850_000,
+            }
+        );
+
+        // 3% to the block engine, then 1% and 2% of the original total
+        let fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 300, 5);
+        let split = fees.split(1_000_000, 5).unwrap();
+        assert_eq!(
+            split,
+            FeeSplit {
+                block_engine: 30_000,
+                dao: 10_000,
+                ncn: 20_000,
+                remainder: 940_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_split_rounding() {
+        let fees = Fees::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            3_333,
+            3_333,
+            1,
+            5,
+        );
+
+        for total_lamports in [0, 1, 7, 999, 1_000_003, u64::MAX] {
+            let split = fees.split(total_lamports, 5).unwrap();
+            assert_eq!(split.total(), Some(total_lamports));
+
+            let ideal = |lamports: u64, bps: u64, denominator_bps: u64| {
+                (lamports as u128 * bps as u128 / denominator_bps as u128) as u64
+            };
+
+            // Each fee rounds down from its share of the lamports it is taken from
+            let after_block_engine = total_lamports - split.block_engine;
+            assert!(split.block_engine <= ideal(total_lamports, 1, 10_000));
+            assert!(split.dao <= ideal(after_block_engine, 3_333, 9_999));
+            assert!(split.ncn <= ideal(after_block_engine, 3_333, 9_999));
+
+            // The block engine fee rounding down leaves less than one lamport extra for the
+            // others, so the DAO and NCN fees can be one lamport over their share of the total
+            assert!(split.dao <= ideal(total_lamports, 3_333, 10_000) + 1);
+            assert!(split.ncn <= ideal(total_lamports, 3_333, 10_000) + 1);
+        }
+
+        // Shares that exceed the remaining lamports are rejected
+        let fees = Fees::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            6_000,
+            6_000,
+            0,
+            5,
+        );
+        assert_eq!(fees.split(1_000, 5), Err(TipRouterError::FeeCapExceeded));
+    }
+
     #[test]
     fn test_get_updatable_fee_mut() {
-        let mut fees = Fees::new(Pubkey::new_unique(), 100, 200, 300, 5);
+        let mut fees = Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 100, 200, 300, 5);
 
         let fee = fees.get_updatable_fee_mut(10);
         fee.set_dao_share_bps(400);
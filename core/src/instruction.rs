@@ -18,10 +18,11 @@ pub enum TipRouterInstruction {
     #[account(1, writable, name = "ncn_config")]
     #[account(2, name = "ncn")]
     #[account(3, signer, name = "ncn_admin")]
-    #[account(4, name = "fee_wallet")]
-    #[account(5, name = "tie_breaker_admin")]
-    #[account(6, name = "restaking_program")]
-    #[account(7, name = "system_program")]
+    #[account(4, name = "dao_fee_wallet")]
+    #[account(5, name = "ncn_fee_wallet")]
+    #[account(6, name = "tie_breaker_admin")]
+    #[account(7, name = "restaking_program")]
+    #[account(8, name = "system_program")]
     InitializeNCNConfig {
         dao_fee_bps: u64,
         ncn_fee_bps: u64,
@@ -38,7 +39,8 @@ pub enum TipRouterInstruction {
         new_dao_fee_bps: Option<u64>,
         new_ncn_fee_bps: Option<u64>,
         new_block_engine_fee_bps: Option<u64>,
-        new_fee_wallet: Option<Pubkey>,
+        new_dao_fee_wallet: Option<Pubkey>,
+        new_ncn_fee_wallet: Option<Pubkey>,
     },
 
     /// Sets a new secondary admin for the NCN
@@ -115,8 +117,9 @@ pub enum TipRouterInstruction {
     #[account(8, name = "weight_table")]
     #[account(9, writable, name = "epoch_snapshot")]
     #[account(10, writable, name = "operator_snapshot")]
-    #[account(11, name = "vault_program")]
-    #[account(12, name = "restaking_program")]
+    #[account(11, writable, name = "vault_snapshot")]
+    #[account(12, name = "vault_program")]
+    #[account(13, name = "restaking_program")]
     SnapshotVaultOperatorDelegation{
         first_slot_of_ncn_epoch: Option<u64>,
     },
@@ -164,31 +167,217 @@ pub enum TipRouterInstruction {
         epoch: u64,
     },
 
-    /// Set the merkle root after consensus is reached
+    /// Set the merkle root after consensus is reached, for the tip distribution account of a
+    /// Solana epoch that overlaps the NCN epoch
     #[account(0, writable, name = "ncn_config")]
-    #[account(1, name = "ncn")]
-    #[account(2, name = "ballot_box")]
-    #[account(3, name = "vote_account")]
-    #[account(4, writable, name = "tip_distribution_account")]
-    #[account(5, name = "tip_distribution_config")]
-    #[account(6, name = "tip_distribution_program")]
-    #[account(7, name = "restaking_program")]
+    #[account(1, name = "restaking_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "ballot_box")]
+    #[account(4, name = "vote_account")]
+    #[account(5, writable, name = "tip_distribution_account")]
+    #[account(6, name = "tip_distribution_config")]
+    #[account(7, name = "tip_distribution_program")]
+    #[account(8, name = "restaking_program")]
     SetMerkleRoot {
         proof: Vec<[u8; 32]>,
         merkle_root: [u8; 32],
         max_total_claim: u64,
         max_num_nodes: u64,
         epoch: u64,
+        tip_distribution_epoch: u64,
     },
 
     /// Set tie breaker in case of stalled voting
     #[account(0, name = "ncn_config")]
-    #[account(1, writable, name = "ballot_box")]
-    #[account(2, name = "ncn")]
-    #[account(3, signer, name = "tie_breaker_admin")]
-    #[account(4, name = "restaking_program")]
+    #[account(1, name = "restaking_config")]
+    #[account(2, writable, name = "ballot_box")]
+    #[account(3, name = "ncn")]
+    #[account(4, signer, name = "tie_breaker_admin")]
+    #[account(5, name = "restaking_program")]
     SetTieBreaker {
         meta_merkle_root: [u8; 32],
         epoch: u64,
-    }
+    },
+
+    /// Deregisters the mint of a vault that is no longer active for the NCN
+    #[account(0, name = "restaking_config")]
+    #[account(1, writable, name = "tracked_mints")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "weight_table")]
+    #[account(4, name = "vault")]
+    #[account(5, name = "vault_ncn_ticket")]
+    #[account(6, name = "ncn_vault_ticket")]
+    #[account(7, name = "restaking_program_id")]
+    #[account(8, name = "vault_program_id")]
+    DeregisterMint,
+
+    /// Resizes the tracked mints account to hold the current number of entries
+    #[account(0, name = "ncn_config")]
+    #[account(1, writable, name = "tracked_mints")]
+    #[account(2, name = "ncn")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    ReallocTrackedMints,
+
+    /// Updates the NCN config parameters
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "ncn_admin")]
+    #[account(3, name = "restaking_program")]
+    SetParameters {
+        epochs_before_close: Option<u64>,
+        snapshot_window_slots: Option<u64>,
+        snapshot_grace_epochs: Option<u64>,
+        fee_activation_delay_epochs: Option<u64>,
+    },
+
+    /// Closes an expired weight table and refunds its payer
+    #[account(0, name = "ncn_config")]
+    #[account(1, name = "restaking_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "ballot_box")]
+    #[account(4, name = "epoch_reward_router")]
+    #[account(5, writable, name = "weight_table")]
+    #[account(6, writable, name = "payer")]
+    #[account(7, name = "restaking_program")]
+    CloseWeightTable {
+        epoch: u64,
+    },
+
+    /// Closes an expired epoch snapshot and refunds its payer
+    #[account(0, name = "ncn_config")]
+    #[account(1, name = "restaking_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "ballot_box")]
+    #[account(4, name = "epoch_reward_router")]
+    #[account(5, writable, name = "epoch_snapshot")]
+    #[account(6, writable, name = "payer")]
+    #[account(7, name = "restaking_program")]
+    CloseEpochSnapshot {
+        epoch: u64,
+    },
+
+    /// Closes an expired operator snapshot and refunds its payer
+    #[account(0, name = "ncn_config")]
+    #[account(1, name = "restaking_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "ballot_box")]
+    #[account(4, name = "epoch_reward_router")]
+    #[account(5, name = "operator")]
+    #[account(6, writable, name = "operator_snapshot")]
+    #[account(7, writable, name = "payer")]
+    #[account(8, name = "restaking_program")]
+    CloseOperatorSnapshot {
+        epoch: u64,
+    },
+
+    /// Closes an expired ballot box and refunds its payer
+    #[account(0, name = "ncn_config")]
+    #[account(1, name = "restaking_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, writable, name = "ballot_box")]
+    #[account(4, name = "epoch_reward_router")]
+    #[account(5, writable, name = "payer")]
+    #[account(6, name = "restaking_program")]
+    CloseBallotBox {
+        epoch: u64,
+    },
+
+    /// Snapshots a batch of vault operator delegations for one operator.
+    /// Remaining accounts are [vault, vault_ncn_ticket, ncn_vault_ticket, vault_operator_delegation, vault_snapshot] groups
+    #[account(0, name = "ncn_config")]
+    #[account(1, name = "restaking_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "weight_table")]
+    #[account(5, writable, name = "epoch_snapshot")]
+    #[account(6, writable, name = "operator_snapshot")]
+    #[account(7, name = "vault_program")]
+    #[account(8, name = "restaking_program")]
+    SnapshotVaultOperatorDelegations {
+        first_slot_of_ncn_epoch: Option<u64>,
+    },
+
+    /// Initializes the Vault Snapshot
+    #[account(0, name = "ncn_config")]
+    #[account(1, name = "restaking_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "vault")]
+    #[account(4, name = "epoch_snapshot")]
+    #[account(5, writable, name = "vault_snapshot")]
+    #[account(6, writable, signer, name = "payer")]
+    #[account(7, name = "vault_program")]
+    #[account(8, name = "restaking_program")]
+    #[account(9, name = "system_program")]
+    InitializeVaultSnapshot {
+        first_slot_of_ncn_epoch: Option<u64>,
+    },
+
+    /// Closes an expired vault snapshot and refunds its payer
+    #[account(0, name = "ncn_config")]
+    #[account(1, name = "restaking_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "ballot_box")]
+    #[account(4, name = "epoch_reward_router")]
+    #[account(5, name = "vault")]
+    #[account(6, writable, name = "vault_snapshot")]
+    #[account(7, writable, name = "payer")]
+    #[account(8, name = "restaking_program")]
+    CloseVaultSnapshot {
+        epoch: u64,
+    },
+
+    /// Initializes the account that receives the NCN share of tips for an epoch
+    #[account(0, name = "ncn_config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "epoch_reward_router")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    InitializeEpochRewardRouter {
+        epoch: u64,
+    },
+
+    /// Pays the DAO and NCN fees out of newly received rewards and sets the rest aside for operators
+    #[account(0, name = "ncn_config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "epoch_snapshot")]
+    #[account(3, writable, name = "epoch_reward_router")]
+    #[account(4, writable, name = "dao_fee_wallet")]
+    #[account(5, writable, name = "ncn_fee_wallet")]
+    RouteNcnRewards {
+        epoch: u64,
+    },
+
+    /// Pays an operator and its vaults their share of the routed rewards, to the fee wallets in their snapshots.
+    /// Remaining accounts are [vault_snapshot, writable fee_wallet] pairs for the vaults with non-zero stake weight, in operator snapshot order
+    #[account(0, name = "ncn_config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "operator_fee_wallet")]
+    #[account(4, name = "epoch_snapshot")]
+    #[account(5, writable, name = "operator_snapshot")]
+    #[account(6, writable, name = "epoch_reward_router")]
+    #[account(7, name = "restaking_program")]
+    DistributeOperatorRewards {
+        epoch: u64,
+    },
+
+    /// Rewrites an NCN config created before the DAO and NCN fee wallets were separated into the current layout
+    #[account(0, writable, name = "ncn_config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, signer, name = "payer")]
+    #[account(3, name = "system_program")]
+    MigrateNcnConfig,
+
+    /// Closes an expired epoch reward router whose rewards are distributed and refunds its payer
+    #[account(0, name = "ncn_config")]
+    #[account(1, name = "restaking_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "ballot_box")]
+    #[account(4, writable, name = "epoch_reward_router")]
+    #[account(5, writable, name = "payer")]
+    #[account(6, name = "restaking_program")]
+    CloseEpochRewardRouter {
+        epoch: u64,
+    },
 }
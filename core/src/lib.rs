@@ -1,12 +1,14 @@
 pub mod ballot_box;
 pub mod constants;
 pub mod discriminators;
+pub mod epoch_reward_router;
 pub mod epoch_snapshot;
 pub mod error;
 pub mod fees;
 pub mod instruction;
 pub mod loaders;
 pub mod ncn_config;
+pub mod ncn_epoch;
 pub mod tracked_mints;
 pub mod weight_entry;
 pub mod weight_table;
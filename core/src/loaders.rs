@@ -1,33 +1,51 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_restaking_core::config::Config;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError};
 
-use crate::error::TipRouterError;
+use crate::{error::TipRouterError, ncn_config::NcnConfig, ncn_epoch::NcnEpochSchedule};
 
 pub fn load_ncn_epoch(
     restaking_config: &AccountInfo,
     current_slot: u64,
     first_slot_of_ncn_epoch: Option<u64>,
-) -> Result<(u64, u64), ProgramError> {
-    let ncn_epoch_length = {
-        let config_data = restaking_config.data.borrow();
-        let config = Config::try_from_slice_unchecked(&config_data)?;
-        config.epoch_length()
-    };
+) -> Result<(u64, NcnEpochSchedule), ProgramError> {
+    let ncn_epoch_schedule = NcnEpochSchedule::load(restaking_config)?;
 
-    let current_ncn_epoch = current_slot
-        .checked_div(ncn_epoch_length)
-        .ok_or(TipRouterError::DenominatorIsZero)?;
+    let current_ncn_epoch = ncn_epoch_schedule.ncn_epoch(current_slot);
 
     let ncn_epoch_slot = first_slot_of_ncn_epoch.unwrap_or(current_slot);
-    let ncn_epoch = ncn_epoch_slot
-        .checked_div(ncn_epoch_length)
-        .ok_or(TipRouterError::DenominatorIsZero)?;
+    let ncn_epoch = ncn_epoch_schedule.ncn_epoch(ncn_epoch_slot);
 
     if ncn_epoch > current_ncn_epoch {
         msg!("Epoch snapshots can only be initialized for current or past epochs");
         return Err(TipRouterError::CannotCreateFutureWeightTables.into());
     }
 
-    Ok((ncn_epoch, ncn_epoch_length))
+    Ok((ncn_epoch, ncn_epoch_schedule))
+}
+
+/// Same as [`load_ncn_epoch`], but also enforces the snapshot window of the NCN config
+pub fn load_snapshot_ncn_epoch(
+    ncn_config: &AccountInfo,
+    restaking_config: &AccountInfo,
+    current_slot: u64,
+    first_slot_of_ncn_epoch: Option<u64>,
+) -> Result<(u64, NcnEpochSchedule), ProgramError> {
+    let (ncn_epoch, ncn_epoch_schedule) =
+        load_ncn_epoch(restaking_config, current_slot, first_slot_of_ncn_epoch)?;
+
+    let ncn_config_data = ncn_config.data.borrow();
+    let ncn_config_account = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+
+    if let Err(e) =
+        ncn_config_account.check_snapshot_window(ncn_epoch, current_slot, &ncn_epoch_schedule)
+    {
+        msg!(
+            "NCN epoch {} cannot be snapshotted at slot {}",
+            ncn_epoch,
+            current_slot
+        );
+        return Err(e.into());
+    }
+
+    Ok((ncn_epoch, ncn_epoch_schedule))
 }
@@ -3,7 +3,15 @@ use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use shank::{ShankAccount, ShankType};
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{discriminators::Discriminators, fees::Fees};
+use crate::{
+    constants::{
+        DEFAULT_EPOCHS_BEFORE_CLOSE, DEFAULT_SNAPSHOT_GRACE_EPOCHS, DEFAULT_SNAPSHOT_WINDOW_SLOTS,
+    },
+    discriminators::Discriminators,
+    error::TipRouterError,
+    fees::{Fees, LegacyFee},
+    ncn_epoch::NcnEpochSchedule,
+};
 
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod, AccountDeserialize, ShankAccount)]
 #[repr(C)]
@@ -25,14 +33,66 @@ pub struct NcnConfig {
 
     /// Bump seed for the PDA
     pub bump: u8,
+
+    /// Number of epochs after which per-epoch accounts can be closed
+    epochs_before_close: PodU64,
+
+    /// Number of slots from the start of an NCN epoch during which it can be snapshotted
+    snapshot_window_slots: PodU64,
+
+    /// Number of epochs a past NCN epoch can still be snapshotted for
+    snapshot_grace_epochs: PodU64,
+
     // /// Reserved space
-    reserved: [u8; 127],
+    reserved: [u8; 103],
 }
 
 impl Discriminator for NcnConfig {
     const DISCRIMINATOR: u8 = Discriminators::NCNConfig as u8;
 }
 
+/// [`NcnConfig`] as created before the DAO and NCN fee wallets were separated. Configs in this
+/// layout no longer load and are rewritten by `MigrateNcnConfig`, see [`NcnConfig::from_legacy`].
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize)]
+#[repr(C)]
+pub struct LegacyNcnConfig {
+    pub ncn: Pubkey,
+    pub tie_breaker_admin: Pubkey,
+    pub fee_admin: Pubkey,
+    pub valid_slots_after_consensus: PodU64,
+    pub epochs_before_stall: PodU64,
+    pub fee_1: LegacyFee,
+    pub fee_2: LegacyFee,
+    pub bump: u8,
+    reserved: [u8; 127],
+}
+
+impl Discriminator for LegacyNcnConfig {
+    const DISCRIMINATOR: u8 = Discriminators::NCNConfig as u8;
+}
+
+impl LegacyNcnConfig {
+    pub fn new(
+        ncn: Pubkey,
+        tie_breaker_admin: Pubkey,
+        fee_admin: Pubkey,
+        fee: LegacyFee,
+        bump: u8,
+    ) -> Self {
+        Self {
+            ncn,
+            tie_breaker_admin,
+            fee_admin,
+            valid_slots_after_consensus: PodU64::from(0),
+            epochs_before_stall: PodU64::from(0),
+            fee_1: fee,
+            fee_2: fee,
+            bump,
+            reserved: [0; 127],
+        }
+    }
+}
+
 impl NcnConfig {
     pub fn new(ncn: Pubkey, tie_breaker_admin: Pubkey, fee_admin: Pubkey, fees: Fees) -> Self {
         Self {
@@ -43,10 +103,28 @@ impl NcnConfig {
             epochs_before_stall: PodU64::from(0),         // TODO set this
             fees,
             bump: 0,
-            reserved: [0; 127],
+            epochs_before_close: PodU64::from(DEFAULT_EPOCHS_BEFORE_CLOSE),
+            snapshot_window_slots: PodU64::from(DEFAULT_SNAPSHOT_WINDOW_SLOTS),
+            snapshot_grace_epochs: PodU64::from(DEFAULT_SNAPSHOT_GRACE_EPOCHS),
+            reserved: [0; 103],
         }
     }
 
+    /// The current layout of a legacy config. Both fee wallets are set to the legacy wallet and
+    /// parameters added since are set as in [`Self::new`].
+    pub fn from_legacy(legacy: &LegacyNcnConfig) -> Self {
+        let mut config = Self::new(
+            legacy.ncn,
+            legacy.tie_breaker_admin,
+            legacy.fee_admin,
+            Fees::from_legacy(legacy.fee_1, legacy.fee_2),
+        );
+        config.valid_slots_after_consensus = legacy.valid_slots_after_consensus;
+        config.epochs_before_stall = legacy.epochs_before_stall;
+        config.bump = legacy.bump;
+        config
+    }
+
     pub fn seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
         vec![b"config".to_vec(), ncn.to_bytes().to_vec()]
     }
@@ -109,4 +187,230 @@ impl NcnConfig {
     pub fn epochs_before_stall(&self) -> u64 {
         self.epochs_before_stall.into()
     }
+
+    pub fn epochs_before_close(&self) -> u64 {
+        self.epochs_before_close.into()
+    }
+
+    pub fn set_epochs_before_close(
+        &mut self,
+        epochs_before_close: u64,
+    ) -> Result<(), TipRouterError> {
+        // Accounts of the current epoch are still in use
+        if epochs_before_close == 0 {
+            return Err(TipRouterError::InvalidEpochsBeforeClose);
+        }
+        self.epochs_before_close = PodU64::from(epochs_before_close);
+        Ok(())
+    }
+
+    /// Whether accounts of `epoch` can be closed during `current_epoch`. Once the epoch's
+    /// ballot box reached consensus that takes `epochs_before_close` epochs. Without consensus
+    /// it takes that long after voting stalls, so the tie breaker can still be set and merkle
+    /// roots uploaded.
+    pub fn can_close_epoch(
+        &self,
+        epoch: u64,
+        current_epoch: u64,
+        consensus_reached: bool,
+    ) -> Result<bool, TipRouterError> {
+        let retention_start_epoch = if consensus_reached {
+            epoch
+        } else {
+            epoch
+                .checked_add(self.epochs_before_stall())
+                .ok_or(TipRouterError::ArithmeticOverflow)?
+        };
+
+        let closable_epoch = retention_start_epoch
+            .checked_add(self.epochs_before_close())
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        Ok(current_epoch >= closable_epoch)
+    }
+
+    pub fn snapshot_window_slots(&self) -> u64 {
+        self.snapshot_window_slots.into()
+    }
+
+    pub fn set_snapshot_window_slots(
+        &mut self,
+        snapshot_window_slots: u64,
+    ) -> Result<(), TipRouterError> {
+        if snapshot_window_slots == 0 {
+            return Err(TipRouterError::InvalidSnapshotWindow);
+        }
+        self.snapshot_window_slots = PodU64::from(snapshot_window_slots);
+        Ok(())
+    }
+
+    pub fn snapshot_grace_epochs(&self) -> u64 {
+        self.snapshot_grace_epochs.into()
+    }
+
+    pub fn set_snapshot_grace_epochs(&mut self, snapshot_grace_epochs: u64) {
+        self.snapshot_grace_epochs = PodU64::from(snapshot_grace_epochs);
+    }
+
+    /// Checks that `ncn_epoch` can be snapshotted at `current_slot`. The current epoch can only
+    /// be snapshotted within the window at its start, past epochs only within the grace period.
+    pub fn check_snapshot_window(
+        &self,
+        ncn_epoch: u64,
+        current_slot: u64,
+        ncn_epoch_schedule: &NcnEpochSchedule,
+    ) -> Result<(), TipRouterError> {
+        let current_ncn_epoch = ncn_epoch_schedule.ncn_epoch(current_slot);
+
+        if ncn_epoch < current_ncn_epoch {
+            let epochs_passed = current_ncn_epoch - ncn_epoch;
+            if epochs_passed > self.snapshot_grace_epochs() {
+                return Err(TipRouterError::SnapshotGracePeriodExpired);
+            }
+            return Ok(());
+        }
+
+        let slots_into_epoch = ncn_epoch_schedule.slots_into_ncn_epoch(current_slot);
+
+        if slots_into_epoch >= self.snapshot_window_slots() {
+            return Err(TipRouterError::SnapshotWindowClosed);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::epoch_schedule::EpochSchedule;
+
+    use super::*;
+
+    #[test]
+    fn test_can_close_epoch() {
+        let mut config = NcnConfig::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0, 0),
+        );
+        assert_eq!(config.epochs_before_close(), DEFAULT_EPOCHS_BEFORE_CLOSE);
+
+        config.set_epochs_before_close(3).unwrap();
+        config.epochs_before_stall = PodU64::from(5);
+        assert!(!config.can_close_epoch(10, 10, true).unwrap());
+        assert!(!config.can_close_epoch(10, 12, true).unwrap());
+        assert!(config.can_close_epoch(10, 13, true).unwrap());
+        assert!(config.can_close_epoch(10, 100, true).unwrap());
+
+        // Without consensus the retention period starts once voting stalls
+        assert!(!config.can_close_epoch(10, 13, false).unwrap());
+        assert!(!config.can_close_epoch(10, 17, false).unwrap());
+        assert!(config.can_close_epoch(10, 18, false).unwrap());
+
+        assert_eq!(
+            config.set_epochs_before_close(0),
+            Err(TipRouterError::InvalidEpochsBeforeClose)
+        );
+    }
+
+    #[test]
+    fn test_new_parameters_use_defaults() {
+        let config = NcnConfig::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0, 0),
+        );
+        assert_eq!(config.epochs_before_close(), DEFAULT_EPOCHS_BEFORE_CLOSE);
+        assert_eq!(
+            config.snapshot_window_slots(),
+            DEFAULT_SNAPSHOT_WINDOW_SLOTS
+        );
+        assert_eq!(
+            config.snapshot_grace_epochs(),
+            DEFAULT_SNAPSHOT_GRACE_EPOCHS
+        );
+
+        // Migrated configs get the same defaults
+        let legacy = LegacyNcnConfig::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            LegacyFee::new(Pubkey::new_unique(), 0, 0, 0, 0),
+            255,
+        );
+        let migrated = NcnConfig::from_legacy(&legacy);
+        assert_eq!(migrated.epochs_before_close(), DEFAULT_EPOCHS_BEFORE_CLOSE);
+        assert_eq!(
+            migrated.snapshot_window_slots(),
+            DEFAULT_SNAPSHOT_WINDOW_SLOTS
+        );
+        assert_eq!(
+            migrated.snapshot_grace_epochs(),
+            DEFAULT_SNAPSHOT_GRACE_EPOCHS
+        );
+
+        let ncn_epoch_schedule =
+            NcnEpochSchedule::new(100, EpochSchedule::without_warmup()).unwrap();
+        assert!(config
+            .can_close_epoch(0, DEFAULT_EPOCHS_BEFORE_CLOSE, true)
+            .unwrap());
+        assert!(config
+            .check_snapshot_window(3, 399, &ncn_epoch_schedule)
+            .is_ok());
+        assert!(config
+            .check_snapshot_window(2, 300, &ncn_epoch_schedule)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_check_snapshot_window() {
+        let mut config = NcnConfig::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Fees::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0, 0),
+        );
+        let ncn_epoch_schedule =
+            NcnEpochSchedule::new(100, EpochSchedule::without_warmup()).unwrap();
+
+        // By default the whole epoch is open
+        assert!(config
+            .check_snapshot_window(3, 399, &ncn_epoch_schedule)
+            .is_ok());
+
+        config.set_snapshot_window_slots(10).unwrap();
+        assert!(config
+            .check_snapshot_window(3, 300, &ncn_epoch_schedule)
+            .is_ok());
+        assert!(config
+            .check_snapshot_window(3, 309, &ncn_epoch_schedule)
+            .is_ok());
+        assert_eq!(
+            config.check_snapshot_window(3, 310, &ncn_epoch_schedule),
+            Err(TipRouterError::SnapshotWindowClosed)
+        );
+
+        // Past epochs are only accepted within the grace period
+        config.set_snapshot_grace_epochs(1);
+        assert!(config
+            .check_snapshot_window(2, 350, &ncn_epoch_schedule)
+            .is_ok());
+        assert_eq!(
+            config.check_snapshot_window(1, 350, &ncn_epoch_schedule),
+            Err(TipRouterError::SnapshotGracePeriodExpired)
+        );
+
+        config.set_snapshot_grace_epochs(0);
+        assert_eq!(
+            config.check_snapshot_window(2, 300, &ncn_epoch_schedule),
+            Err(TipRouterError::SnapshotGracePeriodExpired)
+        );
+
+        assert_eq!(
+            config.set_snapshot_window_slots(0),
+            Err(TipRouterError::InvalidSnapshotWindow)
+        );
+    }
 }
@@ -0,0 +1,234 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::config::Config;
+use solana_program::{
+    account_info::AccountInfo, epoch_schedule::EpochSchedule, program_error::ProgramError,
+    sysvar::Sysvar,
+};
+
+use crate::error::TipRouterError;
+
+/// Maps slots to NCN epochs and NCN epochs to Solana epochs.
+///
+/// NCN epochs are `epoch_length` slots long, as set in the restaking [`Config`], and every
+/// per-epoch account of this program is keyed by one. Solana epochs follow the cluster's
+/// [`EpochSchedule`] and key the tip distribution accounts. The two only line up when the
+/// lengths match and the cluster has no warmup period.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NcnEpochSchedule {
+    ncn_epoch_length: u64,
+    epoch_schedule: EpochSchedule,
+}
+
+impl NcnEpochSchedule {
+    pub fn new(
+        ncn_epoch_length: u64,
+        epoch_schedule: EpochSchedule,
+    ) -> Result<Self, TipRouterError> {
+        if ncn_epoch_length == 0 {
+            return Err(TipRouterError::DenominatorIsZero);
+        }
+
+        Ok(Self {
+            ncn_epoch_length,
+            epoch_schedule,
+        })
+    }
+
+    /// Reads the NCN epoch length from the restaking config and the Solana epoch schedule
+    /// from its sysvar
+    pub fn load(restaking_config: &AccountInfo) -> Result<Self, ProgramError> {
+        let ncn_epoch_length = {
+            let config_data = restaking_config.data.borrow();
+            let config = Config::try_from_slice_unchecked(&config_data)?;
+            config.epoch_length()
+        };
+
+        Ok(Self::new(ncn_epoch_length, EpochSchedule::get()?)?)
+    }
+
+    pub const fn ncn_epoch_length(&self) -> u64 {
+        self.ncn_epoch_length
+    }
+
+    pub const fn epoch_schedule(&self) -> &EpochSchedule {
+        &self.epoch_schedule
+    }
+
+    /// The NCN epoch `slot` falls in
+    pub const fn ncn_epoch(&self, slot: u64) -> u64 {
+        slot / self.ncn_epoch_length
+    }
+
+    /// Number of slots between the start of its NCN epoch and `slot`
+    pub const fn slots_into_ncn_epoch(&self, slot: u64) -> u64 {
+        slot % self.ncn_epoch_length
+    }
+
+    pub fn first_slot_of_ncn_epoch(&self, ncn_epoch: u64) -> Result<u64, TipRouterError> {
+        ncn_epoch
+            .checked_mul(self.ncn_epoch_length)
+            .ok_or(TipRouterError::ArithmeticOverflow)
+    }
+
+    pub fn last_slot_of_ncn_epoch(&self, ncn_epoch: u64) -> Result<u64, TipRouterError> {
+        ncn_epoch
+            .checked_add(1)
+            .and_then(|next_epoch| next_epoch.checked_mul(self.ncn_epoch_length))
+            .and_then(|next_epoch_slot| next_epoch_slot.checked_sub(1))
+            .ok_or(TipRouterError::ArithmeticOverflow)
+    }
+
+    /// The Solana epoch `slot` falls in
+    pub fn solana_epoch(&self, slot: u64) -> u64 {
+        self.epoch_schedule.get_epoch(slot)
+    }
+
+    /// The Solana epoch an NCN epoch starts in
+    pub fn solana_epoch_of_ncn_epoch(&self, ncn_epoch: u64) -> Result<u64, TipRouterError> {
+        Ok(self.solana_epoch(self.first_slot_of_ncn_epoch(ncn_epoch)?))
+    }
+
+    /// Whether any slot of `solana_epoch` falls in `ncn_epoch`. The NCN epoch's ballot box sets
+    /// merkle roots for the tip distribution accounts of these Solana epochs.
+    pub fn ncn_epoch_overlaps_solana_epoch(
+        &self,
+        ncn_epoch: u64,
+        solana_epoch: u64,
+    ) -> Result<bool, TipRouterError> {
+        let first_slot = self.epoch_schedule.get_first_slot_in_epoch(solana_epoch);
+        let last_slot = self.epoch_schedule.get_last_slot_in_epoch(solana_epoch);
+
+        Ok(first_slot <= self.last_slot_of_ncn_epoch(ncn_epoch)?
+            && last_slot >= self.first_slot_of_ncn_epoch(ncn_epoch)?)
+    }
+
+    /// The NCN epoch a Solana epoch starts in
+    pub fn ncn_epoch_of_solana_epoch(&self, solana_epoch: u64) -> u64 {
+        self.ncn_epoch(self.epoch_schedule.get_first_slot_in_epoch(solana_epoch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::clock::DEFAULT_SLOTS_PER_EPOCH;
+
+    use super::*;
+
+    #[test]
+    fn test_zero_epoch_length() {
+        assert_eq!(
+            NcnEpochSchedule::new(0, EpochSchedule::without_warmup()),
+            Err(TipRouterError::DenominatorIsZero)
+        );
+    }
+
+    #[test]
+    fn test_matching_epoch_lengths() {
+        let schedule =
+            NcnEpochSchedule::new(DEFAULT_SLOTS_PER_EPOCH, EpochSchedule::without_warmup())
+                .unwrap();
+
+        for slot in [
+            0,
+            1,
+            DEFAULT_SLOTS_PER_EPOCH - 1,
+            5 * DEFAULT_SLOTS_PER_EPOCH + 7,
+        ] {
+            assert_eq!(schedule.ncn_epoch(slot), schedule.solana_epoch(slot));
+        }
+        for epoch in [0, 1, 100] {
+            assert_eq!(schedule.solana_epoch_of_ncn_epoch(epoch), Ok(epoch));
+            assert_eq!(schedule.ncn_epoch_of_solana_epoch(epoch), epoch);
+            assert_eq!(
+                schedule.ncn_epoch_overlaps_solana_epoch(epoch, epoch),
+                Ok(true)
+            );
+            assert_eq!(
+                schedule.ncn_epoch_overlaps_solana_epoch(epoch, epoch + 1),
+                Ok(false)
+            );
+        }
+    }
+
+    #[test]
+    fn test_shorter_ncn_epochs() {
+        // Four NCN epochs per Solana epoch
+        let ncn_epoch_length = DEFAULT_SLOTS_PER_EPOCH / 4;
+        let schedule =
+            NcnEpochSchedule::new(ncn_epoch_length, EpochSchedule::without_warmup()).unwrap();
+
+        let slot = 3 * DEFAULT_SLOTS_PER_EPOCH + ncn_epoch_length + 5;
+        assert_eq!(schedule.ncn_epoch(slot), 13);
+        assert_eq!(schedule.solana_epoch(slot), 3);
+        assert_eq!(schedule.slots_into_ncn_epoch(slot), 5);
+
+        for ncn_epoch in 12..16 {
+            assert_eq!(schedule.solana_epoch_of_ncn_epoch(ncn_epoch), Ok(3));
+            assert_eq!(
+                schedule.ncn_epoch_overlaps_solana_epoch(ncn_epoch, 3),
+                Ok(true)
+            );
+            assert_eq!(
+                schedule.ncn_epoch_overlaps_solana_epoch(ncn_epoch, 2),
+                Ok(false)
+            );
+            assert_eq!(
+                schedule.ncn_epoch_overlaps_solana_epoch(ncn_epoch, 4),
+                Ok(false)
+            );
+        }
+        assert_eq!(schedule.solana_epoch_of_ncn_epoch(16), Ok(4));
+        assert_eq!(schedule.ncn_epoch_of_solana_epoch(3), 12);
+
+        assert_eq!(
+            schedule.first_slot_of_ncn_epoch(13),
+            Ok(13 * ncn_epoch_length)
+        );
+        assert_eq!(
+            schedule.last_slot_of_ncn_epoch(13),
+            Ok(14 * ncn_epoch_length - 1)
+        );
+    }
+
+    #[test]
+    fn test_longer_ncn_epochs() {
+        // One NCN epoch per three Solana epochs
+        let schedule =
+            NcnEpochSchedule::new(3 * DEFAULT_SLOTS_PER_EPOCH, EpochSchedule::without_warmup())
+                .unwrap();
+
+        assert_eq!(schedule.solana_epoch_of_ncn_epoch(2), Ok(6));
+        assert_eq!(schedule.ncn_epoch_overlaps_solana_epoch(2, 5), Ok(false));
+        assert_eq!(schedule.ncn_epoch_overlaps_solana_epoch(2, 9), Ok(false));
+        for solana_epoch in 6..9 {
+            assert_eq!(
+                schedule.ncn_epoch_overlaps_solana_epoch(2, solana_epoch),
+                Ok(true)
+            );
+            let slot = schedule
+                .epoch_schedule()
+                .get_first_slot_in_epoch(solana_epoch);
+            assert_eq!(schedule.ncn_epoch(slot), 2);
+        }
+        assert_eq!(schedule.ncn_epoch_of_solana_epoch(7), 2);
+        assert_eq!(schedule.ncn_epoch_of_solana_epoch(9), 3);
+    }
+
+    #[test]
+    fn test_solana_warmup() {
+        // Solana epochs start short and double in length during warmup, NCN epochs do not
+        let epoch_schedule = EpochSchedule::custom(DEFAULT_SLOTS_PER_EPOCH, 0, true);
+        let schedule = NcnEpochSchedule::new(DEFAULT_SLOTS_PER_EPOCH, epoch_schedule).unwrap();
+
+        assert_eq!(schedule.ncn_epoch(DEFAULT_SLOTS_PER_EPOCH - 1), 0);
+        assert!(schedule.solana_epoch(DEFAULT_SLOTS_PER_EPOCH - 1) > 0);
+
+        let solana_epoch = schedule.solana_epoch_of_ncn_epoch(1).unwrap();
+        assert_eq!(schedule.solana_epoch(DEFAULT_SLOTS_PER_EPOCH), solana_epoch);
+
+        assert_eq!(
+            schedule.last_slot_of_ncn_epoch(u64::MAX),
+            Err(TipRouterError::ArithmeticOverflow)
+        );
+    }
+}
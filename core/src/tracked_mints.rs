@@ -1,11 +1,11 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, mem::size_of};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use shank::{ShankAccount, ShankType};
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{discriminators::Discriminators, error::TipRouterError};
+use crate::{constants::MAX_TRACKED_MINTS, discriminators::Discriminators, error::TipRouterError};
 
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
 #[repr(C)]
@@ -27,6 +27,15 @@ impl MintEntry {
     pub fn vault_index(&self) -> u64 {
         self.vault_index.into()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.vault_index() == u64::MAX
+    }
+
+    /// A deregistered entry keeps its vault index but no longer tracks a mint
+    pub fn is_deregistered(&self) -> bool {
+        !self.is_empty() && self.st_mint == Pubkey::default()
+    }
 }
 
 impl Default for MintEntry {
@@ -41,7 +50,7 @@ pub struct TrackedMints {
     pub ncn: Pubkey,
     pub bump: u8,
     pub reserved: [u8; 7], // TODO extend to 127; figure out serde issue
-    pub st_mint_list: [MintEntry; 16], // TODO extend to 64; figure out serde issue
+    pub st_mint_list: [MintEntry; 64],
 }
 
 impl Discriminator for TrackedMints {
@@ -49,15 +58,23 @@ impl Discriminator for TrackedMints {
 }
 
 impl TrackedMints {
+    /// Size of the account data preceding `st_mint_list`, including the discriminator
+    pub const HEADER_SIZE: usize = 8 + 32 + 1 + 7;
+
     pub fn new(ncn: Pubkey, bump: u8) -> Self {
         Self {
             ncn,
             bump,
             reserved: [0; 7],
-            st_mint_list: [MintEntry::default(); 16],
+            st_mint_list: [MintEntry::default(); MAX_TRACKED_MINTS],
         }
     }
 
+    /// Number of entries held by an account of `data_len` bytes
+    pub fn entries_for_len(data_len: usize) -> usize {
+        data_len.saturating_sub(Self::HEADER_SIZE) / size_of::<MintEntry>()
+    }
+
     pub fn seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter(
             [b"tracked_mints".to_vec(), ncn.to_bytes().to_vec()]
@@ -84,11 +101,16 @@ impl TrackedMints {
         }
 
         // Check if vault_index is already in use by a different mint
-        if self
+        if let Some(mint_entry) = self
             .st_mint_list
-            .iter()
-            .any(|m| m.vault_index() == vault_index)
+            .iter_mut()
+            .find(|m| m.vault_index() == vault_index)
         {
+            // A deregistered vault can be registered again
+            if mint_entry.is_deregistered() {
+                *mint_entry = MintEntry::new(mint, vault_index);
+                return Ok(());
+            }
             return Err(TipRouterError::VaultIndexAlreadyInUse.into());
         }
 
@@ -96,13 +118,42 @@ impl TrackedMints {
         let mint_entry = self
             .st_mint_list
             .iter_mut()
-            .find(|m| m.st_mint == MintEntry::default().st_mint)
+            .find(|m| m.is_empty())
             .ok_or(TipRouterError::TrackedMintListFull)?;
 
         *mint_entry = MintEntry::new(mint, vault_index);
         Ok(())
     }
 
+    /// Clears the mint tracked for `vault_index`. The vault index is kept so the
+    /// vault still counts towards `vault_count`, and is recorded if it was never registered.
+    pub fn deregister_mint(&mut self, vault_index: u64) -> Result<(), ProgramError> {
+        if vault_index == u64::MAX {
+            return Err(TipRouterError::VaultIndexInvalid.into());
+        }
+
+        if let Some(mint_entry) = self
+            .st_mint_list
+            .iter_mut()
+            .find(|m| m.vault_index() == vault_index)
+        {
+            if mint_entry.is_deregistered() {
+                return Err(TipRouterError::MintAlreadyDeregistered.into());
+            }
+            *mint_entry = MintEntry::new(Pubkey::default(), vault_index);
+            return Ok(());
+        }
+
+        let mint_entry = self
+            .st_mint_list
+            .iter_mut()
+            .find(|m| m.is_empty())
+            .ok_or(TipRouterError::TrackedMintListFull)?;
+
+        *mint_entry = MintEntry::new(Pubkey::default(), vault_index);
+        Ok(())
+    }
+
     pub fn mint_count(&self) -> u64 {
         self.st_mint_list
             .iter()
@@ -110,6 +161,11 @@ impl TrackedMints {
             .count() as u64
     }
 
+    /// Number of vaults accounted for, whether registered or deregistered
+    pub fn vault_count(&self) -> u64 {
+        self.st_mint_list.iter().filter(|m| !m.is_empty()).count() as u64
+    }
+
     pub fn get_unique_mints(&self) -> Vec<Pubkey> {
         let mut unique_mints: HashSet<Pubkey> = HashSet::new();
         self.st_mint_list
@@ -223,4 +279,58 @@ mod tests {
         let empty_tracked_mints = TrackedMints::new(Pubkey::default(), 0);
         assert_eq!(empty_tracked_mints.get_unique_mints().len(), 0);
     }
+
+    #[test]
+    fn test_capacity() {
+        let tracked_mints = TrackedMints::new(Pubkey::default(), 0);
+        assert_eq!(tracked_mints.st_mint_list.len(), MAX_TRACKED_MINTS);
+        assert_eq!(
+            TrackedMints::entries_for_len(8 + size_of::<TrackedMints>()),
+            MAX_TRACKED_MINTS
+        );
+        assert_eq!(
+            TrackedMints::entries_for_len(TrackedMints::HEADER_SIZE + 16 * size_of::<MintEntry>()),
+            16
+        );
+    }
+
+    #[test]
+    fn test_deregister_mint() {
+        let mut tracked_mints = TrackedMints::new(Pubkey::default(), 0);
+        let mint1 = Pubkey::new_unique();
+        let mint2 = Pubkey::new_unique();
+        tracked_mints.add_mint(mint1, 0).unwrap();
+        tracked_mints.add_mint(mint2, 1).unwrap();
+
+        tracked_mints.deregister_mint(0).unwrap();
+        assert_eq!(tracked_mints.mint_count(), 1);
+        assert_eq!(tracked_mints.vault_count(), 2);
+        assert_eq!(tracked_mints.get_unique_mints(), vec![mint2]);
+
+        // Deregistering twice should fail
+        assert_eq!(
+            tracked_mints.deregister_mint(0),
+            Err(TipRouterError::MintAlreadyDeregistered.into())
+        );
+
+        // A vault that was never registered is still recorded
+        tracked_mints.deregister_mint(5).unwrap();
+        assert_eq!(tracked_mints.mint_count(), 1);
+        assert_eq!(tracked_mints.vault_count(), 3);
+
+        // New vaults do not reuse deregistered slots
+        let mint3 = Pubkey::new_unique();
+        tracked_mints.add_mint(mint3, 2).unwrap();
+        assert_eq!(tracked_mints.vault_count(), 4);
+
+        // Deregistered vaults can be registered again
+        tracked_mints.add_mint(mint1, 0).unwrap();
+        assert_eq!(tracked_mints.mint_count(), 3);
+        assert_eq!(tracked_mints.vault_count(), 4);
+
+        assert_eq!(
+            tracked_mints.deregister_mint(u64::MAX),
+            Err(TipRouterError::VaultIndexInvalid.into())
+        );
+    }
 }
@@ -73,6 +73,31 @@ impl WeightEntry {
         PreciseNumber::new(self.weight.into()).ok_or(TipRouterError::NewPreciseNumberError)
     }
 
+    /// Converts a human readable exchange rate (e.g. 1.0234 SOL per JitoSOL) into a weight
+    pub fn weight_from_exchange_rate(exchange_rate: f64) -> Result<u128, TipRouterError> {
+        if !exchange_rate.is_finite() || exchange_rate < 0.0 {
+            return Err(TipRouterError::InvalidExchangeRate);
+        }
+
+        let weight = (exchange_rate * Self::DECIMAL_PRECISION as f64).round();
+        if weight >= u128::MAX as f64 {
+            return Err(TipRouterError::ArithmeticOverflow);
+        }
+
+        Ok(weight as u128)
+    }
+
+    /// Converts a weight back into a human readable exchange rate
+    pub fn exchange_rate_from_weight(weight: u128) -> f64 {
+        let whole = weight / Self::DECIMAL_PRECISION;
+        let fraction = weight % Self::DECIMAL_PRECISION;
+        whole as f64 + fraction as f64 / Self::DECIMAL_PRECISION as f64
+    }
+
+    pub fn exchange_rate(&self) -> f64 {
+        Self::exchange_rate_from_weight(self.weight())
+    }
+
     pub fn set_weight(&mut self, weight: u128, current_slot: u64) {
         self.weight = PodU128::from(weight);
 
@@ -101,4 +126,40 @@ mod tests {
         assert_eq!(weight_entry.slot_set(), 0);
         assert_eq!(weight_entry.slot_updated(), 0);
     }
+
+    #[test]
+    fn test_exchange_rate_conversion() {
+        assert_eq!(
+            WeightEntry::weight_from_exchange_rate(1.0).unwrap(),
+            WeightEntry::DECIMAL_PRECISION
+        );
+        assert_eq!(
+            WeightEntry::weight_from_exchange_rate(1.0234).unwrap(),
+            1_023_400_000_000
+        );
+        assert_eq!(WeightEntry::weight_from_exchange_rate(0.0).unwrap(), 0);
+
+        assert_eq!(
+            WeightEntry::exchange_rate_from_weight(1_023_400_000_000),
+            1.0234
+        );
+        assert_eq!(WeightEntry::exchange_rate_from_weight(0), 0.0);
+
+        let mut weight_entry = WeightEntry::new(Pubkey::new_unique());
+        weight_entry.set_weight(WeightEntry::weight_from_exchange_rate(1.5).unwrap(), 1);
+        assert_eq!(weight_entry.exchange_rate(), 1.5);
+
+        assert_eq!(
+            WeightEntry::weight_from_exchange_rate(-1.0),
+            Err(TipRouterError::InvalidExchangeRate)
+        );
+        assert_eq!(
+            WeightEntry::weight_from_exchange_rate(f64::NAN),
+            Err(TipRouterError::InvalidExchangeRate)
+        );
+        assert_eq!(
+            WeightEntry::weight_from_exchange_rate(f64::MAX),
+            Err(TipRouterError::ArithmeticOverflow)
+        );
+    }
 }
@@ -25,8 +25,11 @@ pub struct WeightTable {
     /// Bump seed for the PDA
     bump: u8,
 
+    /// Account that paid for the weight table, refunded when it is closed
+    payer: Pubkey,
+
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 96],
 
     /// The weight table
     table: [WeightEntry; 32],
@@ -39,13 +42,14 @@ impl Discriminator for WeightTable {
 impl WeightTable {
     pub const MAX_TABLE_ENTRIES: usize = 32;
 
-    pub fn new(ncn: Pubkey, ncn_epoch: u64, slot_created: u64, bump: u8) -> Self {
+    pub fn new(ncn: Pubkey, ncn_epoch: u64, slot_created: u64, bump: u8, payer: Pubkey) -> Self {
         Self {
             ncn,
             ncn_epoch: PodU64::from(ncn_epoch),
             slot_created: PodU64::from(slot_created),
             bump,
-            reserved: [0; 128],
+            payer,
+            reserved: [0; 96],
             table: [WeightEntry::default(); Self::MAX_TABLE_ENTRIES],
         }
     }
@@ -168,6 +172,10 @@ impl WeightTable {
         self.slot_created.into()
     }
 
+    pub const fn payer(&self) -> Pubkey {
+        self.payer
+    }
+
     pub fn initialized(&self) -> bool {
         self.mint_count() > 0
     }
@@ -228,7 +236,7 @@ mod tests {
     #[test]
     fn test_initialize_table_success() {
         let ncn = Pubkey::new_unique();
-        let mut table = WeightTable::new(ncn, 0, 0, 0);
+        let mut table = WeightTable::new(ncn, 0, 0, 0, Pubkey::default());
         assert_eq!(table.mint_count(), 0);
 
         let mints = get_test_pubkeys(2);
@@ -239,7 +247,7 @@ mod tests {
     #[test]
     fn test_initialize_table_too_many() {
         let ncn = Pubkey::new_unique();
-        let mut table = WeightTable::new(ncn, 0, 0, 0);
+        let mut table = WeightTable::new(ncn, 0, 0, 0, Pubkey::default());
         let many_mints = get_test_pubkeys(WeightTable::MAX_TABLE_ENTRIES + 1);
         assert_eq!(
             table.initalize_weight_table(&many_mints),
@@ -250,7 +258,7 @@ mod tests {
     #[test]
     fn test_initialize_table_max() {
         let ncn = Pubkey::new_unique();
-        let mut table = WeightTable::new(ncn, 0, 0, 0);
+        let mut table = WeightTable::new(ncn, 0, 0, 0, Pubkey::default());
         let max_mints = get_test_pubkeys(WeightTable::MAX_TABLE_ENTRIES);
         table.initalize_weight_table(&max_mints).unwrap();
         assert_eq!(table.mint_count(), WeightTable::MAX_TABLE_ENTRIES);
@@ -259,7 +267,7 @@ mod tests {
     #[test]
     fn test_initialize_table_reinitialize() {
         let ncn = Pubkey::new_unique();
-        let mut table = WeightTable::new(ncn, 0, 0, 0);
+        let mut table = WeightTable::new(ncn, 0, 0, 0, Pubkey::default());
         let first_mints = get_test_pubkeys(2);
         table.initalize_weight_table(&first_mints).unwrap();
         let second_mints = get_test_pubkeys(3);
@@ -273,7 +281,7 @@ mod tests {
     #[test]
     fn test_set_weight_success() {
         let ncn = Pubkey::new_unique();
-        let mut table = WeightTable::new(ncn, 0, 0, 0);
+        let mut table = WeightTable::new(ncn, 0, 0, 0, Pubkey::default());
         let mints = get_test_pubkeys(2);
         let mint = mints[0];
 
@@ -286,7 +294,7 @@ mod tests {
     #[test]
     fn test_set_weight_invalid_mint() {
         let ncn = Pubkey::new_unique();
-        let mut table = WeightTable::new(ncn, 0, 0, 0);
+        let mut table = WeightTable::new(ncn, 0, 0, 0, Pubkey::default());
         let mints = get_test_pubkeys(2);
 
         table.initalize_weight_table(&mints).unwrap();
@@ -301,7 +309,7 @@ mod tests {
     #[test]
     fn test_set_weight_update_existing() {
         let ncn = Pubkey::new_unique();
-        let mut table = WeightTable::new(ncn, 0, 0, 0);
+        let mut table = WeightTable::new(ncn, 0, 0, 0, Pubkey::default());
         let mints = get_test_pubkeys(2);
         let mint = mints[0];
 
@@ -317,7 +325,7 @@ mod tests {
     #[test]
     fn test_set_weight_multiple_mints() {
         let ncn = Pubkey::new_unique();
-        let mut table = WeightTable::new(ncn, 0, 0, 0);
+        let mut table = WeightTable::new(ncn, 0, 0, 0, Pubkey::default());
         let mints = get_test_pubkeys(2);
         let mint1 = mints[0];
         let mint2 = mints[1];
@@ -334,7 +342,7 @@ mod tests {
     #[test]
     fn test_set_weight_different_slots() {
         let ncn = Pubkey::new_unique();
-        let mut table = WeightTable::new(ncn, 0, 0, 0);
+        let mut table = WeightTable::new(ncn, 0, 0, 0, Pubkey::default());
         let mints = get_test_pubkeys(2);
         let mint = mints[0];
 
@@ -5,14 +5,18 @@ use std::{
 
 use jito_restaking_core::{config::Config, ncn_vault_ticket::NcnVaultTicket};
 use jito_tip_distribution_sdk::jito_tip_distribution;
+use jito_tip_router_core::weight_entry::WeightEntry;
 use jito_vault_core::vault_ncn_ticket::VaultNcnTicket;
 use solana_program::{
     clock::Clock, native_token::sol_to_lamports, pubkey::Pubkey, system_instruction::transfer,
 };
 use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
-    account::Account, commitment_config::CommitmentLevel, epoch_schedule::EpochSchedule,
-    signature::Signer, transaction::Transaction,
+    account::Account,
+    commitment_config::CommitmentLevel,
+    epoch_schedule::EpochSchedule,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
 };
 
 use super::{
@@ -114,6 +118,17 @@ impl TestBuilder {
             .set_account(&address, &account.into())
     }
 
+    pub async fn get_account(
+        &mut self,
+        address: &Pubkey,
+    ) -> Result<Option<Account>, BanksClientError> {
+        self.context.banks_client.get_account(*address).await
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.context.payer.pubkey()
+    }
+
     pub async fn clock(&mut self) -> Clock {
         self.context.banks_client.get_sysvar().await.unwrap()
     }
@@ -175,6 +190,28 @@ impl TestBuilder {
             .await
     }
 
+    /// Moves lamports out of a wallet it signs for, the payer pays the transaction fee
+    pub async fn transfer_from(
+        &mut self,
+        from: &Keypair,
+        to: &Pubkey,
+        lamports: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.context.banks_client.get_latest_blockhash().await?;
+        self.context
+            .banks_client
+            .process_transaction_with_preflight_and_commitment(
+                Transaction::new_signed_with_payer(
+                    &[transfer(&from.pubkey(), to, lamports)],
+                    Some(&self.context.payer.pubkey()),
+                    &[&self.context.payer, from],
+                    blockhash,
+                ),
+                CommitmentLevel::Processed,
+            )
+            .await
+    }
+
     pub async fn setup_ncn(&mut self) -> TestResult<NcnRoot> {
         let mut restaking_program_client = self.restaking_program_client();
         let mut vault_program_client = self.vault_program_client();
@@ -405,7 +442,7 @@ impl TestBuilder {
         let mut tip_router_client = self.tip_router_client();
         let mut vault_client = self.vault_program_client();
 
-        const WEIGHT: u128 = 100;
+        const WEIGHT: u128 = 100 * WeightEntry::DECIMAL_PRECISION;
 
         // Not sure if this is needed
         self.warp_slot_incremental(1000).await?;
@@ -462,7 +499,25 @@ impl TestBuilder {
         Ok(())
     }
 
-    // 9. Take all VaultOperatorDelegation snapshots
+    // 9. Create all vault snapshots
+    pub async fn add_vault_snapshots_to_test_ncn(&mut self, test_ncn: &TestNcn) -> TestResult<()> {
+        let mut tip_router_client = self.tip_router_client();
+
+        let slot = self.clock().await.slot;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        for vault_root in test_ncn.vaults.iter() {
+            let vault = vault_root.vault_pubkey;
+
+            tip_router_client
+                .do_initialize_vault_snapshot(vault, ncn, slot)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    // 10. Take all VaultOperatorDelegation snapshots
     pub async fn add_vault_operator_delegation_snapshots_to_test_ncn(
         &mut self,
         test_ncn: &TestNcn,
@@ -491,6 +546,7 @@ impl TestBuilder {
         self.add_weights_for_test_ncn(&test_ncn).await?;
         self.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
         self.add_operator_snapshots_to_test_ncn(&test_ncn).await?;
+        self.add_vault_snapshots_to_test_ncn(&test_ncn).await?;
         self.add_vault_operator_delegation_snapshots_to_test_ncn(&test_ncn)
             .await?;
 
@@ -5,20 +5,27 @@ use jito_restaking_core::{
 use jito_tip_distribution_sdk::{derive_tip_distribution_account_address, jito_tip_distribution};
 use jito_tip_router_client::{
     instructions::{
-        AdminUpdateWeightTableBuilder, CastVoteBuilder, InitializeBallotBoxBuilder,
-        InitializeEpochSnapshotBuilder, InitializeNCNConfigBuilder,
-        InitializeOperatorSnapshotBuilder, InitializeTrackedMintsBuilder,
-        InitializeWeightTableBuilder, RegisterMintBuilder, SetConfigFeesBuilder,
-        SetMerkleRootBuilder, SetNewAdminBuilder, SetTieBreakerBuilder,
-        SnapshotVaultOperatorDelegationBuilder,
+        AdminUpdateWeightTableBuilder, CastVoteBuilder, CloseBallotBoxBuilder,
+        CloseEpochRewardRouterBuilder, CloseEpochSnapshotBuilder, CloseOperatorSnapshotBuilder,
+        CloseVaultSnapshotBuilder, CloseWeightTableBuilder, DeregisterMintBuilder,
+        DistributeOperatorRewardsBuilder, InitializeBallotBoxBuilder,
+        InitializeEpochRewardRouterBuilder, InitializeEpochSnapshotBuilder,
+        InitializeNCNConfigBuilder, InitializeOperatorSnapshotBuilder,
+        InitializeTrackedMintsBuilder, InitializeVaultSnapshotBuilder,
+        InitializeWeightTableBuilder, MigrateNcnConfigBuilder, ReallocTrackedMintsBuilder,
+        RegisterMintBuilder, RouteNcnRewardsBuilder, SetConfigFeesBuilder, SetMerkleRootBuilder,
+        SetNewAdminBuilder, SetParametersBuilder, SetTieBreakerBuilder,
+        SnapshotVaultOperatorDelegationBuilder, SnapshotVaultOperatorDelegationsBuilder,
     },
     types::ConfigAdminRole,
 };
 use jito_tip_router_core::{
     ballot_box::BallotBox,
-    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_reward_router::EpochRewardRouter,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot, VaultSnapshot},
     error::TipRouterError,
     ncn_config::NcnConfig,
+    ncn_epoch::NcnEpochSchedule,
     tracked_mints::TrackedMints,
     weight_table::WeightTable,
 };
@@ -26,13 +33,17 @@ use jito_vault_core::{
     vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
 };
 use solana_program::{
-    instruction::InstructionError, native_token::sol_to_lamports, pubkey::Pubkey,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    native_token::sol_to_lamports,
+    pubkey::Pubkey,
     system_instruction::transfer,
 };
 use solana_program_test::{BanksClient, ProgramTestBanksClientExt};
 use solana_sdk::{
     clock::Clock,
     commitment_config::CommitmentLevel,
+    compute_budget::ComputeBudgetInstruction,
+    epoch_schedule::EpochSchedule,
     signature::{Keypair, Signer},
     system_program,
     transaction::{Transaction, TransactionError},
@@ -64,6 +75,17 @@ impl TipRouterClient {
         Ok(())
     }
 
+    pub async fn process_instructions(&mut self, ixs: &[Instruction]) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            ixs,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn airdrop(&mut self, to: &Pubkey, sol: f64) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         let new_blockhash = self
@@ -103,6 +125,12 @@ impl TipRouterClient {
         Ok(*Config::try_from_slice_unchecked(restaking_config_data.data.as_slice()).unwrap())
     }
 
+    pub async fn get_ncn_epoch_schedule(&mut self) -> TestResult<NcnEpochSchedule> {
+        let epoch_length = self.get_restaking_config().await?.epoch_length();
+        let epoch_schedule: EpochSchedule = self.banks_client.get_sysvar().await?;
+        Ok(NcnEpochSchedule::new(epoch_length, epoch_schedule).unwrap())
+    }
+
     pub async fn get_ncn_config(&mut self, ncn_pubkey: Pubkey) -> TestResult<NcnConfig> {
         let config_pda =
             NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_pubkey).0;
@@ -175,6 +203,47 @@ impl TipRouterClient {
         Ok(*account)
     }
 
+    pub async fn get_vault_snapshot(
+        &mut self,
+        vault: Pubkey,
+        ncn: Pubkey,
+        ncn_epoch: u64,
+    ) -> TestResult<VaultSnapshot> {
+        let address = VaultSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &vault,
+            &ncn,
+            ncn_epoch,
+        )
+        .0;
+
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+
+        let account = VaultSnapshot::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap();
+
+        Ok(*account)
+    }
+
+    pub async fn get_epoch_reward_router(
+        &mut self,
+        ncn: Pubkey,
+        ncn_epoch: u64,
+    ) -> TestResult<EpochRewardRouter> {
+        let address = EpochRewardRouter::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            ncn_epoch,
+        )
+        .0;
+
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+
+        let account =
+            EpochRewardRouter::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap();
+
+        Ok(*account)
+    }
+
     pub async fn get_ballot_box(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<BallotBox> {
         let address =
             BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
@@ -190,15 +259,25 @@ impl TipRouterClient {
         self.airdrop(&self.payer.pubkey(), 1.0).await?;
 
         let ncn_admin_pubkey = ncn_admin.pubkey();
-        self.initialize_config(ncn, ncn_admin, ncn_admin_pubkey, ncn_admin_pubkey, 0, 0, 0)
-            .await
+        self.initialize_config(
+            ncn,
+            ncn_admin,
+            ncn_admin_pubkey,
+            ncn_admin_pubkey,
+            ncn_admin_pubkey,
+            0,
+            0,
+            0,
+        )
+        .await
     }
 
     pub async fn initialize_config(
         &mut self,
         ncn: Pubkey,
         ncn_admin: &Keypair,
-        fee_wallet: Pubkey,
+        dao_fee_wallet: Pubkey,
+        ncn_fee_wallet: Pubkey,
         tie_breaker_admin: Pubkey,
         dao_fee_bps: u64,
         ncn_fee_bps: u64,
@@ -212,7 +291,8 @@ impl TipRouterClient {
             .ncn_config(ncn_config)
             .ncn(ncn)
             .ncn_admin(ncn_admin.pubkey())
-            .fee_wallet(fee_wallet)
+            .dao_fee_wallet(dao_fee_wallet)
+            .ncn_fee_wallet(ncn_fee_wallet)
             .tie_breaker_admin(tie_breaker_admin)
             .restaking_program(jito_restaking_program::id())
             .dao_fee_bps(dao_fee_bps)
@@ -235,7 +315,8 @@ impl TipRouterClient {
         dao_fee_bps: u64,
         ncn_fee_bps: u64,
         block_engine_fee_bps: u64,
-        fee_wallet: Pubkey,
+        dao_fee_wallet: Pubkey,
+        ncn_fee_wallet: Pubkey,
         ncn_root: &NcnRoot,
     ) -> TestResult<()> {
         let config_pda =
@@ -246,7 +327,8 @@ impl TipRouterClient {
             dao_fee_bps,
             ncn_fee_bps,
             block_engine_fee_bps,
-            fee_wallet,
+            dao_fee_wallet,
+            ncn_fee_wallet,
             &ncn_root,
         )
         .await
@@ -258,7 +340,8 @@ impl TipRouterClient {
         dao_fee_bps: u64,
         ncn_fee_bps: u64,
         block_engine_fee_bps: u64,
-        fee_wallet: Pubkey,
+        dao_fee_wallet: Pubkey,
+        ncn_fee_wallet: Pubkey,
         ncn_root: &NcnRoot,
     ) -> TestResult<()> {
         let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
@@ -272,7 +355,8 @@ impl TipRouterClient {
             .new_dao_fee_bps(dao_fee_bps)
             .new_ncn_fee_bps(ncn_fee_bps)
             .new_block_engine_fee_bps(block_engine_fee_bps)
-            .new_fee_wallet(fee_wallet)
+            .new_dao_fee_wallet(dao_fee_wallet)
+            .new_ncn_fee_wallet(ncn_fee_wallet)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -324,6 +408,69 @@ impl TipRouterClient {
         .await
     }
 
+    pub async fn do_set_parameters(
+        &mut self,
+        epochs_before_close: Option<u64>,
+        snapshot_window_slots: Option<u64>,
+        snapshot_grace_epochs: Option<u64>,
+        fee_activation_delay_epochs: Option<u64>,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+        self.airdrop(&ncn_root.ncn_admin.pubkey(), 1.0).await?;
+        self.set_parameters(
+            config_pda,
+            epochs_before_close,
+            snapshot_window_slots,
+            snapshot_grace_epochs,
+            fee_activation_delay_epochs,
+            ncn_root,
+        )
+        .await
+    }
+
+    pub async fn set_parameters(
+        &mut self,
+        config_pda: Pubkey,
+        epochs_before_close: Option<u64>,
+        snapshot_window_slots: Option<u64>,
+        snapshot_grace_epochs: Option<u64>,
+        fee_activation_delay_epochs: Option<u64>,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let mut ix = SetParametersBuilder::new();
+        ix.config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .restaking_program(jito_restaking_program::id());
+
+        if let Some(epochs_before_close) = epochs_before_close {
+            ix.epochs_before_close(epochs_before_close);
+        }
+
+        if let Some(snapshot_window_slots) = snapshot_window_slots {
+            ix.snapshot_window_slots(snapshot_window_slots);
+        }
+
+        if let Some(snapshot_grace_epochs) = snapshot_grace_epochs {
+            ix.snapshot_grace_epochs(snapshot_grace_epochs);
+        }
+
+        if let Some(fee_activation_delay_epochs) = fee_activation_delay_epochs {
+            ix.fee_activation_delay_epochs(fee_activation_delay_epochs);
+        }
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix.instruction()],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_initialize_weight_table(
         &mut self,
         ncn: Pubkey,
@@ -506,6 +653,125 @@ impl TipRouterClient {
         .await
     }
 
+    pub async fn do_deregister_mint(
+        &mut self,
+        ncn: Pubkey,
+        vault: Pubkey,
+        vault_ncn_ticket: Pubkey,
+        ncn_vault_ticket: Pubkey,
+    ) -> TestResult<()> {
+        let restaking_config_address =
+            Config::find_program_address(&jito_restaking_program::id()).0;
+        let tracked_mints =
+            TrackedMints::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let restaking_config = self.get_restaking_config().await?;
+        let current_slot = self.banks_client.get_sysvar::<Clock>().await?.slot;
+        let ncn_epoch = current_slot / restaking_config.epoch_length();
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, ncn_epoch).0;
+
+        self.deregister_mint(
+            restaking_config_address,
+            tracked_mints,
+            ncn,
+            weight_table,
+            vault,
+            vault_ncn_ticket,
+            ncn_vault_ticket,
+        )
+        .await
+    }
+
+    pub async fn deregister_mint(
+        &mut self,
+        restaking_config: Pubkey,
+        tracked_mints: Pubkey,
+        ncn: Pubkey,
+        weight_table: Pubkey,
+        vault: Pubkey,
+        vault_ncn_ticket: Pubkey,
+        ncn_vault_ticket: Pubkey,
+    ) -> TestResult<()> {
+        let ix = DeregisterMintBuilder::new()
+            .restaking_config(restaking_config)
+            .tracked_mints(tracked_mints)
+            .ncn(ncn)
+            .weight_table(weight_table)
+            .vault(vault)
+            .vault_ncn_ticket(vault_ncn_ticket)
+            .ncn_vault_ticket(ncn_vault_ticket)
+            .restaking_program_id(jito_restaking_program::id())
+            .vault_program_id(jito_vault_program::id())
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_realloc_tracked_mints(&mut self, ncn: Pubkey) -> TestResult<()> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let tracked_mints =
+            TrackedMints::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        self.realloc_tracked_mints(ncn_config, tracked_mints, ncn)
+            .await
+    }
+
+    pub async fn realloc_tracked_mints(
+        &mut self,
+        ncn_config: Pubkey,
+        tracked_mints: Pubkey,
+        ncn: Pubkey,
+    ) -> TestResult<()> {
+        let ix = ReallocTrackedMintsBuilder::new()
+            .ncn_config(ncn_config)
+            .tracked_mints(tracked_mints)
+            .ncn(ncn)
+            .payer(self.payer.pubkey())
+            .system_program(system_program::id())
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_migrate_ncn_config(&mut self, ncn: Pubkey) -> TestResult<()> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        self.migrate_ncn_config(ncn_config, ncn).await
+    }
+
+    pub async fn migrate_ncn_config(&mut self, ncn_config: Pubkey, ncn: Pubkey) -> TestResult<()> {
+        let ix = MigrateNcnConfigBuilder::new()
+            .ncn_config(ncn_config)
+            .ncn(ncn)
+            .payer(self.payer.pubkey())
+            .system_program(system_program::id())
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_initialize_epoch_snapshot(&mut self, ncn: Pubkey, slot: u64) -> TestResult<()> {
         self.initialize_epoch_snapshot(ncn, slot).await
     }
@@ -609,21 +875,18 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_snapshot_vault_operator_delegation(
+    pub async fn do_initialize_vault_snapshot(
         &mut self,
         vault: Pubkey,
-        operator: Pubkey,
         ncn: Pubkey,
         slot: u64,
     ) -> TestResult<()> {
-        self.snapshot_vault_operator_delegation(vault, operator, ncn, slot)
-            .await
+        self.initialize_vault_snapshot(vault, ncn, slot).await
     }
 
-    pub async fn snapshot_vault_operator_delegation(
+    pub async fn initialize_vault_snapshot(
         &mut self,
         vault: Pubkey,
-        operator: Pubkey,
         ncn: Pubkey,
         slot: u64,
     ) -> TestResult<()> {
@@ -637,44 +900,25 @@ impl TipRouterClient {
         let epoch_snapshot =
             EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, ncn_epoch).0;
 
-        let operator_snapshot = OperatorSnapshot::find_program_address(
+        let vault_snapshot = VaultSnapshot::find_program_address(
             &jito_tip_router_program::id(),
-            &operator,
+            &vault,
             &ncn,
             ncn_epoch,
         )
         .0;
 
-        let vault_ncn_ticket =
-            VaultNcnTicket::find_program_address(&jito_vault_program::id(), &vault, &ncn).0;
-
-        let ncn_vault_ticket =
-            NcnVaultTicket::find_program_address(&jito_restaking_program::id(), &ncn, &vault).0;
-
-        let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
-            &jito_vault_program::id(),
-            &vault,
-            &operator,
-        )
-        .0;
-
-        let weight_table =
-            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, ncn_epoch).0;
-
-        let ix = SnapshotVaultOperatorDelegationBuilder::new()
+        let ix = InitializeVaultSnapshotBuilder::new()
             .ncn_config(config_pda)
             .restaking_config(restaking_config)
             .ncn(ncn)
-            .operator(operator)
             .vault(vault)
-            .vault_ncn_ticket(vault_ncn_ticket)
-            .ncn_vault_ticket(ncn_vault_ticket)
-            .vault_operator_delegation(vault_operator_delegation)
-            .weight_table(weight_table)
             .epoch_snapshot(epoch_snapshot)
-            .operator_snapshot(operator_snapshot)
+            .vault_snapshot(vault_snapshot)
+            .payer(self.payer.pubkey())
             .vault_program(jito_vault_program::id())
             .restaking_program(jito_restaking_program::id())
+            .system_program(system_program::id())
             .first_slot_of_ncn_epoch(slot)
             .instruction();
 
@@ -688,36 +932,282 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_initialize_ballot_box(
+    pub async fn do_snapshot_vault_operator_delegation(
         &mut self,
+        vault: Pubkey,
+        operator: Pubkey,
         ncn: Pubkey,
-        ncn_epoch: u64,
-    ) -> Result<(), TestError> {
-        let ncn_config = jito_tip_router_core::ncn_config::NcnConfig::find_program_address(
-            &jito_tip_router_program::id(),
-            &ncn,
-        )
-        .0;
-
-        let ballot_box = jito_tip_router_core::ballot_box::BallotBox::find_program_address(
-            &jito_tip_router_program::id(),
-            &ncn,
-            ncn_epoch,
-        )
-        .0;
-
-        self.initialize_ballot_box(ncn_config, ballot_box, ncn, ncn_epoch)
+        slot: u64,
+    ) -> TestResult<()> {
+        self.snapshot_vault_operator_delegation(vault, operator, ncn, slot)
             .await
     }
 
-    pub async fn initialize_ballot_box(
+    pub async fn snapshot_vault_operator_delegation(
         &mut self,
-        ncn_config: Pubkey,
-        ballot_box: Pubkey,
+        vault: Pubkey,
+        operator: Pubkey,
         ncn: Pubkey,
-        epoch: u64,
-    ) -> Result<(), TestError> {
-        let ix = InitializeBallotBoxBuilder::new()
+        slot: u64,
+    ) -> TestResult<()> {
+        let ix = self
+            .snapshot_vault_operator_delegation_ix(vault, operator, ncn, slot)
+            .await?;
+
+        self.process_instructions(&[ix]).await
+    }
+
+    pub async fn snapshot_vault_operator_delegation_ix(
+        &mut self,
+        vault: Pubkey,
+        operator: Pubkey,
+        ncn: Pubkey,
+        slot: u64,
+    ) -> TestResult<Instruction> {
+        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        let restaking_config_account = self.get_restaking_config().await?;
+        let ncn_epoch = slot / restaking_config_account.epoch_length();
+
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let epoch_snapshot =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, ncn_epoch).0;
+
+        let operator_snapshot = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            ncn_epoch,
+        )
+        .0;
+
+        let vault_ncn_ticket =
+            VaultNcnTicket::find_program_address(&jito_vault_program::id(), &vault, &ncn).0;
+
+        let ncn_vault_ticket =
+            NcnVaultTicket::find_program_address(&jito_restaking_program::id(), &ncn, &vault).0;
+
+        let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+            &jito_vault_program::id(),
+            &vault,
+            &operator,
+        )
+        .0;
+
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, ncn_epoch).0;
+
+        let vault_snapshot = VaultSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &vault,
+            &ncn,
+            ncn_epoch,
+        )
+        .0;
+
+        let ix = SnapshotVaultOperatorDelegationBuilder::new()
+            .ncn_config(config_pda)
+            .restaking_config(restaking_config)
+            .ncn(ncn)
+            .operator(operator)
+            .vault(vault)
+            .vault_ncn_ticket(vault_ncn_ticket)
+            .ncn_vault_ticket(ncn_vault_ticket)
+            .vault_operator_delegation(vault_operator_delegation)
+            .weight_table(weight_table)
+            .epoch_snapshot(epoch_snapshot)
+            .operator_snapshot(operator_snapshot)
+            .vault_snapshot(vault_snapshot)
+            .vault_program(jito_vault_program::id())
+            .restaking_program(jito_restaking_program::id())
+            .first_slot_of_ncn_epoch(slot)
+            .instruction();
+
+        Ok(ix)
+    }
+
+    pub async fn do_snapshot_vault_operator_delegations(
+        &mut self,
+        vaults: &[Pubkey],
+        operator: Pubkey,
+        ncn: Pubkey,
+        slot: u64,
+    ) -> TestResult<()> {
+        self.snapshot_vault_operator_delegations(vaults, operator, ncn, slot, None)
+            .await
+    }
+
+    pub async fn snapshot_vault_operator_delegations(
+        &mut self,
+        vaults: &[Pubkey],
+        operator: Pubkey,
+        ncn: Pubkey,
+        slot: u64,
+        compute_unit_limit: Option<u32>,
+    ) -> TestResult<()> {
+        let ix = self
+            .snapshot_vault_operator_delegations_ix(vaults, operator, ncn, slot)
+            .await?;
+
+        let mut ixs = vec![];
+        if let Some(compute_unit_limit) = compute_unit_limit {
+            ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(
+                compute_unit_limit,
+            ));
+        }
+        ixs.push(ix);
+
+        self.process_instructions(&ixs).await
+    }
+
+    /// Compute units the batch consumes, without processing it
+    pub async fn simulate_snapshot_vault_operator_delegations(
+        &mut self,
+        vaults: &[Pubkey],
+        operator: Pubkey,
+        ncn: Pubkey,
+        slot: u64,
+    ) -> TestResult<u64> {
+        let ix = self
+            .snapshot_vault_operator_delegations_ix(vaults, operator, ncn, slot)
+            .await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let simulation = self
+            .banks_client
+            .simulate_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&self.payer.pubkey()),
+                &[&self.payer],
+                blockhash,
+            ))
+            .await?;
+
+        Ok(simulation.simulation_details.unwrap().units_consumed)
+    }
+
+    pub async fn snapshot_vault_operator_delegations_ix(
+        &mut self,
+        vaults: &[Pubkey],
+        operator: Pubkey,
+        ncn: Pubkey,
+        slot: u64,
+    ) -> TestResult<Instruction> {
+        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        let restaking_config_account = self.get_restaking_config().await?;
+        let ncn_epoch = slot / restaking_config_account.epoch_length();
+
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let epoch_snapshot =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, ncn_epoch).0;
+
+        let operator_snapshot = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            ncn_epoch,
+        )
+        .0;
+
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, ncn_epoch).0;
+
+        let vault_accounts: Vec<AccountMeta> = vaults
+            .iter()
+            .flat_map(|vault| {
+                [
+                    AccountMeta::new_readonly(*vault, false),
+                    AccountMeta::new_readonly(
+                        VaultNcnTicket::find_program_address(
+                            &jito_vault_program::id(),
+                            vault,
+                            &ncn,
+                        )
+                        .0,
+                        false,
+                    ),
+                    AccountMeta::new_readonly(
+                        NcnVaultTicket::find_program_address(
+                            &jito_restaking_program::id(),
+                            &ncn,
+                            vault,
+                        )
+                        .0,
+                        false,
+                    ),
+                    AccountMeta::new_readonly(
+                        VaultOperatorDelegation::find_program_address(
+                            &jito_vault_program::id(),
+                            vault,
+                            &operator,
+                        )
+                        .0,
+                        false,
+                    ),
+                    AccountMeta::new(
+                        VaultSnapshot::find_program_address(
+                            &jito_tip_router_program::id(),
+                            vault,
+                            &ncn,
+                            ncn_epoch,
+                        )
+                        .0,
+                        false,
+                    ),
+                ]
+            })
+            .collect();
+
+        let ix = SnapshotVaultOperatorDelegationsBuilder::new()
+            .ncn_config(config_pda)
+            .restaking_config(restaking_config)
+            .ncn(ncn)
+            .operator(operator)
+            .weight_table(weight_table)
+            .epoch_snapshot(epoch_snapshot)
+            .operator_snapshot(operator_snapshot)
+            .vault_program(jito_vault_program::id())
+            .restaking_program(jito_restaking_program::id())
+            .first_slot_of_ncn_epoch(slot)
+            .add_remaining_accounts(&vault_accounts)
+            .instruction();
+
+        Ok(ix)
+    }
+
+    pub async fn do_initialize_ballot_box(
+        &mut self,
+        ncn: Pubkey,
+        ncn_epoch: u64,
+    ) -> Result<(), TestError> {
+        let ncn_config = jito_tip_router_core::ncn_config::NcnConfig::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+        )
+        .0;
+
+        let ballot_box = jito_tip_router_core::ballot_box::BallotBox::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            ncn_epoch,
+        )
+        .0;
+
+        self.initialize_ballot_box(ncn_config, ballot_box, ncn, ncn_epoch)
+            .await
+    }
+
+    pub async fn initialize_ballot_box(
+        &mut self,
+        ncn_config: Pubkey,
+        ballot_box: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let ix = InitializeBallotBoxBuilder::new()
             .ncn_config(ncn_config)
             .ballot_box(ballot_box)
             .ncn(ncn)
@@ -840,11 +1330,17 @@ impl TipRouterClient {
         let ballot_box =
             BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
+        let tip_distribution_epoch = self
+            .get_ncn_epoch_schedule()
+            .await?
+            .solana_epoch_of_ncn_epoch(epoch)
+            .unwrap();
+
         let tip_distribution_program_id = jito_tip_distribution::ID;
         let tip_distribution_account = derive_tip_distribution_account_address(
             &tip_distribution_program_id,
             &vote_account,
-            epoch,
+            tip_distribution_epoch,
         )
         .0;
 
@@ -867,6 +1363,7 @@ impl TipRouterClient {
             max_total_claim,
             max_num_nodes,
             epoch,
+            tip_distribution_epoch,
         )
         .await
     }
@@ -886,9 +1383,13 @@ impl TipRouterClient {
         max_total_claim: u64,
         max_num_nodes: u64,
         epoch: u64,
+        tip_distribution_epoch: u64,
     ) -> Result<(), TestError> {
+        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
+
         let ix = SetMerkleRootBuilder::new()
             .ncn_config(ncn_config)
+            .restaking_config(restaking_config)
             .ncn(ncn)
             .ballot_box(ballot_box)
             .vote_account(vote_account)
@@ -901,6 +1402,7 @@ impl TipRouterClient {
             .max_total_claim(max_total_claim)
             .max_num_nodes(max_num_nodes)
             .epoch(epoch)
+            .tip_distribution_epoch(tip_distribution_epoch)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -952,8 +1454,11 @@ impl TipRouterClient {
         epoch: u64,
         restaking_program_id: Pubkey,
     ) -> Result<(), TestError> {
+        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
+
         let ix = SetTieBreakerBuilder::new()
             .ncn_config(ncn_config)
+            .restaking_config(restaking_config)
             .ballot_box(ballot_box)
             .ncn(ncn)
             .tie_breaker_admin(tie_breaker_admin)
@@ -971,6 +1476,384 @@ impl TipRouterClient {
         ))
         .await
     }
+
+    pub async fn do_close_weight_table(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        self.close_weight_table(ncn, epoch, self.payer.pubkey())
+            .await
+    }
+
+    pub async fn close_weight_table(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        payer: Pubkey,
+    ) -> TestResult<()> {
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let ix = CloseWeightTableBuilder::new()
+            .ncn_config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .restaking_config(Config::find_program_address(&jito_restaking_program::id()).0)
+            .ncn(ncn)
+            .ballot_box(
+                BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .epoch_reward_router(
+                EpochRewardRouter::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .weight_table(weight_table)
+            .payer(payer)
+            .restaking_program(jito_restaking_program::id())
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_close_epoch_snapshot(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let epoch_snapshot =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let ix = CloseEpochSnapshotBuilder::new()
+            .ncn_config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .restaking_config(Config::find_program_address(&jito_restaking_program::id()).0)
+            .ncn(ncn)
+            .ballot_box(
+                BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .epoch_reward_router(
+                EpochRewardRouter::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .epoch_snapshot(epoch_snapshot)
+            .payer(self.payer.pubkey())
+            .restaking_program(jito_restaking_program::id())
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_close_operator_snapshot(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let operator_snapshot = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        )
+        .0;
+
+        let ix = CloseOperatorSnapshotBuilder::new()
+            .ncn_config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .restaking_config(Config::find_program_address(&jito_restaking_program::id()).0)
+            .ncn(ncn)
+            .ballot_box(
+                BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .epoch_reward_router(
+                EpochRewardRouter::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .operator(operator)
+            .operator_snapshot(operator_snapshot)
+            .payer(self.payer.pubkey())
+            .restaking_program(jito_restaking_program::id())
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_initialize_epoch_reward_router(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let epoch_reward_router =
+            EpochRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let ix = InitializeEpochRewardRouterBuilder::new()
+            .ncn_config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .ncn(ncn)
+            .epoch_reward_router(epoch_reward_router)
+            .payer(self.payer.pubkey())
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_route_ncn_rewards(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let fee = *self.get_epoch_snapshot(ncn, epoch).await?.fee();
+
+        self.route_ncn_rewards(ncn, epoch, fee.dao_fee_wallet(), fee.ncn_fee_wallet())
+            .await
+    }
+
+    pub async fn route_ncn_rewards(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        dao_fee_wallet: Pubkey,
+        ncn_fee_wallet: Pubkey,
+    ) -> TestResult<()> {
+        let ix = RouteNcnRewardsBuilder::new()
+            .ncn_config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .ncn(ncn)
+            .epoch_snapshot(
+                EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .epoch_reward_router(
+                EpochRewardRouter::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .dao_fee_wallet(dao_fee_wallet)
+            .ncn_fee_wallet(ncn_fee_wallet)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_distribute_operator_rewards(
+        &mut self,
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let operator_snapshot = self.get_operator_snapshot(operator, ncn, epoch).await?;
+
+        let mut vault_accounts = Vec::new();
+        for vault_stake_weight in operator_snapshot
+            .vault_operator_stake_weights()
+            .iter()
+            .filter(|vault| vault.stake_weight() > 0)
+        {
+            let vault = vault_stake_weight.vault();
+            let vault_snapshot = self.get_vault_snapshot(vault, ncn, epoch).await?;
+
+            vault_accounts.push(AccountMeta::new_readonly(
+                VaultSnapshot::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &vault,
+                    &ncn,
+                    epoch,
+                )
+                .0,
+                false,
+            ));
+            vault_accounts.push(AccountMeta::new(vault_snapshot.fee_wallet(), false));
+        }
+
+        let ix = DistributeOperatorRewardsBuilder::new()
+            .ncn_config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_fee_wallet(operator_snapshot.operator_fee_wallet())
+            .epoch_snapshot(
+                EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .operator_snapshot(
+                OperatorSnapshot::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &operator,
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .epoch_reward_router(
+                EpochRewardRouter::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .restaking_program(jito_restaking_program::id())
+            .epoch(epoch)
+            .add_remaining_accounts(&vault_accounts)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_close_vault_snapshot(
+        &mut self,
+        ncn: Pubkey,
+        vault: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let vault_snapshot = VaultSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &vault,
+            &ncn,
+            epoch,
+        )
+        .0;
+
+        let ix = CloseVaultSnapshotBuilder::new()
+            .ncn_config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .restaking_config(Config::find_program_address(&jito_restaking_program::id()).0)
+            .ncn(ncn)
+            .ballot_box(
+                BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .epoch_reward_router(
+                EpochRewardRouter::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .vault(vault)
+            .vault_snapshot(vault_snapshot)
+            .payer(self.payer.pubkey())
+            .restaking_program(jito_restaking_program::id())
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_close_ballot_box(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let ix = CloseBallotBoxBuilder::new()
+            .ncn_config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .restaking_config(Config::find_program_address(&jito_restaking_program::id()).0)
+            .ncn(ncn)
+            .ballot_box(ballot_box)
+            .epoch_reward_router(
+                EpochRewardRouter::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .payer(self.payer.pubkey())
+            .restaking_program(jito_restaking_program::id())
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_close_epoch_reward_router(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let ix = CloseEpochRewardRouterBuilder::new()
+            .ncn_config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .restaking_config(Config::find_program_address(&jito_restaking_program::id()).0)
+            .ncn(ncn)
+            .ballot_box(
+                BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .epoch_reward_router(
+                EpochRewardRouter::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .payer(self.payer.pubkey())
+            .restaking_program(jito_restaking_program::id())
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
 }
 
 #[inline(always)]
@@ -105,14 +105,17 @@ mod set_merkle_root {
                 total_tips: 50,
                 validator_fee_bps: 0,
             }),
-            delegations: vec![test_delegation],
+            delegations: vec![Delegation {
+                stake_account_pubkey: Pubkey::new_unique(),
+                ..test_delegation
+            }],
             total_delegated: 50,
             commission: 0,
         };
 
         let stake_meta_collection = StakeMetaCollection {
             stake_metas: vec![vote_account_stake_meta, other_stake_meta],
-            tip_distribution_program_id: Pubkey::new_unique(),
+            tip_distribution_program_id: jito_tip_distribution::ID,
             bank_hash: String::default(),
             epoch,
             slot: 0,
@@ -199,7 +202,7 @@ mod set_merkle_root {
             BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn_address, epoch);
 
         let ballot_box_fixture = {
-            let mut ballot_box = BallotBox::new(ncn_address, epoch, bump, 0);
+            let mut ballot_box = BallotBox::new(ncn_address, epoch, bump, 0, Pubkey::default());
             let winning_ballot = Ballot::new(winning_root);
             ballot_box.set_winning_ballot(winning_ballot);
             ballot_box
@@ -229,8 +232,12 @@ mod set_merkle_root {
         // Get proof for vote_account
         let node = meta_merkle_tree_fixture
             .meta_merkle_tree
-            .get_node(&tip_distribution_address);
-        let proof = node.proof.clone().unwrap();
+            .try_get_node(&tip_distribution_address)?
+            .clone();
+        let proof = meta_merkle_tree_fixture
+            .meta_merkle_tree
+            .proof_for(&tip_distribution_address)?
+            .to_vec();
 
         ballot_box_fixture
             .verify_merkle_root(
@@ -242,6 +249,30 @@ mod set_merkle_root {
             )
             .unwrap();
 
+        // The tip distribution account must be from a Solana epoch within the NCN epoch
+        let res = tip_router_client
+            .set_merkle_root(
+                ncn_config_address,
+                ncn_address,
+                ballot_box_address,
+                vote_account,
+                tip_distribution_address,
+                jito_tip_distribution_sdk::derive_config_account_address(
+                    &jito_tip_distribution::ID,
+                )
+                .0,
+                jito_tip_distribution::ID,
+                jito_restaking_program::id(),
+                proof.clone(),
+                node.validator_merkle_root,
+                node.max_total_claim,
+                node.max_num_nodes,
+                epoch,
+                epoch + 1_000,
+            )
+            .await;
+        assert_tip_router_error(res, TipRouterError::InvalidTipDistributionEpoch);
+
         // Test wrong proof
         let res = tip_router_client
             .do_set_merkle_root(
@@ -342,8 +373,12 @@ mod set_merkle_root {
         // Get proof for vote_account
         let node = meta_merkle_tree_fixture
             .meta_merkle_tree
-            .get_node(&tip_distribution_address);
-        let proof = node.proof.clone().unwrap();
+            .try_get_node(&tip_distribution_address)?
+            .clone();
+        let proof = meta_merkle_tree_fixture
+            .meta_merkle_tree
+            .proof_for(&tip_distribution_address)?
+            .to_vec();
 
         let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
 
@@ -426,8 +461,12 @@ mod set_merkle_root {
         .0;
         let node = meta_merkle_tree_fixture
             .meta_merkle_tree
-            .get_node(&tip_distribution_address);
-        let proof = node.proof.clone().unwrap();
+            .try_get_node(&tip_distribution_address)?
+            .clone();
+        let proof = meta_merkle_tree_fixture
+            .meta_merkle_tree
+            .proof_for(&tip_distribution_address)?
+            .to_vec();
 
         // Initialize ballot box
         tip_router_client
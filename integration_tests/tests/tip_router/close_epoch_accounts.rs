@@ -0,0 +1,269 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::{
+        ballot_box::BallotBox,
+        epoch_reward_router::EpochRewardRouter,
+        epoch_snapshot::{EpochSnapshot, OperatorSnapshot, VaultSnapshot},
+        error::TipRouterError,
+        weight_table::WeightTable,
+    };
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_close_epoch_accounts() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+
+        let epoch_length = tip_router_client
+            .get_restaking_config()
+            .await?
+            .epoch_length();
+        let ncn_epoch = fixture.clock().await.slot / epoch_length;
+
+        tip_router_client
+            .do_initialize_ballot_box(ncn, ncn_epoch)
+            .await?;
+
+        // Accounts are retained from the epoch once consensus is reached
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+        tip_router_client
+            .do_cast_vote(ncn, operator, operator_admin, [1u8; 32], ncn_epoch)
+            .await?;
+        assert!(tip_router_client
+            .get_ballot_box(ncn, ncn_epoch)
+            .await?
+            .is_consensus_reached());
+
+        tip_router_client
+            .do_set_parameters(Some(2), None, None, None, &test_ncn.ncn_root)
+            .await?;
+
+        // Accounts cannot be closed before the retention period has passed
+        let result = tip_router_client
+            .do_close_weight_table(ncn, ncn_epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::CannotCloseAccount);
+
+        fixture.warp_slot_incremental(2 * epoch_length).await?;
+
+        // Rent can only be returned to the original payer
+        let result = tip_router_client
+            .close_weight_table(ncn, ncn_epoch, Pubkey::new_unique())
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectPayer);
+
+        let payer = fixture.payer();
+        let payer_lamports_before = fixture.get_account(&payer).await?.unwrap().lamports;
+
+        tip_router_client
+            .do_close_weight_table(ncn, ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_close_epoch_snapshot(ncn, ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_close_operator_snapshot(ncn, operator, ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_close_vault_snapshot(ncn, vault, ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_close_ballot_box(ncn, ncn_epoch)
+            .await?;
+
+        let program_id = jito_tip_router_program::id();
+        let closed_accounts = [
+            WeightTable::find_program_address(&program_id, &ncn, ncn_epoch).0,
+            EpochSnapshot::find_program_address(&program_id, &ncn, ncn_epoch).0,
+            OperatorSnapshot::find_program_address(&program_id, &operator, &ncn, ncn_epoch).0,
+            VaultSnapshot::find_program_address(&program_id, &vault, &ncn, ncn_epoch).0,
+            BallotBox::find_program_address(&program_id, &ncn, ncn_epoch).0,
+        ];
+        for account in closed_accounts.iter() {
+            assert!(fixture.get_account(account).await?.is_none());
+        }
+
+        let payer_lamports_after = fixture.get_account(&payer).await?.unwrap().lamports;
+        assert!(payer_lamports_after > payer_lamports_before);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_close_epoch_accounts_with_pending_rewards() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+
+        let epoch_length = tip_router_client
+            .get_restaking_config()
+            .await?
+            .epoch_length();
+        let ncn_epoch = fixture.clock().await.slot / epoch_length;
+
+        tip_router_client
+            .do_initialize_ballot_box(ncn, ncn_epoch)
+            .await?;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+        tip_router_client
+            .do_cast_vote(ncn, operator, operator_admin, [1u8; 32], ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_set_parameters(Some(2), None, None, None, &test_ncn.ncn_root)
+            .await?;
+
+        tip_router_client
+            .do_initialize_epoch_reward_router(ncn, ncn_epoch)
+            .await?;
+        let program_id = jito_tip_router_program::id();
+        let epoch_reward_router =
+            EpochRewardRouter::find_program_address(&program_id, &ncn, ncn_epoch).0;
+        tip_router_client.airdrop(&epoch_reward_router, 1.0).await?;
+
+        fixture.warp_slot_incremental(2 * epoch_length).await?;
+
+        // Rewards that were never routed keep the accounts of the epoch open
+        let result = tip_router_client
+            .do_close_weight_table(ncn, ncn_epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::RewardsNotDistributed);
+        let result = tip_router_client
+            .do_close_epoch_reward_router(ncn, ncn_epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::RewardsNotDistributed);
+
+        // So do operator rewards that were not paid out
+        tip_router_client
+            .do_route_ncn_rewards(ncn, ncn_epoch)
+            .await?;
+        let result = tip_router_client
+            .do_close_epoch_snapshot(ncn, ncn_epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::RewardsNotDistributed);
+
+        tip_router_client
+            .do_distribute_operator_rewards(operator, ncn, ncn_epoch)
+            .await?;
+
+        let router_payer = tip_router_client
+            .get_epoch_reward_router(ncn, ncn_epoch)
+            .await?
+            .payer();
+        let router_lamports = fixture
+            .get_account(&epoch_reward_router)
+            .await?
+            .unwrap()
+            .lamports;
+        let payer_lamports_before = fixture.get_account(&router_payer).await?.unwrap().lamports;
+
+        tip_router_client
+            .do_close_epoch_reward_router(ncn, ncn_epoch)
+            .await?;
+        assert!(fixture.get_account(&epoch_reward_router).await?.is_none());
+
+        let payer_lamports_after = fixture.get_account(&router_payer).await?.unwrap().lamports;
+        // The recorded payer gets the rent and any dust back, less the transaction fee
+        assert!(payer_lamports_after + 5_000 >= payer_lamports_before + router_lamports);
+
+        tip_router_client
+            .do_close_weight_table(ncn, ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_close_epoch_snapshot(ncn, ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_close_operator_snapshot(ncn, operator, ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_close_vault_snapshot(ncn, vault, ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_close_ballot_box(ncn, ncn_epoch)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_close_epoch_accounts_with_unrouted_lamports() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+
+        let epoch_length = tip_router_client
+            .get_restaking_config()
+            .await?
+            .epoch_length();
+        let ncn_epoch = fixture.clock().await.slot / epoch_length;
+
+        tip_router_client
+            .do_initialize_ballot_box(ncn, ncn_epoch)
+            .await?;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+        tip_router_client
+            .do_cast_vote(ncn, operator, operator_admin, [1u8; 32], ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_set_parameters(Some(2), None, None, None, &test_ncn.ncn_root)
+            .await?;
+
+        // Tips sent to the router before it was initialized still have to be routed
+        let epoch_reward_router = EpochRewardRouter::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            ncn_epoch,
+        )
+        .0;
+        tip_router_client.airdrop(&epoch_reward_router, 1.0).await?;
+
+        fixture.warp_slot_incremental(2 * epoch_length).await?;
+
+        let result = tip_router_client
+            .do_close_weight_table(ncn, ncn_epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::RewardsNotDistributed);
+        let result = tip_router_client.do_close_ballot_box(ncn, ncn_epoch).await;
+        assert_tip_router_error(result, TipRouterError::RewardsNotDistributed);
+
+        tip_router_client
+            .do_initialize_epoch_reward_router(ncn, ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_route_ncn_rewards(ncn, ncn_epoch)
+            .await?;
+        tip_router_client
+            .do_distribute_operator_rewards(operator, ncn, ncn_epoch)
+            .await?;
+
+        tip_router_client
+            .do_close_weight_table(ncn, ncn_epoch)
+            .await?;
+
+        Ok(())
+    }
+}
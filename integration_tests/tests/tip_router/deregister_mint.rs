@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{config::Config, ncn_vault_ticket::NcnVaultTicket};
+    use jito_tip_router_core::error::TipRouterError;
+    use jito_vault_core::vault_ncn_ticket::VaultNcnTicket;
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::fixtures::{
+        restaking_client::NcnRoot, test_builder::TestBuilder,
+        tip_router_client::assert_tip_router_error, vault_client::VaultRoot, TestResult,
+    };
+
+    struct RegisteredVault {
+        ncn_root: NcnRoot,
+        vault_root: VaultRoot,
+        vault_ncn_ticket: Pubkey,
+        ncn_vault_ticket: Pubkey,
+        epoch_length: u64,
+    }
+
+    async fn setup_registered_vault(fixture: &mut TestBuilder) -> TestResult<RegisteredVault> {
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut vault_client = fixture.vault_client();
+        let mut restaking_client = fixture.restaking_program_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        tip_router_client.setup_tip_router(&ncn_root).await?;
+
+        let vault_root = vault_client
+            .do_initialize_vault(0, 0, 0, 9, &ncn_root.ncn_pubkey)
+            .await?;
+        restaking_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await?;
+        vault_client
+            .do_initialize_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await?;
+
+        let vault_ncn_ticket = VaultNcnTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &ncn_root.ncn_pubkey,
+        )
+        .0;
+        let ncn_vault_ticket = NcnVaultTicket::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            &vault_root.vault_pubkey,
+        )
+        .0;
+
+        fixture.warp_slot_incremental(2).await?;
+
+        vault_client
+            .do_warmup_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await?;
+        restaking_client
+            .do_warmup_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await?;
+        let restaking_config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+        let epoch_length = restaking_client
+            .get_config(&restaking_config_pubkey)
+            .await?
+            .epoch_length();
+        fixture.warp_slot_incremental(2 * epoch_length).await?;
+
+        tip_router_client
+            .do_register_mint(
+                ncn_root.ncn_pubkey,
+                vault_root.vault_pubkey,
+                vault_ncn_ticket,
+                ncn_vault_ticket,
+            )
+            .await?;
+
+        Ok(RegisteredVault {
+            ncn_root,
+            vault_root,
+            vault_ncn_ticket,
+            ncn_vault_ticket,
+            epoch_length,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_deregister_mint_after_cooldown() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut restaking_client = fixture.restaking_program_client();
+        let registered = setup_registered_vault(&mut fixture).await?;
+        let ncn = registered.ncn_root.ncn_pubkey;
+        let vault = registered.vault_root.vault_pubkey;
+
+        restaking_client
+            .do_cooldown_ncn_vault_ticket(&registered.ncn_root, &vault)
+            .await?;
+        fixture
+            .warp_slot_incremental(2 * registered.epoch_length)
+            .await?;
+
+        tip_router_client
+            .do_deregister_mint(
+                ncn,
+                vault,
+                registered.vault_ncn_ticket,
+                registered.ncn_vault_ticket,
+            )
+            .await?;
+
+        let tracked_mints = tip_router_client.get_tracked_mints(ncn).await?;
+        assert_eq!(tracked_mints.mint_count(), 0);
+        assert_eq!(tracked_mints.vault_count(), 1);
+
+        // The NCN still counts the vault, so the counts stay in sync
+        let ncn_account = restaking_client.get_ncn(&ncn).await?;
+        assert_eq!(ncn_account.vault_count(), tracked_mints.vault_count());
+
+        // Deregistering twice fails
+        fixture.warp_slot_incremental(1).await?;
+        let result = tip_router_client
+            .do_deregister_mint(
+                ncn,
+                vault,
+                registered.vault_ncn_ticket,
+                registered.ncn_vault_ticket,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::MintAlreadyDeregistered);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_deregister_mint_fails_while_active() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let registered = setup_registered_vault(&mut fixture).await?;
+
+        let result = tip_router_client
+            .do_deregister_mint(
+                registered.ncn_root.ncn_pubkey,
+                registered.vault_root.vault_pubkey,
+                registered.vault_ncn_ticket,
+                registered.ncn_vault_ticket,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::VaultStillActive);
+
+        let tracked_mints = tip_router_client
+            .get_tracked_mints(registered.ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(tracked_mints.mint_count(), 1);
+
+        Ok(())
+    }
+}
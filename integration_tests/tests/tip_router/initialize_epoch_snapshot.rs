@@ -1,7 +1,11 @@
 #[cfg(test)]
 mod tests {
 
-    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+    use jito_tip_router_core::error::TipRouterError;
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
 
     #[tokio::test]
     async fn test_initialize_epoch_snapshot_ok() -> TestResult<()> {
@@ -33,6 +37,114 @@ mod tests {
             .do_initialize_epoch_snapshot(test_ncn.ncn_root.ncn_pubkey, slot)
             .await?;
 
+        // Only the fee in effect for the epoch is copied into the snapshot
+        let ncn_epoch = slot
+            / tip_router_client
+                .get_restaking_config()
+                .await?
+                .epoch_length();
+        let epoch_snapshot = tip_router_client
+            .get_epoch_snapshot(test_ncn.ncn_root.ncn_pubkey, ncn_epoch)
+            .await?;
+        let config = tip_router_client
+            .get_ncn_config(test_ncn.ncn_root.ncn_pubkey)
+            .await?;
+        let fee = config.fees.current_fee(ncn_epoch);
+        assert_eq!(epoch_snapshot.fee().dao_fee_wallet(), fee.dao_fee_wallet());
+        assert_eq!(epoch_snapshot.fee().ncn_fee_wallet(), fee.ncn_fee_wallet());
+        assert_eq!(epoch_snapshot.fee().dao_share_bps(), fee.dao_share_bps());
+        assert_eq!(epoch_snapshot.fee().ncn_share_bps(), fee.ncn_share_bps());
+        assert_eq!(
+            epoch_snapshot.fee().activation_epoch(),
+            fee.activation_epoch()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_initialize_epoch_snapshot_outside_window() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1).await?;
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch_length = tip_router_client
+            .get_restaking_config()
+            .await?
+            .epoch_length();
+
+        let mut slot = fixture.clock().await.slot;
+        if slot % epoch_length == 0 {
+            fixture.warp_slot_incremental(1).await?;
+            slot = fixture.clock().await.slot;
+        }
+        let slots_into_epoch = slot % epoch_length;
+
+        // The window closes right before the current slot
+        tip_router_client
+            .do_set_parameters(None, Some(slots_into_epoch), None, None, &test_ncn.ncn_root)
+            .await?;
+
+        let result = tip_router_client
+            .do_initialize_epoch_snapshot(ncn, slot)
+            .await;
+        assert_tip_router_error(result, TipRouterError::SnapshotWindowClosed);
+
+        tip_router_client
+            .do_set_parameters(
+                None,
+                Some(slots_into_epoch + 1),
+                None,
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        tip_router_client
+            .do_initialize_epoch_snapshot(ncn, slot)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_initialize_epoch_snapshot_grace_period() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1).await?;
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch_length = tip_router_client
+            .get_restaking_config()
+            .await?
+            .epoch_length();
+        let slot = fixture.clock().await.slot;
+
+        // Move to the next NCN epoch
+        fixture.warp_slot_incremental(epoch_length).await?;
+
+        tip_router_client
+            .do_set_parameters(None, None, Some(0), None, &test_ncn.ncn_root)
+            .await?;
+
+        let result = tip_router_client
+            .do_initialize_epoch_snapshot(ncn, slot)
+            .await;
+        assert_tip_router_error(result, TipRouterError::SnapshotGracePeriodExpired);
+
+        tip_router_client
+            .do_set_parameters(None, None, Some(1), None, &test_ncn.ncn_root)
+            .await?;
+
+        tip_router_client
+            .do_initialize_epoch_snapshot(ncn, slot)
+            .await?;
+
         Ok(())
     }
 }
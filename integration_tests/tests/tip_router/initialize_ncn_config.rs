@@ -69,6 +69,7 @@ mod tests {
                 &ncn_root.ncn_admin,
                 ncn_admin_pubkey,
                 ncn_admin_pubkey,
+                ncn_admin_pubkey,
                 10_001,
                 0,
                 0,
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    #[tokio::test]
+    async fn test_initialize_vault_snapshot() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(2, 1).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let slot = fixture.clock().await.slot;
+        let ncn_epoch = slot
+            / tip_router_client
+                .get_restaking_config()
+                .await?
+                .epoch_length();
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+
+        let vault_snapshot = tip_router_client
+            .get_vault_snapshot(vault, ncn, ncn_epoch)
+            .await?;
+        assert_eq!(vault_snapshot.vault(), vault);
+        assert_eq!(vault_snapshot.operators_registered(), 2);
+
+        let mut operator_stake_weight = 0;
+        for operator_root in test_ncn.operators.iter() {
+            let operator_snapshot = tip_router_client
+                .get_operator_snapshot(operator_root.operator_pubkey, ncn, ncn_epoch)
+                .await?;
+            operator_stake_weight += operator_snapshot.stake_weight();
+        }
+        assert_eq!(vault_snapshot.stake_weight(), operator_stake_weight);
+
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, ncn_epoch).await?;
+        assert_eq!(vault_snapshot.stake_weight(), epoch_snapshot.stake_weight());
+
+        Ok(())
+    }
+}
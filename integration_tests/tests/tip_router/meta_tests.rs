@@ -24,6 +24,7 @@ mod tests {
         fixture
             .add_operator_snapshots_to_test_ncn(&test_ncn)
             .await?;
+        fixture.add_vault_snapshots_to_test_ncn(&test_ncn).await?;
         fixture
             .add_vault_operator_delegation_snapshots_to_test_ncn(&test_ncn)
             .await?;
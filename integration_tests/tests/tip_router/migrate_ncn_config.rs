@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use jito_bytemuck::{AccountDeserialize, Discriminator};
+    use jito_tip_router_core::{
+        constants::DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS,
+        fees::LegacyFee,
+        ncn_config::{LegacyNcnConfig, NcnConfig},
+    };
+    use solana_sdk::{pubkey::Pubkey, rent::Rent};
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    #[tokio::test]
+    async fn test_migrate_ncn_config_from_legacy_layout() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        tip_router_client.setup_tip_router(&ncn_root).await?;
+
+        let ncn = ncn_root.ncn_pubkey;
+        let ncn_config_pubkey =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+
+        // Recreate the account as created before the fee wallets were separated
+        let fee_wallet = Pubkey::new_unique();
+        let legacy_config = LegacyNcnConfig::new(
+            ncn,
+            config.tie_breaker_admin,
+            config.fee_admin,
+            LegacyFee::new(fee_wallet, 270, 15, 300, 4),
+            config.bump,
+        );
+        let legacy_size = 8 + size_of::<LegacyNcnConfig>();
+        let mut account = fixture.get_account(&ncn_config_pubkey).await?.unwrap();
+        account.data = vec![0; 8];
+        account.data[0] = LegacyNcnConfig::DISCRIMINATOR;
+        account
+            .data
+            .extend_from_slice(bytemuck::bytes_of(&legacy_config));
+        account.lamports = Rent::default().minimum_balance(legacy_size);
+        fixture.set_account(ncn_config_pubkey, account).await;
+
+        let account = fixture.get_account(&ncn_config_pubkey).await?.unwrap();
+        assert_eq!(account.data.len(), legacy_size);
+        assert!(NcnConfig::try_from_slice_unchecked(&account.data).is_err());
+
+        tip_router_client.do_migrate_ncn_config(ncn).await?;
+
+        let account = fixture.get_account(&ncn_config_pubkey).await?.unwrap();
+        let full_size = 8 + size_of::<NcnConfig>();
+        assert_eq!(account.data.len(), full_size);
+        assert!(account.lamports >= Rent::default().minimum_balance(full_size));
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.ncn, ncn);
+        assert_eq!(config.tie_breaker_admin, legacy_config.tie_breaker_admin);
+        assert_eq!(config.fee_admin, legacy_config.fee_admin);
+        assert_eq!(config.bump, legacy_config.bump);
+
+        // The legacy wallet is paid both fees
+        let fee = config.fees.current_fee(4);
+        assert_eq!(fee.dao_fee_wallet(), fee_wallet);
+        assert_eq!(fee.ncn_fee_wallet(), fee_wallet);
+        assert_eq!(fee.dao_share_bps(), 270);
+        assert_eq!(fee.ncn_share_bps(), 15);
+        assert_eq!(fee.block_engine_fee_bps(), 300);
+        assert_eq!(fee.activation_epoch(), 4);
+        assert!(config.fees.fee_history().is_empty());
+        assert_eq!(
+            config.fees.activation_delay_epochs(),
+            DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS
+        );
+
+        // Already in the current layout, nothing to do
+        fixture.warp_slot_incremental(1).await?;
+        tip_router_client.do_migrate_ncn_config(ncn).await?;
+        assert_eq!(
+            tip_router_client
+                .get_ncn_config(ncn)
+                .await?
+                .fees
+                .current_fee(4)
+                .dao_fee_wallet(),
+            fee_wallet
+        );
+
+        Ok(())
+    }
+}
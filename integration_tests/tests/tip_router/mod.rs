@@ -1,15 +1,24 @@
 mod admin_update_weight_table;
 mod bpf;
 mod cast_vote;
+mod close_epoch_accounts;
+mod deregister_mint;
 mod initialize_ballot_box;
 mod initialize_epoch_snapshot;
 mod initialize_ncn_config;
 mod initialize_operator_snapshot;
 mod initialize_tracked_mints;
+mod initialize_vault_snapshot;
 mod initialize_weight_table;
 mod meta_tests;
+mod migrate_ncn_config;
+mod realloc_tracked_mints;
 mod register_mint;
+mod route_rewards;
 mod set_config_fees;
 mod set_new_admin;
+mod set_parameters;
 mod set_tie_breaker;
 mod snapshot_vault_operator_delegation;
+mod snapshot_vault_operator_delegations;
+mod verify_snapshot;
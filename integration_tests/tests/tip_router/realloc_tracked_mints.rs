@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use jito_tip_router_core::tracked_mints::{MintEntry, TrackedMints};
+    use solana_sdk::{pubkey::Pubkey, rent::Rent};
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    #[tokio::test]
+    async fn test_realloc_tracked_mints_from_legacy_size() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        tip_router_client.setup_tip_router(&ncn_root).await?;
+
+        let tracked_mints_pubkey = TrackedMints::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn_root.ncn_pubkey,
+        )
+        .0;
+
+        // Recreate an account laid out with the previous 16 entry list
+        let mint = Pubkey::new_unique();
+        let mut tracked_mints = tip_router_client
+            .get_tracked_mints(ncn_root.ncn_pubkey)
+            .await?;
+        tracked_mints.add_mint(mint, 0)?;
+
+        let legacy_size = TrackedMints::HEADER_SIZE + 16 * size_of::<MintEntry>();
+        let mut account = fixture.get_account(&tracked_mints_pubkey).await?.unwrap();
+        account.data[8..].copy_from_slice(bytemuck::bytes_of(&tracked_mints));
+        account.data.truncate(legacy_size);
+        account.lamports = Rent::default().minimum_balance(legacy_size);
+        fixture.set_account(tracked_mints_pubkey, account).await;
+
+        tip_router_client
+            .do_realloc_tracked_mints(ncn_root.ncn_pubkey)
+            .await?;
+
+        let account = fixture.get_account(&tracked_mints_pubkey).await?.unwrap();
+        let full_size = 8 + size_of::<TrackedMints>();
+        assert_eq!(account.data.len(), full_size);
+        assert!(account.lamports >= Rent::default().minimum_balance(full_size));
+
+        let tracked_mints = tip_router_client
+            .get_tracked_mints(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(tracked_mints.mint_count(), 1);
+        assert_eq!(tracked_mints.vault_count(), 1);
+        assert_eq!(tracked_mints.get_unique_mints(), vec![mint]);
+        assert!(tracked_mints.st_mint_list[16..]
+            .iter()
+            .all(|mint_entry| mint_entry.is_empty()));
+
+        // Already at full size, nothing to do
+        fixture.warp_slot_incremental(1).await?;
+        tip_router_client
+            .do_realloc_tracked_mints(ncn_root.ncn_pubkey)
+            .await?;
+
+        Ok(())
+    }
+}
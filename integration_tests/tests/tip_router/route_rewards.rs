@@ -0,0 +1,250 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::{
+        constants::DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS, epoch_reward_router::EpochRewardRouter,
+        error::TipRouterError,
+    };
+    use solana_program::native_token::sol_to_lamports;
+    use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_route_rewards() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut vault_client = fixture.vault_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(2, 2).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        let epoch_length = tip_router_client
+            .get_restaking_config()
+            .await?
+            .epoch_length();
+
+        // 10% DAO fee and 5% NCN fee, active once the activation delay has passed
+        let dao_fee_wallet = Pubkey::new_unique();
+        let ncn_fee_wallet = Pubkey::new_unique();
+        tip_router_client.airdrop(&dao_fee_wallet, 1.0).await?;
+        tip_router_client.airdrop(&ncn_fee_wallet, 1.0).await?;
+        tip_router_client
+            .do_set_config_fees(
+                1_000,
+                500,
+                0,
+                dao_fee_wallet,
+                ncn_fee_wallet,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        fixture
+            .warp_slot_incremental(DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS * epoch_length)
+            .await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let ncn_epoch = fixture.clock().await.slot / epoch_length;
+
+        tip_router_client
+            .do_initialize_epoch_reward_router(ncn, ncn_epoch)
+            .await?;
+
+        let epoch_reward_router = EpochRewardRouter::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            ncn_epoch,
+        )
+        .0;
+        tip_router_client.airdrop(&epoch_reward_router, 1.0).await?;
+
+        // Fees can only be paid to the wallets recorded in the epoch snapshot
+        let result = tip_router_client
+            .route_ncn_rewards(ncn, ncn_epoch, dao_fee_wallet, Pubkey::new_unique())
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectFeeWallet);
+
+        let result = tip_router_client
+            .route_ncn_rewards(ncn, ncn_epoch, ncn_fee_wallet, dao_fee_wallet)
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectFeeWallet);
+
+        let dao_fee_wallet_before = fixture
+            .get_account(&dao_fee_wallet)
+            .await?
+            .unwrap()
+            .lamports;
+        let ncn_fee_wallet_before = fixture
+            .get_account(&ncn_fee_wallet)
+            .await?
+            .unwrap()
+            .lamports;
+
+        tip_router_client
+            .do_route_ncn_rewards(ncn, ncn_epoch)
+            .await?;
+
+        let rewards = sol_to_lamports(1.0);
+        let router = tip_router_client
+            .get_epoch_reward_router(ncn, ncn_epoch)
+            .await?;
+        assert_eq!(router.total_rewards(), rewards);
+        assert_eq!(router.dao_rewards(), rewards / 10);
+        assert_eq!(router.ncn_rewards(), rewards / 20);
+        assert_eq!(
+            router.operator_rewards(),
+            rewards - rewards / 10 - rewards / 20
+        );
+
+        let dao_fee_wallet_after = fixture
+            .get_account(&dao_fee_wallet)
+            .await?
+            .unwrap()
+            .lamports;
+        let ncn_fee_wallet_after = fixture
+            .get_account(&ncn_fee_wallet)
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(
+            dao_fee_wallet_after - dao_fee_wallet_before,
+            router.dao_rewards()
+        );
+        assert_eq!(
+            ncn_fee_wallet_after - ncn_fee_wallet_before,
+            router.ncn_rewards()
+        );
+
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, ncn_epoch).await?;
+
+        // Rewards go to wallets that can spend them, the operator admin and the vault fee
+        // wallets, rather than to the operator and vault accounts
+        let mut vault_fee_wallets = Vec::new();
+        for vault_root in test_ncn.vaults.iter() {
+            let vault = vault_client.get_vault(&vault_root.vault_pubkey).await?;
+            let vault_snapshot = tip_router_client
+                .get_vault_snapshot(vault_root.vault_pubkey, ncn, ncn_epoch)
+                .await?;
+            assert_eq!(vault_snapshot.fee_wallet(), vault.fee_wallet);
+            vault_fee_wallets.push(vault.fee_wallet);
+        }
+        let mut vault_fee_wallets_start = Vec::new();
+        for vault_fee_wallet in vault_fee_wallets.iter() {
+            vault_fee_wallets_start.push(
+                fixture
+                    .get_account(vault_fee_wallet)
+                    .await?
+                    .unwrap()
+                    .lamports,
+            );
+        }
+
+        for operator_root in test_ncn.operators.iter() {
+            let operator = operator_root.operator_pubkey;
+            let operator_snapshot = tip_router_client
+                .get_operator_snapshot(operator, ncn, ncn_epoch)
+                .await?;
+            let operator_fee_wallet = operator_snapshot.operator_fee_wallet();
+            assert_eq!(operator_fee_wallet, operator_root.operator_admin.pubkey());
+
+            let operator_before = fixture
+                .get_account(&operator_fee_wallet)
+                .await?
+                .unwrap()
+                .lamports;
+            let mut vaults_before = Vec::new();
+            for vault_fee_wallet in vault_fee_wallets.iter() {
+                vaults_before.push(
+                    fixture
+                        .get_account(vault_fee_wallet)
+                        .await?
+                        .unwrap()
+                        .lamports,
+                );
+            }
+
+            tip_router_client
+                .do_distribute_operator_rewards(operator, ncn, ncn_epoch)
+                .await?;
+
+            let operator_rewards = fixture
+                .get_account(&operator_fee_wallet)
+                .await?
+                .unwrap()
+                .lamports
+                - operator_before;
+            let mut vault_rewards = 0;
+            for (vault_fee_wallet, before) in vault_fee_wallets.iter().zip(vaults_before) {
+                vault_rewards += fixture
+                    .get_account(vault_fee_wallet)
+                    .await?
+                    .unwrap()
+                    .lamports
+                    - before;
+            }
+
+            let entitlement = (router.operator_rewards() as u128 * operator_snapshot.stake_weight()
+                / epoch_snapshot.stake_weight()) as u64;
+            assert_eq!(operator_rewards + vault_rewards, entitlement);
+            assert!(
+                operator_rewards
+                    >= entitlement * operator_snapshot.operator_fee_bps() as u64 / 10_000
+            );
+
+            let operator_snapshot = tip_router_client
+                .get_operator_snapshot(operator, ncn, ncn_epoch)
+                .await?;
+            assert_eq!(operator_snapshot.rewards_routed(), entitlement);
+        }
+
+        let router = tip_router_client
+            .get_epoch_reward_router(ncn, ncn_epoch)
+            .await?;
+        assert!(router.undistributed_operator_rewards().unwrap() < test_ncn.operators.len() as u64);
+
+        // The vault admin holds the vault fee wallet and can move what it was paid
+        let vault_root = &test_ncn.vaults[0];
+        assert_eq!(vault_fee_wallets[0], vault_root.vault_admin.pubkey());
+        let vault_rewards = fixture
+            .get_account(&vault_fee_wallets[0])
+            .await?
+            .unwrap()
+            .lamports
+            - vault_fee_wallets_start[0];
+        assert!(vault_rewards > 0);
+        let recipient = Pubkey::new_unique();
+        fixture
+            .transfer_from(&vault_root.vault_admin, &recipient, vault_rewards)
+            .await?;
+        assert_eq!(
+            fixture.get_account(&recipient).await?.unwrap().lamports,
+            vault_rewards
+        );
+
+        // Nothing more is owed until new rewards arrive
+        let operator_root = &test_ncn.operators[0];
+        let operator = operator_root.operator_pubkey;
+        let operator_fee_wallet = operator_root.operator_admin.pubkey();
+        let operator_before = fixture
+            .get_account(&operator_fee_wallet)
+            .await?
+            .unwrap()
+            .lamports;
+        fixture.warp_slot_incremental(1).await?;
+        tip_router_client
+            .do_distribute_operator_rewards(operator, ncn, ncn_epoch)
+            .await?;
+        assert_eq!(
+            fixture
+                .get_account(&operator_fee_wallet)
+                .await?
+                .unwrap()
+                .lamports,
+            operator_before
+        );
+
+        Ok(())
+    }
+}
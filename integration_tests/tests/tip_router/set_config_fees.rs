@@ -1,10 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use jito_tip_router_core::error::TipRouterError;
-    use solana_sdk::{
-        clock::DEFAULT_SLOTS_PER_EPOCH,
-        signature::{Keypair, Signer},
+    use jito_tip_router_core::{
+        constants::DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS, error::TipRouterError,
     };
+    use solana_sdk::signature::{Keypair, Signer};
 
     use crate::fixtures::{
         test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
@@ -21,14 +20,16 @@ mod tests {
             .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
             .await?;
 
-        // Change fees and fee wallet
-        let new_fee_wallet = Keypair::new();
+        // Change fees and fee wallets
+        let new_dao_fee_wallet = Keypair::new();
+        let new_ncn_fee_wallet = Keypair::new();
         tip_router_client
             .do_set_config_fees(
                 100, // dao_fee_bps
                 200, // ncn_fee_bps
                 300, // block_engine_fee_bps
-                new_fee_wallet.pubkey(),
+                new_dao_fee_wallet.pubkey(),
+                new_ncn_fee_wallet.pubkey(),
                 &ncn_root,
             )
             .await?;
@@ -49,7 +50,14 @@ mod tests {
 
         // Try to set fees above max
         let transaction_error = tip_router_client
-            .do_set_config_fees(10_001, 0, 0, ncn_root.ncn_admin.pubkey(), &ncn_root)
+            .do_set_config_fees(
+                10_001,
+                0,
+                0,
+                ncn_root.ncn_admin.pubkey(),
+                ncn_root.ncn_admin.pubkey(),
+                &ncn_root,
+            )
             .await;
 
         assert_tip_router_error(transaction_error, TipRouterError::FeeCapExceeded);
@@ -69,7 +77,14 @@ mod tests {
         let wrong_admin = Keypair::new();
         ncn_root.ncn_admin = wrong_admin;
         let transaction_error = tip_router_client
-            .do_set_config_fees(100, 200, 300, ncn_root.ncn_admin.pubkey(), &ncn_root)
+            .do_set_config_fees(
+                100,
+                200,
+                300,
+                ncn_root.ncn_admin.pubkey(),
+                ncn_root.ncn_admin.pubkey(),
+                &ncn_root,
+            )
             .await;
 
         assert_tip_router_error(transaction_error, TipRouterError::IncorrectFeeAdmin);
@@ -88,26 +103,56 @@ mod tests {
             .await?;
 
         // Set new fees
-        let new_fee_wallet = Keypair::new();
+        let new_dao_fee_wallet = Keypair::new();
+        let new_ncn_fee_wallet = Keypair::new();
         tip_router_client
-            .do_set_config_fees(100, 200, 0, new_fee_wallet.pubkey(), &ncn_root)
+            .do_set_config_fees(
+                100,
+                200,
+                0,
+                new_dao_fee_wallet.pubkey(),
+                new_ncn_fee_wallet.pubkey(),
+                &ncn_root,
+            )
             .await?;
 
-        // Advance epoch
+        let epoch_length = tip_router_client
+            .get_restaking_config()
+            .await?
+            .epoch_length();
+        let ncn_epoch = fixture.clock().await.slot / epoch_length;
+
+        // New fees only take effect after the activation delay
+        let config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        let activation_epoch = ncn_epoch + DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS;
+        assert_eq!(config.fees.dao_fee(activation_epoch - 1).unwrap(), 0);
+        assert_eq!(
+            config.fees.dao_fee_wallet(activation_epoch - 1),
+            ncn_root.ncn_admin.pubkey()
+        );
+
+        // Advance past the activation epoch
         fixture
-            .warp_slot_incremental(2 * DEFAULT_SLOTS_PER_EPOCH)
+            .warp_slot_incremental(DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS * epoch_length)
             .await?;
 
         let config = tip_router_client
             .get_ncn_config(ncn_root.ncn_pubkey)
             .await?;
-        let clock = fixture.clock().await;
-        assert_eq!(config.fees.dao_fee(clock.epoch as u64).unwrap(), 100);
-        assert_eq!(config.fees.ncn_fee(clock.epoch as u64).unwrap(), 200);
-        assert_eq!(config.fees.block_engine_fee(clock.epoch as u64), 0);
+        let ncn_epoch = fixture.clock().await.slot / epoch_length;
+        assert_eq!(ncn_epoch, activation_epoch);
+        assert_eq!(config.fees.dao_fee(ncn_epoch).unwrap(), 100);
+        assert_eq!(config.fees.ncn_fee(ncn_epoch).unwrap(), 200);
+        assert_eq!(config.fees.block_engine_fee(ncn_epoch), 0);
+        assert_eq!(
+            config.fees.dao_fee_wallet(ncn_epoch),
+            new_dao_fee_wallet.pubkey()
+        );
         assert_eq!(
-            config.fees.fee_wallet(clock.epoch as u64),
-            new_fee_wallet.pubkey()
+            config.fees.ncn_fee_wallet(ncn_epoch),
+            new_ncn_fee_wallet.pubkey()
         );
 
         Ok(())
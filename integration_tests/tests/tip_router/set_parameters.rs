@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::{
+        constants::{
+            DEFAULT_EPOCHS_BEFORE_CLOSE, DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS,
+            DEFAULT_SNAPSHOT_GRACE_EPOCHS, DEFAULT_SNAPSHOT_WINDOW_SLOTS,
+        },
+        error::TipRouterError,
+    };
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        restaking_client::NcnRoot, test_builder::TestBuilder,
+        tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_set_parameters_success() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        tip_router_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.epochs_before_close(), DEFAULT_EPOCHS_BEFORE_CLOSE);
+        assert_eq!(
+            config.snapshot_window_slots(),
+            DEFAULT_SNAPSHOT_WINDOW_SLOTS
+        );
+        assert_eq!(
+            config.snapshot_grace_epochs(),
+            DEFAULT_SNAPSHOT_GRACE_EPOCHS
+        );
+        assert_eq!(
+            config.fees.activation_delay_epochs(),
+            DEFAULT_FEE_ACTIVATION_DELAY_EPOCHS
+        );
+
+        tip_router_client
+            .do_set_parameters(Some(3), Some(500), Some(2), Some(5), &ncn_root)
+            .await?;
+
+        let config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.epochs_before_close(), 3);
+        assert_eq!(config.snapshot_window_slots(), 500);
+        assert_eq!(config.snapshot_grace_epochs(), 2);
+        assert_eq!(config.fees.activation_delay_epochs(), 5);
+
+        // Leaving a parameter unset keeps its value
+        fixture.warp_slot_incremental(1).await?;
+        tip_router_client
+            .do_set_parameters(None, None, None, None, &ncn_root)
+            .await?;
+
+        let config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.epochs_before_close(), 3);
+        assert_eq!(config.snapshot_window_slots(), 500);
+        assert_eq!(config.snapshot_grace_epochs(), 2);
+        assert_eq!(config.fees.activation_delay_epochs(), 5);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_parameters_invalid() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        tip_router_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let result = tip_router_client
+            .do_set_parameters(Some(0), None, None, None, &ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidEpochsBeforeClose);
+
+        let result = tip_router_client
+            .do_set_parameters(None, Some(0), None, None, &ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidSnapshotWindow);
+
+        let result = tip_router_client
+            .do_set_parameters(None, None, None, Some(0), &ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidFeeActivationDelay);
+
+        // Only the NCN admin can set parameters
+        let wrong_ncn_root = NcnRoot {
+            ncn_pubkey: ncn_root.ncn_pubkey,
+            ncn_admin: Keypair::new(),
+        };
+        let result = tip_router_client
+            .do_set_parameters(Some(3), None, None, None, &wrong_ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectNcnAdmin);
+
+        Ok(())
+    }
+}
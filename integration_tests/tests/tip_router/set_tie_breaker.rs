@@ -17,10 +17,9 @@ mod tests {
         fixture.snapshot_test_ncn(&test_ncn).await?;
         //////
 
-        let clock = fixture.clock().await;
-        let slot = clock.slot;
-        let restaking_config_account = tip_router_client.get_restaking_config().await?;
-        let ncn_epoch = slot / restaking_config_account.epoch_length();
+        let slot = fixture.clock().await.slot;
+        let ncn_epoch_schedule = tip_router_client.get_ncn_epoch_schedule().await?;
+        let ncn_epoch = ncn_epoch_schedule.ncn_epoch(slot);
         let ncn = test_ncn.ncn_root.ncn_pubkey;
 
         tip_router_client
@@ -46,8 +45,10 @@ mod tests {
         );
         assert!(!ballot_box.is_consensus_reached());
 
-        // Wait a bunch of epochs for voting window to expire (TODO use the exact length)
-        fixture.warp_slot_incremental(1000000).await?;
+        // Stalls are counted in NCN epochs, whatever the length of a Solana epoch
+        fixture
+            .warp_slot_incremental(ncn_epoch_schedule.ncn_epoch_length())
+            .await?;
 
         tip_router_client
             .do_set_tie_breaker(ncn, meta_merkle_root, ncn_epoch)
@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod tests {
+    use jito_tip_router_core::epoch_snapshot::ExclusionReason;
+    use solana_program::instruction::InstructionError;
+    use solana_sdk::pubkey::Pubkey;
 
-    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+    use crate::fixtures::{assert_ix_error, test_builder::TestBuilder, TestResult};
 
     #[tokio::test]
     async fn test_initialize_operator_snapshot() -> TestResult<()> {
@@ -42,10 +45,121 @@ mod tests {
             .do_initalize_operator_snapshot(operator, ncn, slot)
             .await?;
 
+        tip_router_client
+            .do_initialize_vault_snapshot(vault_address, ncn, slot)
+            .await?;
+
         tip_router_client
             .do_snapshot_vault_operator_delegation(vault_address, operator, ncn, slot)
             .await?;
 
+        let ncn_epoch = slot
+            / tip_router_client
+                .get_restaking_config()
+                .await?
+                .epoch_length();
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, ncn_epoch)
+            .await?;
+        assert_eq!(
+            operator_snapshot.exclusion_reason(),
+            Ok(ExclusionReason::NotExcluded)
+        );
+        assert_eq!(
+            operator_snapshot.vault_operator_stake_weights()[0].exclusion_reason(),
+            Ok(ExclusionReason::NotExcluded)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegation_missing() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        // The operator joins after the vault, so the vault never delegates to it
+        let mut test_ncn = fixture.create_test_ncn().await?;
+        fixture.add_vaults_to_test_ncn(&mut test_ncn, 1).await?;
+        fixture.add_tracked_mints_to_test_ncn(&test_ncn).await?;
+        fixture.add_operators_to_test_ncn(&mut test_ncn, 1).await?;
+
+        let epoch_length = tip_router_client
+            .get_restaking_config()
+            .await?
+            .epoch_length();
+        fixture.warp_slot_incremental(2 * epoch_length).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let ncn_epoch = fixture.clock().await.slot / epoch_length;
+
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, ncn_epoch)
+            .await?;
+        assert!(operator_snapshot.finalized());
+        assert_eq!(operator_snapshot.stake_weight(), 0);
+
+        let stake_weight = operator_snapshot.vault_operator_stake_weights()[0];
+        assert_eq!(stake_weight.vault(), test_ncn.vaults[0].vault_pubkey);
+        assert_eq!(
+            stake_weight.exclusion_reason(),
+            Ok(ExclusionReason::DelegationMissing)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegation_fake_missing_delegation() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+        fixture
+            .add_operator_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+        fixture.add_vault_snapshots_to_test_ncn(&test_ncn).await?;
+
+        let slot = fixture.clock().await.slot;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+
+        // An empty account in place of the delegation cannot exclude the vault
+        let mut ix = tip_router_client
+            .snapshot_vault_operator_delegation_ix(vault, operator, ncn, slot)
+            .await?;
+        ix.accounts[7].pubkey = Pubkey::new_unique();
+
+        let result = tip_router_client.process_instructions(&[ix]).await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+
+        let ncn_epoch = slot
+            / tip_router_client
+                .get_restaking_config()
+                .await?
+                .epoch_length();
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, ncn_epoch)
+            .await?;
+        assert_eq!(operator_snapshot.vault_operator_delegations_registered(), 0);
+
+        // The real delegation still snapshots
+        tip_router_client
+            .do_snapshot_vault_operator_delegation(vault, operator, ncn, slot)
+            .await?;
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, ncn_epoch)
+            .await?;
+        assert!(operator_snapshot.finalized());
+        assert!(operator_snapshot.stake_weight() > 0);
+
         Ok(())
     }
 }
@@ -0,0 +1,285 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::{
+        constants::SNAPSHOT_COMPUTE_UNITS_PER_VAULT, error::TipRouterError,
+    };
+    use solana_program::instruction::InstructionError;
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::fixtures::{
+        assert_ix_error, test_builder::TestBuilder, tip_router_client::assert_tip_router_error,
+        TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegations() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 3).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+        fixture
+            .add_operator_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+        fixture.add_vault_snapshots_to_test_ncn(&test_ncn).await?;
+
+        let slot = fixture.clock().await.slot;
+        let ncn_epoch = slot
+            / tip_router_client
+                .get_restaking_config()
+                .await?
+                .epoch_length();
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let vaults: Vec<Pubkey> = test_ncn.vaults.iter().map(|v| v.vault_pubkey).collect();
+
+        tip_router_client
+            .do_snapshot_vault_operator_delegations(&vaults, operator, ncn, slot)
+            .await?;
+
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, ncn_epoch)
+            .await?;
+        assert!(operator_snapshot.finalized());
+        assert_eq!(operator_snapshot.vault_operator_delegations_registered(), 3);
+
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, ncn_epoch).await?;
+        assert_eq!(epoch_snapshot.operators_registered(), 1);
+        assert_eq!(
+            epoch_snapshot.stake_weight(),
+            operator_snapshot.stake_weight()
+        );
+        assert!(epoch_snapshot.finalized());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegations_resumes() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 3).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+        fixture
+            .add_operator_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+        fixture.add_vault_snapshots_to_test_ncn(&test_ncn).await?;
+
+        let slot = fixture.clock().await.slot;
+        let ncn_epoch = slot
+            / tip_router_client
+                .get_restaking_config()
+                .await?
+                .epoch_length();
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let vaults: Vec<Pubkey> = test_ncn.vaults.iter().map(|v| v.vault_pubkey).collect();
+
+        tip_router_client
+            .do_snapshot_vault_operator_delegations(&vaults[..1], operator, ncn, slot)
+            .await?;
+
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, ncn_epoch)
+            .await?;
+        assert!(!operator_snapshot.finalized());
+        assert_eq!(operator_snapshot.vault_operator_delegations_registered(), 1);
+
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, ncn_epoch).await?;
+        assert_eq!(epoch_snapshot.operators_registered(), 0);
+
+        // The already snapshotted vault is skipped
+        tip_router_client
+            .do_snapshot_vault_operator_delegations(&vaults, operator, ncn, slot)
+            .await?;
+
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, ncn_epoch)
+            .await?;
+        assert!(operator_snapshot.finalized());
+        assert_eq!(operator_snapshot.vault_operator_delegations_registered(), 3);
+
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, ncn_epoch).await?;
+        assert_eq!(epoch_snapshot.operators_registered(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegations_stops_early() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 3).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+        fixture
+            .add_operator_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+        fixture.add_vault_snapshots_to_test_ncn(&test_ncn).await?;
+
+        let slot = fixture.clock().await.slot;
+        let ncn_epoch = slot
+            / tip_router_client
+                .get_restaking_config()
+                .await?
+                .epoch_length();
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let vaults: Vec<Pubkey> = test_ncn.vaults.iter().map(|v| v.vault_pubkey).collect();
+
+        // Enough compute for the first vault, leaving less than the per vault budget after it
+        let one_vault_units = tip_router_client
+            .simulate_snapshot_vault_operator_delegations(&vaults[..1], operator, ncn, slot)
+            .await?;
+        let compute_unit_limit = one_vault_units + SNAPSHOT_COMPUTE_UNITS_PER_VAULT - 10_000;
+
+        tip_router_client
+            .snapshot_vault_operator_delegations(
+                &vaults,
+                operator,
+                ncn,
+                slot,
+                Some(compute_unit_limit as u32),
+            )
+            .await?;
+
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, ncn_epoch)
+            .await?;
+        assert!(!operator_snapshot.finalized());
+        assert_eq!(operator_snapshot.vault_operator_delegations_registered(), 1);
+
+        // Resubmitting the same batch picks up where it stopped
+        tip_router_client
+            .do_snapshot_vault_operator_delegations(&vaults, operator, ncn, slot)
+            .await?;
+
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, ncn_epoch)
+            .await?;
+        assert!(operator_snapshot.finalized());
+        assert_eq!(operator_snapshot.vault_operator_delegations_registered(), 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegations_incomplete_group() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 2).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+        fixture
+            .add_operator_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+        fixture.add_vault_snapshots_to_test_ncn(&test_ncn).await?;
+
+        let slot = fixture.clock().await.slot;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let vaults: Vec<Pubkey> = test_ncn.vaults.iter().map(|v| v.vault_pubkey).collect();
+
+        // Drop the vault snapshot of the last group
+        let mut ix = tip_router_client
+            .snapshot_vault_operator_delegations_ix(&vaults, operator, ncn, slot)
+            .await?;
+        ix.accounts.pop();
+
+        let result = tip_router_client.process_instructions(&[ix]).await;
+        assert_ix_error(result, InstructionError::NotEnoughAccountKeys);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegations_no_compute_for_first_vault() -> TestResult<()>
+    {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 2).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+        fixture
+            .add_operator_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+        fixture.add_vault_snapshots_to_test_ncn(&test_ncn).await?;
+
+        let slot = fixture.clock().await.slot;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let vaults: Vec<Pubkey> = test_ncn.vaults.iter().map(|v| v.vault_pubkey).collect();
+
+        // Enough compute to reach the first vault, but less than the per vault budget there
+        let one_vault_units = tip_router_client
+            .simulate_snapshot_vault_operator_delegations(&vaults[..1], operator, ncn, slot)
+            .await?;
+        let two_vault_units = tip_router_client
+            .simulate_snapshot_vault_operator_delegations(&vaults, operator, ncn, slot)
+            .await?;
+        let setup_units = 2 * one_vault_units - two_vault_units;
+        let compute_unit_limit = setup_units + SNAPSHOT_COMPUTE_UNITS_PER_VAULT / 2;
+
+        // A batch that cannot make progress fails instead of succeeding without doing anything
+        let result = tip_router_client
+            .snapshot_vault_operator_delegations(
+                &vaults,
+                operator,
+                ncn,
+                slot,
+                Some(compute_unit_limit as u32),
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::NotEnoughComputeUnits);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegations_fake_missing_delegation() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 2).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.add_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+        fixture
+            .add_operator_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+        fixture.add_vault_snapshots_to_test_ncn(&test_ncn).await?;
+
+        let slot = fixture.clock().await.slot;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let vaults: Vec<Pubkey> = test_ncn.vaults.iter().map(|v| v.vault_pubkey).collect();
+
+        // Swap every vault's delegation for an empty account, in groups of 5 after the 9 fixed
+        // accounts
+        let mut ix = tip_router_client
+            .snapshot_vault_operator_delegations_ix(&vaults, operator, ncn, slot)
+            .await?;
+        for i in 0..vaults.len() {
+            ix.accounts[9 + 5 * i + 3].pubkey = Pubkey::new_unique();
+        }
+
+        let result = tip_router_client.process_instructions(&[ix]).await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use jito_bytemuck::AccountDeserialize;
+    use jito_restaking_core::{
+        config::Config, ncn_operator_state::NcnOperatorState, ncn_vault_ticket::NcnVaultTicket,
+    };
+    use jito_tip_router_cli::{
+        account_source::{keyed_account_to_json, DirectoryAccountSource},
+        snapshot_verifier::{verify_snapshot, SnapshotMismatch},
+    };
+    use jito_tip_router_core::{
+        epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+        weight_table::WeightTable,
+    };
+    use jito_vault_core::{
+        vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
+    };
+    use solana_sdk::{account::Account, pubkey::Pubkey};
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    #[tokio::test]
+    async fn test_verify_snapshot() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(2, 2).await?;
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let ncn_epoch = fixture.clock().await.slot
+            / tip_router_client
+                .get_restaking_config()
+                .await?
+                .epoch_length();
+
+        let tip_router_program_id = jito_tip_router_program::id();
+        let restaking_program_id = jito_restaking_program::id();
+        let vault_program_id = jito_vault_program::id();
+
+        let mut addresses = vec![
+            Config::find_program_address(&restaking_program_id).0,
+            EpochSnapshot::find_program_address(&tip_router_program_id, &ncn, ncn_epoch).0,
+            WeightTable::find_program_address(&tip_router_program_id, &ncn, ncn_epoch).0,
+        ];
+        for vault_root in test_ncn.vaults.iter() {
+            let vault = vault_root.vault_pubkey;
+            addresses.push(vault);
+            addresses
+                .push(NcnVaultTicket::find_program_address(&restaking_program_id, &ncn, &vault).0);
+            addresses.push(VaultNcnTicket::find_program_address(&vault_program_id, &vault, &ncn).0);
+        }
+        for operator_root in test_ncn.operators.iter() {
+            let operator = operator_root.operator_pubkey;
+            addresses.push(
+                NcnOperatorState::find_program_address(&restaking_program_id, &ncn, &operator).0,
+            );
+            addresses.push(
+                OperatorSnapshot::find_program_address(
+                    &tip_router_program_id,
+                    &operator,
+                    &ncn,
+                    ncn_epoch,
+                )
+                .0,
+            );
+            for vault_root in test_ncn.vaults.iter() {
+                addresses.push(
+                    VaultOperatorDelegation::find_program_address(
+                        &vault_program_id,
+                        &vault_root.vault_pubkey,
+                        &operator,
+                    )
+                    .0,
+                );
+            }
+        }
+
+        let dir = env::temp_dir().join(format!("verify-snapshot-{}", Pubkey::new_unique()));
+        fs::create_dir_all(&dir)?;
+
+        let mut accounts: Vec<(Pubkey, Account)> = Vec::new();
+        for address in addresses {
+            let account = fixture.get_account(&address).await?.unwrap();
+            accounts.push((address, account));
+        }
+        let write_accounts = |accounts: &[(Pubkey, Account)]| -> TestResult<()> {
+            for (address, account) in accounts.iter() {
+                fs::write(
+                    dir.join(format!("{}.json", address)),
+                    keyed_account_to_json(address, account).unwrap(),
+                )?;
+            }
+            Ok(())
+        };
+
+        // A dump of the accounts the snapshot was taken from matches it
+        write_accounts(&accounts)?;
+        let source = DirectoryAccountSource::load(&dir).unwrap();
+        let report = verify_snapshot(&source, &tip_router_program_id, &ncn, ncn_epoch).unwrap();
+        assert!(report.is_consistent(), "{}", report);
+        assert_eq!(report.operators.len(), 2);
+
+        // Tamper with the stake weight recorded for the first operator
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_snapshot_address = OperatorSnapshot::find_program_address(
+            &tip_router_program_id,
+            &operator,
+            &ncn,
+            ncn_epoch,
+        )
+        .0;
+        let (_, operator_snapshot_account) = accounts
+            .iter_mut()
+            .find(|(address, _)| *address == operator_snapshot_address)
+            .unwrap();
+        let stake_weight =
+            OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_account.data)?
+                .stake_weight();
+        assert!(stake_weight > 0);
+
+        // The operator total is the first field holding this value, ahead of the per vault
+        // weights that sum to it
+        let offset = operator_snapshot_account
+            .data
+            .windows(16)
+            .position(|bytes| bytes == stake_weight.to_le_bytes())
+            .unwrap();
+        operator_snapshot_account.data[offset..offset + 16]
+            .copy_from_slice(&(stake_weight + 1).to_le_bytes());
+        assert_eq!(
+            OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_account.data)?
+                .stake_weight(),
+            stake_weight + 1
+        );
+
+        write_accounts(&accounts)?;
+        let source = DirectoryAccountSource::load(&dir).unwrap();
+        let report = verify_snapshot(&source, &tip_router_program_id, &ncn, ncn_epoch).unwrap();
+        assert_eq!(
+            report.mismatches,
+            vec![SnapshotMismatch::OperatorStakeWeight {
+                operator,
+                expected: stake_weight,
+                actual: stake_weight + 1,
+            }]
+        );
+
+        fs::remove_dir_all(&dir)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+//! Serial vs parallel construction of a generated merkle tree collection with roughly a
+//! million delegations. Run with `cargo bench -p meta-merkle-tree --features parallel`.
+use std::time::{Duration, Instant};
+
+use jito_tip_distribution_sdk::{derive_tip_distribution_account_address, jito_tip_distribution};
+use meta_merkle_tree::generated_merkle_tree::{
+    Delegation, GeneratedMerkleTreeCollection, NcnFeeMeta, OperatorFeeMeta, StakeMeta,
+    StakeMetaCollection, TipDistributionMeta, VaultFeeMeta,
+};
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+
+const NUM_VALIDATORS: usize = 1_000;
+const DELEGATIONS_PER_VALIDATOR: usize = 1_000;
+
+fn stake_meta_collection() -> StakeMetaCollection {
+    let stake_metas = (0..NUM_VALIDATORS)
+        .map(|_| {
+            let delegations: Vec<Delegation> = (0..DELEGATIONS_PER_VALIDATOR)
+                .map(|i| Delegation {
+                    stake_account_pubkey: Pubkey::new_unique(),
+                    staker_pubkey: Pubkey::new_unique(),
+                    withdrawer_pubkey: Pubkey::new_unique(),
+                    lamports_delegated: (i as u64 + 1) * LAMPORTS_PER_SOL,
+                })
+                .collect();
+            let total_delegated = delegations.iter().map(|d| d.lamports_delegated).sum();
+            let validator_vote_account = Pubkey::new_unique();
+
+            StakeMeta {
+                validator_vote_account,
+                validator_node_pubkey: Pubkey::new_unique(),
+                maybe_tip_distribution_meta: Some(TipDistributionMeta {
+                    merkle_root_upload_authority: Pubkey::new_unique(),
+                    tip_distribution_pubkey: derive_tip_distribution_account_address(
+                        &jito_tip_distribution::ID,
+                        &validator_vote_account,
+                        0,
+                    )
+                    .0,
+                    total_tips: 10 * LAMPORTS_PER_SOL,
+                    validator_fee_bps: 500,
+                }),
+                delegations,
+                total_delegated,
+                commission: 5,
+            }
+        })
+        .collect();
+
+    StakeMetaCollection {
+        stake_metas,
+        tip_distribution_program_id: jito_tip_distribution::ID,
+        bank_hash: String::default(),
+        epoch: 0,
+        slot: 0,
+    }
+}
+
+fn ncn_fee_meta() -> NcnFeeMeta {
+    NcnFeeMeta {
+        dao_fee_wallet: Pubkey::new_unique(),
+        ncn_fee_wallet: Pubkey::new_unique(),
+        dao_share_bps: 270,
+        ncn_share_bps: 15,
+        block_engine_fee_bps: 300,
+        total_stake_weight: 10,
+        operator_fees: (0..10)
+            .map(|_| OperatorFeeMeta {
+                operator: Pubkey::new_unique(),
+                operator_fee_wallet: Pubkey::new_unique(),
+                operator_fee_bps: 1_000,
+                stake_weight: 1,
+                vault_fees: vec![VaultFeeMeta {
+                    vault: Pubkey::new_unique(),
+                    vault_fee_wallet: Pubkey::new_unique(),
+                    stake_weight: 1,
+                }],
+            })
+            .collect(),
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+fn main() {
+    let stake_meta_collection = stake_meta_collection();
+    let ncn_fee_meta = ncn_fee_meta();
+
+    let (serial, serial_elapsed) = time(|| {
+        GeneratedMerkleTreeCollection::new_from_stake_meta_collection_with_fees(
+            stake_meta_collection.clone(),
+            &ncn_fee_meta,
+        )
+        .unwrap()
+    });
+    let (parallel, parallel_elapsed) = time(|| {
+        GeneratedMerkleTreeCollection::new_from_stake_meta_collection_parallel(
+            stake_meta_collection,
+            Some(&ncn_fee_meta),
+            false,
+        )
+        .unwrap()
+    });
+
+    assert_eq!(parallel, serial, "parallel output differs from serial");
+
+    println!(
+        "{} validators x {} delegations",
+        NUM_VALIDATORS, DELEGATIONS_PER_VALIDATOR
+    );
+    println!("serial:   {:?}", serial_elapsed);
+    println!(
+        "parallel: {:?} ({:.1}x)",
+        parallel_elapsed,
+        serial_elapsed.as_secs_f64() / parallel_elapsed.as_secs_f64()
+    );
+}
@@ -0,0 +1,37 @@
+//! Writes the meta merkle tree and generated merkle tree test vectors as JSON, to
+//! `meta_merkle_tree/test_vectors/` unless a directory is given.
+use std::{
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use meta_merkle_tree::test_vectors::{
+    generate_generated_merkle_tree_test_vectors, generate_test_vectors,
+};
+use serde::Serialize;
+
+fn main() {
+    let dir = std::env::args().nth(1).map_or_else(
+        || PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_vectors"),
+        PathBuf::from,
+    );
+
+    write_vectors(
+        &dir.join("meta_merkle_tree.json"),
+        &generate_test_vectors().expect("generate test vectors"),
+    );
+    write_vectors(
+        &dir.join("generated_merkle_tree.json"),
+        &generate_generated_merkle_tree_test_vectors().expect("generate test vectors"),
+    );
+}
+
+fn write_vectors<T: Serialize>(path: &Path, vectors: &[T]) {
+    let json = serde_json::to_string_pretty(vectors).expect("serialize test vectors");
+
+    let mut file = File::create(path).expect("create test vectors file");
+    writeln!(file, "{}", json).expect("write test vectors");
+
+    println!("wrote {} test vectors to {}", vectors.len(), path.display());
+}
@@ -0,0 +1,553 @@
+//! Versioned binary encoding of [`MetaMerkleTree`] and [`GeneratedMerkleTreeCollection`]
+//!
+//! Every file starts with a 4 byte magic and a little-endian `u16` version, followed by the
+//! borsh encoded body and the sha256 hash of everything before it. Generated merkle trees are
+//! written and read one at a time, so a collection never has to be buffered in full.
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::PathBuf,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{de::DeserializeOwned, Serialize};
+use solana_program::{
+    hash::{Hash, Hasher, HASH_BYTES},
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::MerkleTreeError,
+    generated_merkle_tree::{GeneratedMerkleTree, GeneratedMerkleTreeCollection},
+    meta_merkle_tree::{MetaMerkleTree, Result},
+    tree_node::TreeNode,
+};
+
+pub const META_MERKLE_TREE_MAGIC: [u8; 4] = *b"JMMT";
+pub const GENERATED_MERKLE_TREE_COLLECTION_MAGIC: [u8; 4] = *b"JGMC";
+pub const BINARY_FORMAT_VERSION: u16 = 1;
+
+/// Types with a binary encoding, and the file helpers built on it
+pub trait BinaryFormat: Sized {
+    fn write_binary<W: Write>(&self, writer: W) -> Result<()>;
+
+    fn read_binary<R: Read>(reader: R) -> Result<Self>;
+
+    fn write_to_binary_file(&self, path: &PathBuf) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_binary(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn new_from_binary_file(path: &PathBuf) -> Result<Self> {
+        Self::read_binary(BufReader::new(File::open(path)?))
+    }
+}
+
+/// Converts a JSON encoded `T` to its binary encoding
+pub fn json_to_binary<T, R, W>(json: R, binary: W) -> Result<()>
+where
+    T: BinaryFormat + DeserializeOwned,
+    R: Read,
+    W: Write,
+{
+    let value: T = serde_json::from_reader(json)?;
+    value.write_binary(binary)
+}
+
+/// Converts a binary encoded `T` to the same pretty JSON `write_to_file` produces
+pub fn binary_to_json<T, R, W>(binary: R, json: W) -> Result<()>
+where
+    T: BinaryFormat + Serialize,
+    R: Read,
+    W: Write,
+{
+    let value = T::read_binary(binary)?;
+    serde_json::to_writer_pretty(json, &value)?;
+    Ok(())
+}
+
+impl BinaryFormat for MetaMerkleTree {
+    fn write_binary<W: Write>(&self, writer: W) -> Result<()> {
+        let mut writer = HashingWriter::new(writer, META_MERKLE_TREE_MAGIC)?;
+        self.merkle_root.serialize(&mut writer)?;
+        self.num_nodes.serialize(&mut writer)?;
+        self.tree_nodes().serialize(&mut writer)?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    fn read_binary<R: Read>(reader: R) -> Result<Self> {
+        let mut reader = HashingReader::new(reader, META_MERKLE_TREE_MAGIC)?;
+        let merkle_root = <[u8; 32]>::deserialize_reader(&mut reader)?;
+        let num_nodes = u64::deserialize_reader(&mut reader)?;
+        let tree_nodes = Vec::<TreeNode>::deserialize_reader(&mut reader)?;
+        reader.finish()?;
+
+        Ok(Self::from_parts(merkle_root, num_nodes, tree_nodes))
+    }
+}
+
+impl BinaryFormat for GeneratedMerkleTreeCollection {
+    fn write_binary<W: Write>(&self, writer: W) -> Result<()> {
+        let mut writer = GeneratedMerkleTreeCollectionWriter::new(
+            writer,
+            &self.bank_hash,
+            self.epoch,
+            self.slot,
+            self.generated_merkle_trees.len() as u64,
+        )?;
+        for generated_merkle_tree in self.generated_merkle_trees.iter() {
+            writer.write_tree(generated_merkle_tree)?;
+        }
+        writer.finish()?;
+        Ok(())
+    }
+
+    fn read_binary<R: Read>(reader: R) -> Result<Self> {
+        let mut reader = GeneratedMerkleTreeCollectionReader::new(reader)?;
+        let generated_merkle_trees = reader.by_ref().collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            generated_merkle_trees,
+            bank_hash: reader.bank_hash,
+            epoch: reader.epoch,
+            slot: reader.slot,
+        })
+    }
+}
+
+/// Writes a [`GeneratedMerkleTreeCollection`] one [`GeneratedMerkleTree`] at a time
+pub struct GeneratedMerkleTreeCollectionWriter<W: Write> {
+    writer: HashingWriter<W>,
+    num_trees: u64,
+    trees_written: u64,
+}
+
+impl<W: Write> GeneratedMerkleTreeCollectionWriter<W> {
+    pub fn new(writer: W, bank_hash: &str, epoch: u64, slot: u64, num_trees: u64) -> Result<Self> {
+        let mut writer = HashingWriter::new(writer, GENERATED_MERKLE_TREE_COLLECTION_MAGIC)?;
+        bank_hash.serialize(&mut writer)?;
+        epoch.serialize(&mut writer)?;
+        slot.serialize(&mut writer)?;
+        num_trees.serialize(&mut writer)?;
+
+        Ok(Self {
+            writer,
+            num_trees,
+            trees_written: 0,
+        })
+    }
+
+    pub fn write_tree(&mut self, generated_merkle_tree: &GeneratedMerkleTree) -> Result<()> {
+        if self.trees_written == self.num_trees {
+            return Err(MerkleTreeError::TreeCountMismatch {
+                expected: self.num_trees,
+                actual: self.trees_written.saturating_add(1),
+            });
+        }
+
+        generated_merkle_tree
+            .tip_distribution_account
+            .serialize(&mut self.writer)?;
+        generated_merkle_tree
+            .merkle_root_upload_authority
+            .serialize(&mut self.writer)?;
+        generated_merkle_tree
+            .merkle_root
+            .to_bytes()
+            .serialize(&mut self.writer)?;
+        generated_merkle_tree
+            .tree_nodes
+            .serialize(&mut self.writer)?;
+        generated_merkle_tree
+            .max_total_claim
+            .serialize(&mut self.writer)?;
+        generated_merkle_tree
+            .max_num_nodes
+            .serialize(&mut self.writer)?;
+
+        self.trees_written = self
+            .trees_written
+            .checked_add(1)
+            .ok_or(MerkleTreeError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Writes the checksum once every announced tree has been written
+    pub fn finish(self) -> Result<W> {
+        if self.trees_written != self.num_trees {
+            return Err(MerkleTreeError::TreeCountMismatch {
+                expected: self.num_trees,
+                actual: self.trees_written,
+            });
+        }
+
+        self.writer.finish()
+    }
+}
+
+/// Iterates over the [`GeneratedMerkleTree`]s of a binary encoded collection. The checksum
+/// is verified along with the last tree, which is returned as an error if it does not match.
+pub struct GeneratedMerkleTreeCollectionReader<R: Read> {
+    reader: Option<HashingReader<R>>,
+    bank_hash: String,
+    epoch: u64,
+    slot: u64,
+    num_trees: u64,
+    trees_read: u64,
+}
+
+impl<R: Read> GeneratedMerkleTreeCollectionReader<R> {
+    pub fn new(reader: R) -> Result<Self> {
+        let mut reader = HashingReader::new(reader, GENERATED_MERKLE_TREE_COLLECTION_MAGIC)?;
+        let bank_hash = String::deserialize_reader(&mut reader)?;
+        let epoch = u64::deserialize_reader(&mut reader)?;
+        let slot = u64::deserialize_reader(&mut reader)?;
+        let num_trees = u64::deserialize_reader(&mut reader)?;
+
+        let reader = if num_trees == 0 {
+            reader.finish()?;
+            None
+        } else {
+            Some(reader)
+        };
+
+        Ok(Self {
+            reader,
+            bank_hash,
+            epoch,
+            slot,
+            num_trees,
+            trees_read: 0,
+        })
+    }
+
+    pub fn bank_hash(&self) -> &str {
+        &self.bank_hash
+    }
+
+    pub const fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    pub const fn slot(&self) -> u64 {
+        self.slot
+    }
+
+    pub const fn num_trees(&self) -> u64 {
+        self.num_trees
+    }
+
+    fn read_tree(&mut self) -> Result<Option<GeneratedMerkleTree>> {
+        let Some(reader) = self.reader.as_mut() else {
+            return Ok(None);
+        };
+
+        let generated_merkle_tree = GeneratedMerkleTree {
+            tip_distribution_account: Pubkey::deserialize_reader(reader)?,
+            merkle_root_upload_authority: Pubkey::deserialize_reader(reader)?,
+            merkle_root: Hash::new_from_array(<[u8; 32]>::deserialize_reader(reader)?),
+            tree_nodes: Vec::deserialize_reader(reader)?,
+            max_total_claim: u64::deserialize_reader(reader)?,
+            max_num_nodes: u64::deserialize_reader(reader)?,
+        };
+
+        self.trees_read = self
+            .trees_read
+            .checked_add(1)
+            .ok_or(MerkleTreeError::ArithmeticOverflow)?;
+        if self.trees_read == self.num_trees {
+            if let Some(reader) = self.reader.take() {
+                reader.finish()?;
+            }
+        }
+
+        Ok(Some(generated_merkle_tree))
+    }
+}
+
+impl<R: Read> Iterator for GeneratedMerkleTreeCollectionReader<R> {
+    type Item = Result<GeneratedMerkleTree>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_tree() {
+            Ok(maybe_tree) => maybe_tree.map(Ok),
+            Err(e) => {
+                // Nothing after a failed read can be trusted
+                self.reader = None;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Writes the header and hashes every byte written after it
+struct HashingWriter<W: Write> {
+    inner: W,
+    hasher: Hasher,
+}
+
+impl<W: Write> HashingWriter<W> {
+    fn new(inner: W, magic: [u8; 4]) -> Result<Self> {
+        let mut writer = Self {
+            inner,
+            hasher: Hasher::default(),
+        };
+        writer.write_all(&magic)?;
+        writer.write_all(&BINARY_FORMAT_VERSION.to_le_bytes())?;
+        Ok(writer)
+    }
+
+    fn finish(mut self) -> Result<W> {
+        let checksum = self.hasher.result();
+        self.inner.write_all(checksum.as_ref())?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.hash(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Checks the header and hashes every byte read after it
+struct HashingReader<R: Read> {
+    inner: R,
+    hasher: Hasher,
+}
+
+impl<R: Read> HashingReader<R> {
+    fn new(inner: R, magic: [u8; 4]) -> Result<Self> {
+        let mut reader = Self {
+            inner,
+            hasher: Hasher::default(),
+        };
+
+        let mut header_magic = [0; 4];
+        reader.read_exact(&mut header_magic)?;
+        if header_magic != magic {
+            return Err(MerkleTreeError::InvalidMagic);
+        }
+
+        let mut version = [0; 2];
+        reader.read_exact(&mut version)?;
+        let version = u16::from_le_bytes(version);
+        if version != BINARY_FORMAT_VERSION {
+            return Err(MerkleTreeError::UnsupportedVersion(version));
+        }
+
+        Ok(reader)
+    }
+
+    fn finish(mut self) -> Result<R> {
+        let mut checksum = [0; HASH_BYTES];
+        self.inner.read_exact(&mut checksum)?;
+        if self.hasher.result().to_bytes() != checksum {
+            return Err(MerkleTreeError::ChecksumMismatch);
+        }
+        Ok(self.inner)
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.hash(&buf[..read]);
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated_merkle_tree;
+
+    fn test_meta_merkle_tree() -> MetaMerkleTree {
+        let tree_nodes = (0..5)
+            .map(|i| TreeNode::new(Pubkey::new_unique(), [i; 32], i as u64, i as u64 + 1))
+            .collect();
+        MetaMerkleTree::new(tree_nodes).unwrap()
+    }
+
+    fn test_generated_merkle_tree_collection(num_trees: usize) -> GeneratedMerkleTreeCollection {
+        let generated_merkle_trees = (0..num_trees)
+            .map(|i| GeneratedMerkleTree {
+                tip_distribution_account: Pubkey::new_unique(),
+                merkle_root_upload_authority: Pubkey::new_unique(),
+                merkle_root: Hash::new_unique(),
+                tree_nodes: vec![generated_merkle_tree::TreeNode {
+                    claimant: Pubkey::new_unique(),
+                    claim_status_pubkey: Pubkey::new_unique(),
+                    claim_status_bump: i as u8,
+                    staker_pubkey: Pubkey::new_unique(),
+                    withdrawer_pubkey: Pubkey::new_unique(),
+                    amount: i as u64 * 100,
+                    proof: Some(vec![[i as u8; 32], [u8::MAX; 32]]),
+                }],
+                max_total_claim: i as u64 * 100,
+                max_num_nodes: 1,
+            })
+            .collect();
+
+        GeneratedMerkleTreeCollection {
+            generated_merkle_trees,
+            bank_hash: Hash::new_unique().to_string(),
+            epoch: 600,
+            slot: 259_200_000,
+        }
+    }
+
+    #[test]
+    fn test_meta_merkle_tree_roundtrip() {
+        let tree = test_meta_merkle_tree();
+
+        let mut binary = Vec::new();
+        tree.write_binary(&mut binary).unwrap();
+        let read = MetaMerkleTree::read_binary(binary.as_slice()).unwrap();
+
+        assert_eq!(read.merkle_root, tree.merkle_root);
+        assert_eq!(read.num_nodes, tree.num_nodes);
+        assert_eq!(read.tree_nodes(), tree.tree_nodes());
+        for node in tree.tree_nodes().iter() {
+            assert_eq!(
+                read.try_get_node(&node.tip_distribution_account).unwrap(),
+                node
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_merkle_tree_collection_roundtrip() {
+        for num_trees in [0, 1, 3] {
+            let collection = test_generated_merkle_tree_collection(num_trees);
+
+            let mut binary = Vec::new();
+            collection.write_binary(&mut binary).unwrap();
+            let read = GeneratedMerkleTreeCollection::read_binary(binary.as_slice()).unwrap();
+
+            assert_eq!(read, collection);
+        }
+    }
+
+    #[test]
+    fn test_streaming_reader() {
+        let collection = test_generated_merkle_tree_collection(3);
+
+        let mut binary = Vec::new();
+        collection.write_binary(&mut binary).unwrap();
+
+        let mut reader = GeneratedMerkleTreeCollectionReader::new(binary.as_slice()).unwrap();
+        assert_eq!(reader.bank_hash(), collection.bank_hash);
+        assert_eq!(reader.epoch(), collection.epoch);
+        assert_eq!(reader.slot(), collection.slot);
+        assert_eq!(reader.num_trees(), 3);
+
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            collection.generated_merkle_trees[0]
+        );
+        assert_eq!(reader.count(), 2);
+    }
+
+    #[test]
+    fn test_streaming_writer_tree_count() {
+        let collection = test_generated_merkle_tree_collection(2);
+
+        let mut writer = GeneratedMerkleTreeCollectionWriter::new(Vec::new(), "", 0, 0, 1).unwrap();
+        writer
+            .write_tree(&collection.generated_merkle_trees[0])
+            .unwrap();
+        assert!(matches!(
+            writer.write_tree(&collection.generated_merkle_trees[1]),
+            Err(MerkleTreeError::TreeCountMismatch {
+                expected: 1,
+                actual: 2
+            })
+        ));
+
+        let writer = GeneratedMerkleTreeCollectionWriter::new(Vec::new(), "", 0, 0, 1).unwrap();
+        assert!(matches!(
+            writer.finish(),
+            Err(MerkleTreeError::TreeCountMismatch {
+                expected: 1,
+                actual: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn test_corrupted_binary() {
+        let collection = test_generated_merkle_tree_collection(2);
+        let mut binary = Vec::new();
+        collection.write_binary(&mut binary).unwrap();
+
+        // Flip a byte of the last tree's max_num_nodes
+        let mut corrupted = binary.clone();
+        let index = corrupted.len() - HASH_BYTES - 1;
+        corrupted[index] ^= 1;
+        assert!(matches!(
+            GeneratedMerkleTreeCollection::read_binary(corrupted.as_slice()),
+            Err(MerkleTreeError::ChecksumMismatch)
+        ));
+
+        let mut wrong_magic = binary.clone();
+        wrong_magic[0] = b'X';
+        assert!(matches!(
+            GeneratedMerkleTreeCollection::read_binary(wrong_magic.as_slice()),
+            Err(MerkleTreeError::InvalidMagic)
+        ));
+        assert!(matches!(
+            MetaMerkleTree::read_binary(binary.as_slice()),
+            Err(MerkleTreeError::InvalidMagic)
+        ));
+
+        let mut wrong_version = binary.clone();
+        wrong_version[4] = 2;
+        assert!(matches!(
+            GeneratedMerkleTreeCollection::read_binary(wrong_version.as_slice()),
+            Err(MerkleTreeError::UnsupportedVersion(2))
+        ));
+
+        let truncated = &binary[..binary.len() - 1];
+        assert!(matches!(
+            GeneratedMerkleTreeCollection::read_binary(truncated),
+            Err(MerkleTreeError::IoError(_))
+        ));
+    }
+
+    #[test]
+    fn test_json_conversion_is_lossless() {
+        let collection = test_generated_merkle_tree_collection(3);
+        let json = serde_json::to_vec_pretty(&collection).unwrap();
+
+        let mut binary = Vec::new();
+        json_to_binary::<GeneratedMerkleTreeCollection, _, _>(json.as_slice(), &mut binary)
+            .unwrap();
+        let mut converted_json = Vec::new();
+        binary_to_json::<GeneratedMerkleTreeCollection, _, _>(
+            binary.as_slice(),
+            &mut converted_json,
+        )
+        .unwrap();
+        assert_eq!(converted_json, json);
+
+        let tree = test_meta_merkle_tree();
+        let json = serde_json::to_vec_pretty(&tree).unwrap();
+
+        let mut binary = Vec::new();
+        json_to_binary::<MetaMerkleTree, _, _>(json.as_slice(), &mut binary).unwrap();
+        let mut converted_json = Vec::new();
+        binary_to_json::<MetaMerkleTree, _, _>(binary.as_slice(), &mut converted_json).unwrap();
+        assert_eq!(converted_json, json);
+    }
+}
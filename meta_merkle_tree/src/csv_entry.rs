@@ -0,0 +1,415 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    error::MerkleTreeError,
+    meta_merkle_tree::{MetaMerkleTree, Result},
+    tree_node::TreeNode,
+};
+
+pub const CSV_HEADER: [&str; 4] = [
+    "tip_distribution_account",
+    "validator_merkle_root",
+    "max_total_claim",
+    "max_num_nodes",
+];
+
+/// Column written after [`CSV_HEADER`] on export. Optional on import, where each proof is checked
+/// against the one regenerated from the rows.
+pub const CSV_PROOF_COLUMN: &str = "proof";
+
+/// Separates the hex encoded hashes of a proof within its column
+pub const CSV_PROOF_SEPARATOR: char = ';';
+
+/// One row of a meta merkle tree CSV, the inputs of a [`TreeNode`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvEntry {
+    pub tip_distribution_account: Pubkey,
+    pub validator_merkle_root: [u8; 32],
+    pub max_total_claim: u64,
+    pub max_num_nodes: u64,
+    /// Proof from the optional [`CSV_PROOF_COLUMN`]
+    pub proof: Option<Vec<[u8; 32]>>,
+}
+
+impl CsvEntry {
+    pub fn new_from_file(path: &PathBuf) -> Result<Vec<Self>> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads every row, rejecting the whole file on the first malformed or duplicate one.
+    /// Line numbers in errors are 1-indexed and count the header.
+    pub fn read<R: BufRead>(reader: R) -> Result<Vec<Self>> {
+        Ok(Self::read_numbered(reader)?
+            .into_iter()
+            .map(|(_, entry)| entry)
+            .collect())
+    }
+
+    /// Like [`Self::read`], keeping the line number of each entry
+    fn read_numbered<R: BufRead>(reader: R) -> Result<Vec<(usize, Self)>> {
+        let mut lines = reader.lines().enumerate();
+
+        let has_proof_column = match lines.next() {
+            Some((_, line)) => Self::parse_header(&line?)?,
+            None => return Err(csv_error(1, "missing header".to_string())),
+        };
+        let num_columns = CSV_HEADER.len() + usize::from(has_proof_column);
+
+        let mut entries = Vec::new();
+        let mut seen: HashMap<Pubkey, usize> = HashMap::new();
+
+        for (i, line) in lines {
+            let line_number = i + 1;
+            let line = line?;
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() {
+                continue;
+            }
+
+            let columns: Vec<&str> = line.split(',').map(str::trim).collect();
+            if columns.len() != num_columns {
+                return Err(csv_error(
+                    line_number,
+                    format!("expected {} columns, got {}", num_columns, columns.len()),
+                ));
+            }
+
+            let entry = Self::parse_row(&columns).map_err(|e| csv_error(line_number, e))?;
+
+            if let Some(first_line) = seen.insert(entry.tip_distribution_account, line_number) {
+                return Err(csv_error(
+                    line_number,
+                    format!(
+                        "duplicate tip distribution account {}, first seen on line {}",
+                        entry.tip_distribution_account, first_line
+                    ),
+                ));
+            }
+
+            entries.push((line_number, entry));
+        }
+
+        Ok(entries)
+    }
+
+    /// Whether the header has the export's trailing proof column
+    fn parse_header(line: &str) -> Result<bool> {
+        let columns: Vec<&str> = line
+            .trim_end_matches('\r')
+            .split(',')
+            .map(str::trim)
+            .collect();
+
+        if columns == CSV_HEADER {
+            return Ok(false);
+        }
+        if columns.len() == CSV_HEADER.len() + 1
+            && columns[..CSV_HEADER.len()] == CSV_HEADER
+            && columns[CSV_HEADER.len()] == CSV_PROOF_COLUMN
+        {
+            return Ok(true);
+        }
+
+        Err(csv_error(
+            1,
+            format!("expected header {}", CSV_HEADER.join(",")),
+        ))
+    }
+
+    fn parse_row(columns: &[&str]) -> std::result::Result<Self, String> {
+        let tip_distribution_account = Pubkey::from_str(columns[0])
+            .map_err(|_| format!("invalid tip_distribution_account {:?}", columns[0]))?;
+        let validator_merkle_root =
+            parse_hash(columns[1]).map_err(|e| format!("invalid validator_merkle_root: {}", e))?;
+        let max_total_claim = columns[2]
+            .parse()
+            .map_err(|_| format!("invalid max_total_claim {:?}", columns[2]))?;
+        let max_num_nodes = columns[3]
+            .parse()
+            .map_err(|_| format!("invalid max_num_nodes {:?}", columns[3]))?;
+        let proof = columns
+            .get(CSV_HEADER.len())
+            .map(|column| parse_proof(column))
+            .transpose()
+            .map_err(|e| format!("invalid proof: {}", e))?;
+
+        Ok(Self {
+            tip_distribution_account,
+            validator_merkle_root,
+            max_total_claim,
+            max_num_nodes,
+            proof,
+        })
+    }
+}
+
+impl From<CsvEntry> for TreeNode {
+    fn from(entry: CsvEntry) -> Self {
+        Self::new(
+            entry.tip_distribution_account,
+            entry.validator_merkle_root,
+            entry.max_total_claim,
+            entry.max_num_nodes,
+        )
+    }
+}
+
+impl MetaMerkleTree {
+    /// Load a merkle tree from a csv path
+    pub fn new_from_csv(path: &PathBuf) -> Result<Self> {
+        Self::read_csv(BufReader::new(File::open(path)?))
+    }
+
+    /// Builds a merkle tree from csv rows, rejecting rows whose proof column does not match the
+    /// regenerated proof
+    pub fn read_csv<R: BufRead>(reader: R) -> Result<Self> {
+        let csv_entries = CsvEntry::read_numbered(reader)?;
+        let tree = Self::new(
+            csv_entries
+                .iter()
+                .map(|(_, entry)| TreeNode::from(entry.clone()))
+                .collect(),
+        )?;
+
+        for (line_number, entry) in csv_entries.iter() {
+            let Some(proof) = entry.proof.as_ref() else {
+                continue;
+            };
+            if tree.proof_for(&entry.tip_distribution_account)? != proof.as_slice() {
+                return Err(csv_error(
+                    *line_number,
+                    format!(
+                        "proof for tip distribution account {} does not match the regenerated tree",
+                        entry.tip_distribution_account
+                    ),
+                ));
+            }
+        }
+
+        Ok(tree)
+    }
+
+    /// Writes one row per node, in tree order, with its proof as hex encoded hashes
+    pub fn write_to_csv<W: Write>(&self, mut writer: W) -> Result<()> {
+        writeln!(writer, "{},{}", CSV_HEADER.join(","), CSV_PROOF_COLUMN)?;
+
+        for node in self.tree_nodes().iter() {
+            let proof = node
+                .proof
+                .as_ref()
+                .ok_or(MerkleTreeError::MissingProof(node.tip_distribution_account))?
+                .iter()
+                .map(hex::encode)
+                .collect::<Vec<String>>()
+                .join(&CSV_PROOF_SEPARATOR.to_string());
+
+            writeln!(
+                writer,
+                "{},{},{},{},{}",
+                node.tip_distribution_account,
+                hex::encode(node.validator_merkle_root),
+                node.max_total_claim,
+                node.max_num_nodes,
+                proof
+            )?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    pub fn write_to_csv_file(&self, path: &PathBuf) -> Result<()> {
+        self.write_to_csv(BufWriter::new(File::create(path)?))
+    }
+}
+
+fn parse_hash(value: &str) -> std::result::Result<[u8; 32], String> {
+    let bytes = hex::decode(value).map_err(|e| format!("{:?} is not hex: {}", value, e))?;
+    bytes
+        .try_into()
+        .map_err(|bytes: Vec<u8>| format!("expected 32 bytes, got {}", bytes.len()))
+}
+
+/// An empty column is the empty proof of a single node tree
+fn parse_proof(value: &str) -> std::result::Result<Vec<[u8; 32]>, String> {
+    if value.is_empty() {
+        return Ok(Vec::new());
+    }
+    value.split(CSV_PROOF_SEPARATOR).map(parse_hash).collect()
+}
+
+fn csv_error(line: usize, message: String) -> MerkleTreeError {
+    MerkleTreeError::CsvError { line, message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_csv(rows: &[String]) -> String {
+        let mut csv = CSV_HEADER.join(",");
+        for row in rows {
+            csv.push('\n');
+            csv.push_str(row);
+        }
+        csv
+    }
+
+    fn row(tip_distribution_account: &Pubkey, root: u8, max_total_claim: u64) -> String {
+        format!(
+            "{},{},{},10",
+            tip_distribution_account,
+            hex::encode([root; 32]),
+            max_total_claim
+        )
+    }
+
+    fn assert_csv_error(csv: &str, expected_line: usize) {
+        match CsvEntry::read(csv.as_bytes()) {
+            Err(MerkleTreeError::CsvError { line, .. }) => assert_eq!(line, expected_line),
+            result => panic!("expected a CSV error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_read_csv() {
+        let pubkey1 = Pubkey::new_unique();
+        let pubkey2 = Pubkey::new_unique();
+        let csv = test_csv(&[row(&pubkey1, 1, 100), row(&pubkey2, 2, 200), String::new()]);
+
+        let entries = CsvEntry::read(csv.as_bytes()).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                CsvEntry {
+                    tip_distribution_account: pubkey1,
+                    validator_merkle_root: [1; 32],
+                    max_total_claim: 100,
+                    max_num_nodes: 10,
+                    proof: None,
+                },
+                CsvEntry {
+                    tip_distribution_account: pubkey2,
+                    validator_merkle_root: [2; 32],
+                    max_total_claim: 200,
+                    max_num_nodes: 10,
+                    proof: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_csv_errors() {
+        let pubkey = Pubkey::new_unique();
+
+        assert_csv_error("", 1);
+        assert_csv_error("tip_distribution_account,max_total_claim", 1);
+
+        // Line numbers count the header
+        assert_csv_error(
+            &test_csv(&[
+                row(&pubkey, 1, 100),
+                format!("{},00,1,1", Pubkey::new_unique()),
+            ]),
+            3,
+        );
+        assert_csv_error(
+            &test_csv(&[format!("not-a-pubkey,{},1,1", "00".repeat(32))]),
+            2,
+        );
+        assert_csv_error(
+            &test_csv(&[format!("{},{},-1,1", pubkey, "00".repeat(32))]),
+            2,
+        );
+        assert_csv_error(&test_csv(&[format!("{},{},1", pubkey, "00".repeat(32))]), 2);
+        assert_csv_error(&test_csv(&[row(&pubkey, 1, 100), row(&pubkey, 2, 200)]), 3);
+
+        let header = format!("{},{}", CSV_HEADER.join(","), CSV_PROOF_COLUMN);
+        assert_csv_error(&format!("{}\n{},00", header, row(&pubkey, 1, 100)), 2);
+        assert_csv_error(
+            &format!(
+                "{}\n{},{};zz",
+                header,
+                row(&pubkey, 1, 100),
+                "00".repeat(32)
+            ),
+            2,
+        );
+    }
+
+    #[test]
+    fn test_csv_roundtrip() {
+        let tree_nodes = (0..4)
+            .map(|i| TreeNode::new(Pubkey::new_unique(), [i; 32], i as u64 * 100, 10))
+            .collect();
+        let tree = MetaMerkleTree::new(tree_nodes).unwrap();
+
+        let mut csv = Vec::new();
+        tree.write_to_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+
+        let first_row: Vec<&str> = csv.lines().nth(1).unwrap().split(',').collect();
+        let proof = tree.tree_nodes()[0].proof.as_ref().unwrap();
+        assert_eq!(
+            first_row[4],
+            proof
+                .iter()
+                .map(hex::encode)
+                .collect::<Vec<String>>()
+                .join(";")
+        );
+
+        // Exports can be imported again, their proofs match the regenerated ones
+        let entries = CsvEntry::read(csv.as_bytes()).unwrap();
+        assert_eq!(entries[0].proof.as_ref(), Some(proof));
+        let read_tree = MetaMerkleTree::read_csv(csv.as_bytes()).unwrap();
+        assert_eq!(read_tree.merkle_root, tree.merkle_root);
+        assert_eq!(read_tree.tree_nodes(), tree.tree_nodes());
+    }
+
+    #[test]
+    fn test_read_csv_proof_mismatch() {
+        let tree_nodes = (0..4)
+            .map(|i| TreeNode::new(Pubkey::new_unique(), [i; 32], i as u64 * 100, 10))
+            .collect();
+        let tree = MetaMerkleTree::new(tree_nodes).unwrap();
+
+        let mut csv = Vec::new();
+        tree.write_to_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+
+        // Swap the proofs of the second and third rows
+        let mut rows: Vec<Vec<&str>> = csv.lines().map(|l| l.split(',').collect()).collect();
+        let second_proof = rows[2][4];
+        rows[2][4] = rows[3][4];
+        rows[3][4] = second_proof;
+        let tampered = rows
+            .iter()
+            .map(|columns| columns.join(","))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        match MetaMerkleTree::read_csv(tampered.as_bytes()) {
+            Err(MerkleTreeError::CsvError { line, .. }) => assert_eq!(line, 3),
+            result => panic!("expected a CSV error, got {:?}", result),
+        }
+
+        // Rows without a proof column are not checked
+        let without_proofs = rows
+            .iter()
+            .map(|columns| columns[..CSV_HEADER.len()].join(","))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let read_tree = MetaMerkleTree::read_csv(without_proofs.as_bytes()).unwrap();
+        assert_eq!(read_tree.merkle_root, tree.merkle_root);
+    }
+}
@@ -0,0 +1,464 @@
+//! Differences between two [`MetaMerkleTree`]s or two [`GeneratedMerkleTreeCollection`]s,
+//! used to find out why operators voted for different meta merkle roots
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    generated_merkle_tree::{GeneratedMerkleTree, GeneratedMerkleTreeCollection},
+    meta_merkle_tree::MetaMerkleTree,
+    tree_node::TreeNode,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeDifference {
+    MerkleRoot {
+        expected: [u8; 32],
+        actual: [u8; 32],
+    },
+    Epoch {
+        expected: u64,
+        actual: u64,
+    },
+    MissingTipDistributionAccount {
+        tip_distribution_account: Pubkey,
+    },
+    ExtraTipDistributionAccount {
+        tip_distribution_account: Pubkey,
+    },
+    ValidatorMerkleRoot {
+        tip_distribution_account: Pubkey,
+        expected: [u8; 32],
+        actual: [u8; 32],
+    },
+    MaxTotalClaim {
+        tip_distribution_account: Pubkey,
+        expected: u64,
+        actual: u64,
+    },
+    MaxNumNodes {
+        tip_distribution_account: Pubkey,
+        expected: u64,
+        actual: u64,
+    },
+    MissingClaimant {
+        tip_distribution_account: Pubkey,
+        claimant: Pubkey,
+    },
+    ExtraClaimant {
+        tip_distribution_account: Pubkey,
+        claimant: Pubkey,
+    },
+    ClaimantAmount {
+        tip_distribution_account: Pubkey,
+        claimant: Pubkey,
+        expected: u64,
+        actual: u64,
+    },
+}
+
+impl fmt::Display for TreeDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MerkleRoot { expected, actual } => write!(
+                f,
+                "merkle root expected {}, got {}",
+                hex::encode(expected),
+                hex::encode(actual)
+            ),
+            Self::Epoch { expected, actual } => {
+                write!(f, "epoch expected {expected}, got {actual}")
+            }
+            Self::MissingTipDistributionAccount {
+                tip_distribution_account,
+            } => write!(f, "tip distribution account {tip_distribution_account}: missing"),
+            Self::ExtraTipDistributionAccount {
+                tip_distribution_account,
+            } => write!(f, "tip distribution account {tip_distribution_account}: extra"),
+            Self::ValidatorMerkleRoot {
+                tip_distribution_account,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "tip distribution account {tip_distribution_account}: merkle root expected {}, got {}",
+                hex::encode(expected),
+                hex::encode(actual)
+            ),
+            Self::MaxTotalClaim {
+                tip_distribution_account,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "tip distribution account {tip_distribution_account}: max_total_claim expected {expected}, got {actual}"
+            ),
+            Self::MaxNumNodes {
+                tip_distribution_account,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "tip distribution account {tip_distribution_account}: max_num_nodes expected {expected}, got {actual}"
+            ),
+            Self::MissingClaimant {
+                tip_distribution_account,
+                claimant,
+            } => write!(
+                f,
+                "tip distribution account {tip_distribution_account} claimant {claimant}: missing"
+            ),
+            Self::ExtraClaimant {
+                tip_distribution_account,
+                claimant,
+            } => write!(
+                f,
+                "tip distribution account {tip_distribution_account} claimant {claimant}: extra"
+            ),
+            Self::ClaimantAmount {
+                tip_distribution_account,
+                claimant,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "tip distribution account {tip_distribution_account} claimant {claimant}: amount expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+/// Differences of `actual` from `expected`. Tip distribution accounts only in `expected`
+/// are missing, those only in `actual` are extra.
+pub fn diff_meta_merkle_trees(
+    expected: &MetaMerkleTree,
+    actual: &MetaMerkleTree,
+) -> Vec<TreeDifference> {
+    let mut differences = Vec::new();
+
+    if expected.merkle_root != actual.merkle_root {
+        differences.push(TreeDifference::MerkleRoot {
+            expected: expected.merkle_root,
+            actual: actual.merkle_root,
+        });
+    }
+
+    let actual_nodes: HashMap<Pubkey, &TreeNode> = actual
+        .tree_nodes()
+        .iter()
+        .map(|node| (node.tip_distribution_account, node))
+        .collect();
+
+    for expected_node in expected.tree_nodes().iter() {
+        let tip_distribution_account = expected_node.tip_distribution_account;
+        let Some(actual_node) = actual_nodes.get(&tip_distribution_account) else {
+            differences.push(TreeDifference::MissingTipDistributionAccount {
+                tip_distribution_account,
+            });
+            continue;
+        };
+
+        diff_tip_distribution_account(
+            &mut differences,
+            tip_distribution_account,
+            (
+                expected_node.validator_merkle_root,
+                expected_node.max_total_claim,
+                expected_node.max_num_nodes,
+            ),
+            (
+                actual_node.validator_merkle_root,
+                actual_node.max_total_claim,
+                actual_node.max_num_nodes,
+            ),
+        );
+    }
+
+    push_extra_tip_distribution_accounts(
+        &mut differences,
+        expected
+            .tree_nodes()
+            .iter()
+            .map(|node| node.tip_distribution_account),
+        actual
+            .tree_nodes()
+            .iter()
+            .map(|node| node.tip_distribution_account),
+    );
+
+    differences
+}
+
+/// Same as [`diff_meta_merkle_trees`], down to the amount of every claimant
+pub fn diff_generated_merkle_tree_collections(
+    expected: &GeneratedMerkleTreeCollection,
+    actual: &GeneratedMerkleTreeCollection,
+) -> Vec<TreeDifference> {
+    let mut differences = Vec::new();
+
+    if expected.epoch != actual.epoch {
+        differences.push(TreeDifference::Epoch {
+            expected: expected.epoch,
+            actual: actual.epoch,
+        });
+    }
+
+    let actual_trees: HashMap<Pubkey, &GeneratedMerkleTree> = actual
+        .generated_merkle_trees
+        .iter()
+        .map(|tree| (tree.tip_distribution_account, tree))
+        .collect();
+
+    for expected_tree in expected.generated_merkle_trees.iter() {
+        let tip_distribution_account = expected_tree.tip_distribution_account;
+        let Some(actual_tree) = actual_trees.get(&tip_distribution_account) else {
+            differences.push(TreeDifference::MissingTipDistributionAccount {
+                tip_distribution_account,
+            });
+            continue;
+        };
+
+        diff_tip_distribution_account(
+            &mut differences,
+            tip_distribution_account,
+            (
+                expected_tree.merkle_root.to_bytes(),
+                expected_tree.max_total_claim,
+                expected_tree.max_num_nodes,
+            ),
+            (
+                actual_tree.merkle_root.to_bytes(),
+                actual_tree.max_total_claim,
+                actual_tree.max_num_nodes,
+            ),
+        );
+        diff_claimants(&mut differences, expected_tree, actual_tree);
+    }
+
+    push_extra_tip_distribution_accounts(
+        &mut differences,
+        expected
+            .generated_merkle_trees
+            .iter()
+            .map(|tree| tree.tip_distribution_account),
+        actual
+            .generated_merkle_trees
+            .iter()
+            .map(|tree| tree.tip_distribution_account),
+    );
+
+    differences
+}
+
+/// Compares the `(merkle root, max_total_claim, max_num_nodes)` of a tip distribution account
+fn diff_tip_distribution_account(
+    differences: &mut Vec<TreeDifference>,
+    tip_distribution_account: Pubkey,
+    expected: ([u8; 32], u64, u64),
+    actual: ([u8; 32], u64, u64),
+) {
+    if expected.0 != actual.0 {
+        differences.push(TreeDifference::ValidatorMerkleRoot {
+            tip_distribution_account,
+            expected: expected.0,
+            actual: actual.0,
+        });
+    }
+    if expected.1 != actual.1 {
+        differences.push(TreeDifference::MaxTotalClaim {
+            tip_distribution_account,
+            expected: expected.1,
+            actual: actual.1,
+        });
+    }
+    if expected.2 != actual.2 {
+        differences.push(TreeDifference::MaxNumNodes {
+            tip_distribution_account,
+            expected: expected.2,
+            actual: actual.2,
+        });
+    }
+}
+
+fn diff_claimants(
+    differences: &mut Vec<TreeDifference>,
+    expected: &GeneratedMerkleTree,
+    actual: &GeneratedMerkleTree,
+) {
+    let tip_distribution_account = expected.tip_distribution_account;
+    let actual_amounts: HashMap<Pubkey, u64> = actual
+        .tree_nodes
+        .iter()
+        .map(|node| (node.claimant, node.amount))
+        .collect();
+
+    for expected_node in expected.tree_nodes.iter() {
+        match actual_amounts.get(&expected_node.claimant) {
+            None => differences.push(TreeDifference::MissingClaimant {
+                tip_distribution_account,
+                claimant: expected_node.claimant,
+            }),
+            Some(actual_amount) if *actual_amount != expected_node.amount => {
+                differences.push(TreeDifference::ClaimantAmount {
+                    tip_distribution_account,
+                    claimant: expected_node.claimant,
+                    expected: expected_node.amount,
+                    actual: *actual_amount,
+                })
+            }
+            Some(_) => {}
+        }
+    }
+
+    let expected_claimants: HashSet<Pubkey> = expected
+        .tree_nodes
+        .iter()
+        .map(|node| node.claimant)
+        .collect();
+    differences.extend(
+        actual
+            .tree_nodes
+            .iter()
+            .filter(|node| !expected_claimants.contains(&node.claimant))
+            .map(|node| TreeDifference::ExtraClaimant {
+                tip_distribution_account,
+                claimant: node.claimant,
+            }),
+    );
+}
+
+fn push_extra_tip_distribution_accounts(
+    differences: &mut Vec<TreeDifference>,
+    expected: impl Iterator<Item = Pubkey>,
+    actual: impl Iterator<Item = Pubkey>,
+) {
+    let expected: HashSet<Pubkey> = expected.collect();
+    differences.extend(actual.filter(|key| !expected.contains(key)).map(
+        |tip_distribution_account| TreeDifference::ExtraTipDistributionAccount {
+            tip_distribution_account,
+        },
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::hash::Hash;
+
+    use super::*;
+    use crate::generated_merkle_tree;
+
+    fn claim(claimant: Pubkey, amount: u64) -> generated_merkle_tree::TreeNode {
+        generated_merkle_tree::TreeNode {
+            claimant,
+            claim_status_pubkey: Pubkey::default(),
+            claim_status_bump: 0,
+            staker_pubkey: Pubkey::default(),
+            withdrawer_pubkey: Pubkey::default(),
+            amount,
+            proof: None,
+        }
+    }
+
+    #[test]
+    fn test_identical_trees() {
+        let tree = MetaMerkleTree::new(vec![
+            TreeNode::new(Pubkey::new_unique(), [1; 32], 10, 2),
+            TreeNode::new(Pubkey::new_unique(), [2; 32], 20, 3),
+        ])
+        .unwrap();
+
+        assert!(diff_meta_merkle_trees(&tree, &tree.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_meta_merkle_trees() {
+        let shared = Pubkey::new_unique();
+        let missing = Pubkey::new_unique();
+        let extra = Pubkey::new_unique();
+
+        let expected = MetaMerkleTree::new(vec![
+            TreeNode::new(shared, [1; 32], 10, 2),
+            TreeNode::new(missing, [2; 32], 20, 3),
+        ])
+        .unwrap();
+        let actual = MetaMerkleTree::new(vec![
+            TreeNode::new(shared, [3; 32], 11, 2),
+            TreeNode::new(extra, [2; 32], 20, 3),
+        ])
+        .unwrap();
+
+        let differences = diff_meta_merkle_trees(&expected, &actual);
+        assert_eq!(differences.len(), 5);
+        assert!(matches!(differences[0], TreeDifference::MerkleRoot { .. }));
+        assert!(differences.contains(&TreeDifference::ValidatorMerkleRoot {
+            tip_distribution_account: shared,
+            expected: [1; 32],
+            actual: [3; 32],
+        }));
+        assert!(differences.contains(&TreeDifference::MaxTotalClaim {
+            tip_distribution_account: shared,
+            expected: 10,
+            actual: 11,
+        }));
+        assert!(
+            differences.contains(&TreeDifference::MissingTipDistributionAccount {
+                tip_distribution_account: missing,
+            })
+        );
+        assert_eq!(
+            differences[4],
+            TreeDifference::ExtraTipDistributionAccount {
+                tip_distribution_account: extra,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diff_claimants() {
+        let tip_distribution_account = Pubkey::new_unique();
+        let shared = Pubkey::new_unique();
+        let missing = Pubkey::new_unique();
+        let extra = Pubkey::new_unique();
+
+        let collection =
+            |tree_nodes: Vec<generated_merkle_tree::TreeNode>| GeneratedMerkleTreeCollection {
+                generated_merkle_trees: vec![GeneratedMerkleTree {
+                    tip_distribution_account,
+                    merkle_root_upload_authority: Pubkey::default(),
+                    merkle_root: Hash::default(),
+                    max_num_nodes: tree_nodes.len() as u64,
+                    max_total_claim: 100,
+                    tree_nodes,
+                }],
+                bank_hash: String::default(),
+                epoch: 0,
+                slot: 0,
+            };
+
+        let expected = collection(vec![claim(shared, 60), claim(missing, 40)]);
+        let actual = collection(vec![claim(shared, 50), claim(extra, 50)]);
+
+        assert_eq!(
+            diff_generated_merkle_tree_collections(&expected, &actual),
+            vec![
+                TreeDifference::ClaimantAmount {
+                    tip_distribution_account,
+                    claimant: shared,
+                    expected: 60,
+                    actual: 50,
+                },
+                TreeDifference::MissingClaimant {
+                    tip_distribution_account,
+                    claimant: missing,
+                },
+                TreeDifference::ExtraClaimant {
+                    tip_distribution_account,
+                    claimant: extra,
+                },
+            ]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use solana_program::pubkey::Pubkey;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -12,4 +13,18 @@ pub enum MerkleTreeError {
     SerdeError(#[from] serde_json::Error),
     #[error("Arithmetic Overflow/Underflow")]
     ArithmeticOverflow,
+    #[error("Tip distribution account {0} not found in tree")]
+    NodeNotFound(Pubkey),
+    #[error("No proof for tip distribution account {0}")]
+    MissingProof(Pubkey),
+    #[error("Invalid binary format magic")]
+    InvalidMagic,
+    #[error("Unsupported binary format version {0}")]
+    UnsupportedVersion(u16),
+    #[error("Binary format checksum mismatch")]
+    ChecksumMismatch,
+    #[error("Expected {expected} generated merkle trees, got {actual}")]
+    TreeCountMismatch { expected: u64, actual: u64 },
+    #[error("CSV Error on line {line}: {message}")]
+    CsvError { line: usize, message: String },
 }
@@ -1,8 +1,11 @@
 // Mostly copied from modules in jito-solana/tip-distributor/src
 // To be replaced by tip distributor code in this repo
-use std::{fs::File, io::BufReader, path::PathBuf};
+use std::{collections::HashMap, fs::File, io::BufReader, path::PathBuf};
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use jito_tip_distribution_sdk::{jito_tip_distribution, CLAIM_STATUS_SEED};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use solana_program::{
     clock::{Epoch, Slot},
@@ -11,7 +14,7 @@ use solana_program::{
 };
 use thiserror::Error;
 
-use crate::{merkle_tree::MerkleTree, utils::get_proof};
+use crate::{merkle_tree::MerkleTree, stake_meta_validation::StakeMetaProblem, utils::get_proof};
 
 #[derive(Error, Debug)]
 pub enum MerkleRootGeneratorError {
@@ -20,9 +23,32 @@ pub enum MerkleRootGeneratorError {
 
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+
+    #[error("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    #[error("No lamports delegated to split rewards between")]
+    NoDelegatedStake,
+
+    #[error("File not found: {0}")]
+    FileNotFound(PathBuf),
+
+    #[error("Merkle tree of tip distribution account {0} has no root")]
+    EmptyTree(Pubkey),
+
+    #[error("Invalid stake meta collection: {}", format_problems(.0))]
+    InvalidStakeMetaCollection(Vec<StakeMetaProblem>),
+}
+
+fn format_problems(problems: &[StakeMetaProblem]) -> String {
+    problems
+        .iter()
+        .map(StakeMetaProblem::to_string)
+        .collect::<Vec<String>>()
+        .join("; ")
 }
 
-#[derive(Clone, Deserialize, Serialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq)]
 pub struct GeneratedMerkleTreeCollection {
     pub generated_merkle_trees: Vec<GeneratedMerkleTree>,
     pub bank_hash: String,
@@ -43,50 +69,84 @@ pub struct GeneratedMerkleTree {
 }
 
 impl GeneratedMerkleTreeCollection {
+    /// Fails with [`MerkleRootGeneratorError::InvalidStakeMetaCollection`] when
+    /// [`StakeMetaCollection::validate`] finds any problem
     pub fn new_from_stake_meta_collection(
         stake_meta_coll: StakeMetaCollection,
     ) -> Result<Self, MerkleRootGeneratorError> {
+        Self::new_from_stake_meta_collection_with_options(stake_meta_coll, None, false)
+    }
+
+    /// Same as [`Self::new_from_stake_meta_collection`], with the NCN, DAO and operator fee
+    /// shares of every validator's tips added as claimants of its tree
+    pub fn new_from_stake_meta_collection_with_fees(
+        stake_meta_coll: StakeMetaCollection,
+        ncn_fee_meta: &NcnFeeMeta,
+    ) -> Result<Self, MerkleRootGeneratorError> {
+        Self::new_from_stake_meta_collection_with_options(
+            stake_meta_coll,
+            Some(ncn_fee_meta),
+            false,
+        )
+    }
+
+    /// Same as [`Self::new_from_stake_meta_collection_with_fees`] when fees are given. With
+    /// `force`, trees are generated from a collection that fails validation.
+    pub fn new_from_stake_meta_collection_with_options(
+        stake_meta_coll: StakeMetaCollection,
+        maybe_ncn_fee_meta: Option<&NcnFeeMeta>,
+        force: bool,
+    ) -> Result<Self, MerkleRootGeneratorError> {
+        check_stake_meta_collection(&stake_meta_coll, force)?;
+        Self::new(stake_meta_coll, maybe_ncn_fee_meta)
+    }
+
+    /// Same as [`Self::new_from_stake_meta_collection_with_options`], building validator trees
+    /// and claim status PDAs on the rayon thread pool. The output is identical to the serial
+    /// path.
+    #[cfg(feature = "parallel")]
+    pub fn new_from_stake_meta_collection_parallel(
+        stake_meta_coll: StakeMetaCollection,
+        maybe_ncn_fee_meta: Option<&NcnFeeMeta>,
+        force: bool,
+    ) -> Result<Self, MerkleRootGeneratorError> {
+        check_stake_meta_collection(&stake_meta_coll, force)?;
+
         let generated_merkle_trees = stake_meta_coll
             .stake_metas
-            .into_iter()
-            .filter(|stake_meta| stake_meta.maybe_tip_distribution_meta.is_some())
+            .par_iter()
             .filter_map(|stake_meta| {
-                let mut tree_nodes = match TreeNode::vec_from_stake_meta(&stake_meta) {
-                    Err(e) => return Some(Err(e)),
-                    Ok(maybe_tree_nodes) => maybe_tree_nodes,
-                }?;
-
-                // if let Some(rpc_client) = &maybe_rpc_client {
-                //     if let Some(tda) = stake_meta.maybe_tip_distribution_meta.as_ref() {
-                // emit_inconsistent_tree_node_amount_dp(
-                //     &tree_nodes[..],
-                //     &tda.tip_distribution_pubkey,
-                //     rpc_client,
-                // );
-                //     }
-                // }
-
-                let hashed_nodes: Vec<[u8; 32]> =
-                    tree_nodes.iter().map(|n| n.hash().to_bytes()).collect();
-
-                let tip_distribution_meta = stake_meta.maybe_tip_distribution_meta.unwrap();
-
-                let merkle_tree = MerkleTree::new(&hashed_nodes[..], true);
-                let max_num_nodes = tree_nodes.len() as u64;
-
-                for (i, tree_node) in tree_nodes.iter_mut().enumerate() {
-                    tree_node.proof = Some(get_proof(&merkle_tree, i));
-                }
+                GeneratedMerkleTree::new_from_stake_meta(
+                    stake_meta,
+                    maybe_ncn_fee_meta,
+                    Execution::Parallel,
+                )
+                .transpose()
+            })
+            .collect::<Result<Vec<GeneratedMerkleTree>, MerkleRootGeneratorError>>()?;
 
-                Some(Ok(GeneratedMerkleTree {
-                    max_num_nodes,
-                    tip_distribution_account: tip_distribution_meta.tip_distribution_pubkey,
-                    merkle_root_upload_authority: tip_distribution_meta
-                        .merkle_root_upload_authority,
-                    merkle_root: *merkle_tree.get_root().unwrap(),
-                    tree_nodes,
-                    max_total_claim: tip_distribution_meta.total_tips,
-                }))
+        Ok(Self {
+            generated_merkle_trees,
+            bank_hash: stake_meta_coll.bank_hash,
+            epoch: stake_meta_coll.epoch,
+            slot: stake_meta_coll.slot,
+        })
+    }
+
+    fn new(
+        stake_meta_coll: StakeMetaCollection,
+        maybe_ncn_fee_meta: Option<&NcnFeeMeta>,
+    ) -> Result<Self, MerkleRootGeneratorError> {
+        let generated_merkle_trees = stake_meta_coll
+            .stake_metas
+            .iter()
+            .filter_map(|stake_meta| {
+                GeneratedMerkleTree::new_from_stake_meta(
+                    stake_meta,
+                    maybe_ncn_fee_meta,
+                    Execution::Serial,
+                )
+                .transpose()
             })
             .collect::<Result<Vec<GeneratedMerkleTree>, MerkleRootGeneratorError>>()?;
 
@@ -99,7 +159,89 @@ impl GeneratedMerkleTreeCollection {
     }
 }
 
-#[derive(Clone, Eq, Debug, Hash, PartialEq, Deserialize, Serialize)]
+/// Refuses collections that fail [`StakeMetaCollection::validate`] unless forced
+fn check_stake_meta_collection(
+    stake_meta_coll: &StakeMetaCollection,
+    force: bool,
+) -> Result<(), MerkleRootGeneratorError> {
+    if force {
+        return Ok(());
+    }
+
+    let problems = stake_meta_coll.validate();
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(MerkleRootGeneratorError::InvalidStakeMetaCollection(
+            problems,
+        ))
+    }
+}
+
+/// Whether per-delegation work runs on the calling thread or the rayon thread pool
+#[derive(Clone, Copy, Debug)]
+enum Execution {
+    Serial,
+    #[cfg(feature = "parallel")]
+    Parallel,
+}
+
+impl GeneratedMerkleTree {
+    /// The tree of a validator's tip distribution account, `None` if it has none
+    fn new_from_stake_meta(
+        stake_meta: &StakeMeta,
+        maybe_ncn_fee_meta: Option<&NcnFeeMeta>,
+        execution: Execution,
+    ) -> Result<Option<Self>, MerkleRootGeneratorError> {
+        let Some(tip_distribution_meta) = stake_meta.maybe_tip_distribution_meta.as_ref() else {
+            return Ok(None);
+        };
+
+        let Some(mut tree_nodes) =
+            TreeNode::vec_from_stake_meta(stake_meta, maybe_ncn_fee_meta, execution)?
+        else {
+            return Ok(None);
+        };
+
+        // if let Some(rpc_client) = &maybe_rpc_client {
+        //     if let Some(tda) = stake_meta.maybe_tip_distribution_meta.as_ref() {
+        // emit_inconsistent_tree_node_amount_dp(
+        //     &tree_nodes[..],
+        //     &tda.tip_distribution_pubkey,
+        //     rpc_client,
+        // );
+        //     }
+        // }
+
+        let hashed_nodes: Vec<[u8; 32]> = tree_nodes.iter().map(|n| n.hash().to_bytes()).collect();
+
+        let merkle_tree = MerkleTree::new(&hashed_nodes[..], true);
+        let max_num_nodes = tree_nodes.len() as u64;
+
+        for (i, tree_node) in tree_nodes.iter_mut().enumerate() {
+            tree_node.proof = Some(get_proof(&merkle_tree, i));
+        }
+
+        let merkle_root = *merkle_tree
+            .get_root()
+            .ok_or(MerkleRootGeneratorError::EmptyTree(
+                tip_distribution_meta.tip_distribution_pubkey,
+            ))?;
+
+        Ok(Some(Self {
+            max_num_nodes,
+            tip_distribution_account: tip_distribution_meta.tip_distribution_pubkey,
+            merkle_root_upload_authority: tip_distribution_meta.merkle_root_upload_authority,
+            merkle_root,
+            tree_nodes,
+            max_total_claim: tip_distribution_meta.total_tips,
+        }))
+    }
+}
+
+#[derive(
+    Clone, Eq, Debug, Hash, PartialEq, Deserialize, Serialize, BorshSerialize, BorshDeserialize,
+)]
 pub struct TreeNode {
     /// The stake account entitled to redeem.
     #[serde(with = "pubkey_string_conversion")]
@@ -125,15 +267,116 @@ pub struct TreeNode {
     pub proof: Option<Vec<[u8; 32]>>,
 }
 impl TreeNode {
+    /// Claims of a validator's tip distribution account: the validator's commission, then the
+    /// fee claims, then one claim per delegation.
+    ///
+    /// A claimant can only claim once from a tip distribution account, so a fee wallet that is
+    /// also the vote account or a delegated stake account has its fee added to that claim.
+    /// The commission and fees are rounded down. Delegations split what is left pro rata to
+    /// `lamports_delegated` with [`split_largest_remainder`], so the claims always sum to
+    /// `total_tips`. When no lamports are delegated the validator claims what is left.
     fn vec_from_stake_meta(
         stake_meta: &StakeMeta,
+        maybe_ncn_fee_meta: Option<&NcnFeeMeta>,
+        execution: Execution,
     ) -> Result<Option<Vec<Self>>, MerkleRootGeneratorError> {
         if let Some(tip_distribution_meta) = stake_meta.maybe_tip_distribution_meta.as_ref() {
-            let validator_amount = (tip_distribution_meta.total_tips as u128)
-                .checked_mul(tip_distribution_meta.validator_fee_bps as u128)
-                .unwrap()
-                .checked_div(10_000)
-                .unwrap() as u64;
+            let fee_claims = match maybe_ncn_fee_meta {
+                Some(ncn_fee_meta) => ncn_fee_meta.fee_claims(tip_distribution_meta.total_tips)?,
+                None => Vec::new(),
+            };
+
+            let tips_after_fees = fee_claims
+                .iter()
+                .try_fold(tip_distribution_meta.total_tips, |tips, (_, amount)| {
+                    tips.checked_sub(*amount)
+                })
+                .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+
+            let commission = fee_portion(
+                tips_after_fees,
+                tip_distribution_meta.validator_fee_bps as u128,
+                10_000,
+            )?;
+            let remaining_total_rewards = tips_after_fees
+                .checked_sub(commission)
+                .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+
+            let delegation_weights: Vec<(Pubkey, u64)> = stake_meta
+                .delegations
+                .iter()
+                .map(|delegation| {
+                    (
+                        delegation.stake_account_pubkey,
+                        delegation.lamports_delegated,
+                    )
+                })
+                .collect();
+            let has_delegated_lamports = delegation_weights.iter().any(|(_, weight)| *weight > 0);
+
+            let (mut validator_amount, delegation_amounts) = if has_delegated_lamports {
+                (
+                    commission,
+                    split_largest_remainder(remaining_total_rewards, &delegation_weights)?,
+                )
+            } else {
+                (tips_after_fees, vec![0; delegation_weights.len()])
+            };
+
+            let delegation_claim = |(delegation, reward_amount): (&Delegation, &u64)| {
+                let (claim_status_pubkey, claim_status_bump) = Pubkey::find_program_address(
+                    &[
+                        CLAIM_STATUS_SEED,
+                        &delegation.stake_account_pubkey.to_bytes(),
+                        &tip_distribution_meta.tip_distribution_pubkey.to_bytes(),
+                    ],
+                    &jito_tip_distribution::ID,
+                );
+                Ok(Self {
+                    claimant: delegation.stake_account_pubkey,
+                    claim_status_pubkey,
+                    claim_status_bump,
+                    staker_pubkey: delegation.staker_pubkey,
+                    withdrawer_pubkey: delegation.withdrawer_pubkey,
+                    amount: *reward_amount,
+                    proof: None,
+                })
+            };
+
+            // Deriving the claim status PDAs dominates the cost of building a tree
+            let mut delegation_claims = match execution {
+                Execution::Serial => stake_meta
+                    .delegations
+                    .iter()
+                    .zip(delegation_amounts.iter())
+                    .map(delegation_claim)
+                    .collect::<Result<Vec<Self>, MerkleRootGeneratorError>>()?,
+                #[cfg(feature = "parallel")]
+                Execution::Parallel => stake_meta
+                    .delegations
+                    .par_iter()
+                    .zip(delegation_amounts.par_iter())
+                    .map(delegation_claim)
+                    .collect::<Result<Vec<Self>, MerkleRootGeneratorError>>()?,
+            };
+
+            // Fee claims are already merged per wallet, merge them into the other claims
+            let mut unmerged_fee_claims: HashMap<Pubkey, u64> =
+                fee_claims.iter().copied().collect();
+            if let Some(amount) = unmerged_fee_claims.remove(&stake_meta.validator_vote_account) {
+                validator_amount = validator_amount
+                    .checked_add(amount)
+                    .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+            }
+            for claim in delegation_claims.iter_mut() {
+                if let Some(amount) = unmerged_fee_claims.remove(&claim.claimant) {
+                    claim.amount = claim
+                        .amount
+                        .checked_add(amount)
+                        .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+                }
+            }
+
             let (claim_status_pubkey, claim_status_bump) = Pubkey::find_program_address(
                 &[
                     CLAIM_STATUS_SEED,
@@ -152,42 +395,19 @@ impl TreeNode {
                 proof: None,
             }];
 
-            let remaining_total_rewards = tip_distribution_meta
-                .total_tips
-                .checked_sub(validator_amount)
-                .unwrap() as u128;
-
-            let total_delegated = stake_meta.total_delegated as u128;
             tree_nodes.extend(
-                stake_meta
-                    .delegations
-                    .iter()
-                    .map(|delegation| {
-                        let amount_delegated = delegation.lamports_delegated as u128;
-                        let reward_amount = (amount_delegated.checked_mul(remaining_total_rewards))
-                            .unwrap()
-                            .checked_div(total_delegated)
-                            .unwrap();
-                        let (claim_status_pubkey, claim_status_bump) = Pubkey::find_program_address(
-                            &[
-                                CLAIM_STATUS_SEED,
-                                &delegation.stake_account_pubkey.to_bytes(),
-                                &tip_distribution_meta.tip_distribution_pubkey.to_bytes(),
-                            ],
-                            &jito_tip_distribution::ID,
-                        );
-                        Ok(Self {
-                            claimant: delegation.stake_account_pubkey,
-                            claim_status_pubkey,
-                            claim_status_bump,
-                            staker_pubkey: delegation.staker_pubkey,
-                            withdrawer_pubkey: delegation.withdrawer_pubkey,
-                            amount: reward_amount as u64,
-                            proof: None,
-                        })
-                    })
-                    .collect::<Result<Vec<Self>, MerkleRootGeneratorError>>()?,
+                fee_claims
+                    .into_iter()
+                    .filter(|(claimant, _)| unmerged_fee_claims.contains_key(claimant))
+                    .map(|(claimant, amount)| {
+                        Self::new_fee_claim(
+                            claimant,
+                            amount,
+                            &tip_distribution_meta.tip_distribution_pubkey,
+                        )
+                    }),
             );
+            tree_nodes.extend(delegation_claims);
 
             Ok(Some(tree_nodes))
         } else {
@@ -195,7 +415,27 @@ impl TreeNode {
         }
     }
 
-    fn hash(&self) -> Hash {
+    fn new_fee_claim(claimant: Pubkey, amount: u64, tip_distribution_pubkey: &Pubkey) -> Self {
+        let (claim_status_pubkey, claim_status_bump) = Pubkey::find_program_address(
+            &[
+                CLAIM_STATUS_SEED,
+                &claimant.to_bytes(),
+                &tip_distribution_pubkey.to_bytes(),
+            ],
+            &jito_tip_distribution::ID,
+        );
+        Self {
+            claimant,
+            claim_status_pubkey,
+            claim_status_bump,
+            staker_pubkey: Pubkey::default(),
+            withdrawer_pubkey: Pubkey::default(),
+            amount,
+            proof: None,
+        }
+    }
+
+    pub(crate) fn hash(&self) -> Hash {
         let mut hasher = Hasher::default();
         hasher.hash(self.claimant.as_ref());
         hasher.hash(self.amount.to_le_bytes().as_ref());
@@ -272,6 +512,245 @@ pub struct TipDistributionMeta {
     pub validator_fee_bps: u16,
 }
 
+/// Protocol fees taken from every validator's tips, as recorded in the NCN's epoch snapshot
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq)]
+pub struct NcnFeeMeta {
+    #[serde(with = "pubkey_string_conversion")]
+    pub dao_fee_wallet: Pubkey,
+
+    #[serde(with = "pubkey_string_conversion")]
+    pub ncn_fee_wallet: Pubkey,
+
+    /// DAO share of the tips left after the block engine fee, in bps of the total
+    pub dao_share_bps: u64,
+
+    /// NCN share of the tips left after the block engine fee, in bps of the total
+    pub ncn_share_bps: u64,
+
+    /// Already taken by the time tips reach the [TipDistributionAccount].
+    pub block_engine_fee_bps: u64,
+
+    /// Stake weight of the epoch snapshot, operators are paid pro rata to it.
+    pub total_stake_weight: u128,
+
+    /// Operators that secured the NCN in the epoch.
+    pub operator_fees: Vec<OperatorFeeMeta>,
+}
+
+impl NcnFeeMeta {
+    /// Splits `total_tips` into claims the way the on-chain reward router splits the same
+    /// lamports, merging claims for the same wallet.
+    ///
+    /// The DAO and NCN fees match `Fee::split_after_block_engine_fee`. What is left goes to
+    /// operators pro rata to `total_stake_weight`, and each operator's part is split between
+    /// its fee wallet and its vaults as in `split_operator_rewards`. Every amount is rounded
+    /// down, the dust of the operator parts is left unclaimed.
+    pub fn fee_claims(
+        &self,
+        total_tips: u64,
+    ) -> Result<Vec<(Pubkey, u64)>, MerkleRootGeneratorError> {
+        let remaining_bps = 10_000_u64
+            .checked_sub(self.block_engine_fee_bps)
+            .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+
+        let dao_amount = fee_portion(
+            total_tips,
+            self.dao_share_bps as u128,
+            remaining_bps as u128,
+        )?;
+        let ncn_amount = fee_portion(
+            total_tips,
+            self.ncn_share_bps as u128,
+            remaining_bps as u128,
+        )?;
+        let operator_rewards = total_tips
+            .checked_sub(dao_amount)
+            .and_then(|x| x.checked_sub(ncn_amount))
+            .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+
+        let mut all_claims = vec![
+            (self.dao_fee_wallet, dao_amount),
+            (self.ncn_fee_wallet, ncn_amount),
+        ];
+        for operator in self.operator_fees.iter() {
+            let entitlement = fee_portion(
+                operator_rewards,
+                operator.stake_weight,
+                self.total_stake_weight,
+            )?;
+            let (operator_amount, vault_amounts) = operator.split_rewards(entitlement)?;
+
+            all_claims.push((operator.operator_fee_wallet, operator_amount));
+            all_claims.extend(
+                operator
+                    .vault_fees
+                    .iter()
+                    .map(|vault| vault.vault_fee_wallet)
+                    .zip(vault_amounts),
+            );
+        }
+
+        let mut claims: Vec<(Pubkey, u64)> = Vec::with_capacity(all_claims.len());
+        for (claimant, amount) in all_claims {
+            if amount == 0 {
+                continue;
+            }
+            // A wallet can only claim once per tip distribution account
+            match claims
+                .iter_mut()
+                .find(|(existing, _)| *existing == claimant)
+            {
+                Some((_, existing_amount)) => {
+                    *existing_amount = existing_amount
+                        .checked_add(amount)
+                        .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+                }
+                None => claims.push((claimant, amount)),
+            }
+        }
+
+        Ok(claims)
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq)]
+pub struct OperatorFeeMeta {
+    #[serde(with = "pubkey_string_conversion")]
+    pub operator: Pubkey,
+
+    /// Paid the operator's fee and the rounding dust of its vaults' rewards.
+    #[serde(with = "pubkey_string_conversion")]
+    pub operator_fee_wallet: Pubkey,
+
+    /// The operator's cut of its share of the rewards, the rest goes to its vaults.
+    pub operator_fee_bps: u16,
+
+    /// The operator's stake weight in the epoch snapshot.
+    pub stake_weight: u128,
+
+    /// Vaults delegating to the operator with a non-zero stake weight.
+    pub vault_fees: Vec<VaultFeeMeta>,
+}
+
+impl OperatorFeeMeta {
+    /// Same as `split_operator_rewards`: the operator fee is rounded down, vaults are paid pro
+    /// rata to their stake weight rounded down, and the operator keeps what is left.
+    fn split_rewards(&self, rewards: u64) -> Result<(u64, Vec<u64>), MerkleRootGeneratorError> {
+        if self.stake_weight == 0 {
+            return Ok((rewards, vec![0; self.vault_fees.len()]));
+        }
+
+        let operator_fee = fee_portion(rewards, self.operator_fee_bps as u128, 10_000)?;
+        let vault_pool = rewards
+            .checked_sub(operator_fee)
+            .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+
+        let vault_amounts = self
+            .vault_fees
+            .iter()
+            .map(|vault| fee_portion(vault_pool, vault.stake_weight, self.stake_weight))
+            .collect::<Result<Vec<u64>, MerkleRootGeneratorError>>()?;
+
+        let operator_amount = vault_amounts
+            .iter()
+            .try_fold(rewards, |left, amount| left.checked_sub(*amount))
+            .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+
+        Ok((operator_amount, vault_amounts))
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq)]
+pub struct VaultFeeMeta {
+    #[serde(with = "pubkey_string_conversion")]
+    pub vault: Pubkey,
+
+    /// Paid the vault's share of its operator's rewards.
+    #[serde(with = "pubkey_string_conversion")]
+    pub vault_fee_wallet: Pubkey,
+
+    /// The vault's stake weight delegated to the operator.
+    pub stake_weight: u128,
+}
+
+/// Splits `amount` pro rata to the weights with the largest remainder method, so the parts
+/// always sum to `amount`.
+///
+/// Every part is first rounded down to `amount * weight / total_weight`. The lamports left
+/// over, fewer than the number of weights, then go one each to the parts with the largest
+/// remainders of that division. Ties go to the smaller key by byte order, then to the earlier
+/// entry, so the split does not depend on the order of distinct keys. Parts are returned in the
+/// order of `weights`. Fails with [`MerkleRootGeneratorError::NoDelegatedStake`] when the
+/// weights sum to zero.
+///
+/// Test vectors are in `test_vectors/largest_remainder.json`.
+pub fn split_largest_remainder(
+    amount: u64,
+    weights: &[(Pubkey, u64)],
+) -> Result<Vec<u64>, MerkleRootGeneratorError> {
+    let total_weight = weights
+        .iter()
+        .try_fold(0_u128, |total, (_, weight)| {
+            total.checked_add(*weight as u128)
+        })
+        .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+    if total_weight == 0 {
+        return Err(MerkleRootGeneratorError::NoDelegatedStake);
+    }
+
+    let mut parts = Vec::with_capacity(weights.len());
+    let mut remainders = Vec::with_capacity(weights.len());
+    for (i, (key, weight)) in weights.iter().enumerate() {
+        let product = (amount as u128)
+            .checked_mul(*weight as u128)
+            .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+        parts.push((product / total_weight) as u64);
+        remainders.push((product % total_weight, *key, i));
+    }
+
+    let distributed = parts
+        .iter()
+        .try_fold(0_u64, |total, part| total.checked_add(*part))
+        .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+    let leftover = amount
+        .checked_sub(distributed)
+        .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+
+    remainders.sort_by(
+        |(a_remainder, a_key, a_index), (b_remainder, b_key, b_index)| {
+            b_remainder
+                .cmp(a_remainder)
+                .then_with(|| a_key.cmp(b_key))
+                .then_with(|| a_index.cmp(b_index))
+        },
+    );
+    for (_, _, i) in remainders.into_iter().take(leftover as usize) {
+        parts[i] = parts[i]
+            .checked_add(1)
+            .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?;
+    }
+
+    Ok(parts)
+}
+
+/// `amount * numerator / denominator`, rounded down. Zero when the denominator is zero.
+fn fee_portion(
+    amount: u64,
+    numerator: u128,
+    denominator: u128,
+) -> Result<u64, MerkleRootGeneratorError> {
+    if denominator == 0 {
+        return Ok(0);
+    }
+
+    let portion = (amount as u128)
+        .checked_mul(numerator)
+        .ok_or(MerkleRootGeneratorError::ArithmeticOverflow)?
+        / denominator;
+
+    u64::try_from(portion).map_err(|_| MerkleRootGeneratorError::ArithmeticOverflow)
+}
+
 #[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq)]
 pub struct Delegation {
     #[serde(with = "pubkey_string_conversion")]
@@ -332,11 +811,369 @@ mod pubkey_string_conversion {
     }
 }
 
-pub fn read_json_from_file<T>(path: &PathBuf) -> serde_json::Result<T>
+pub fn read_json_from_file<T>(path: &PathBuf) -> Result<T, MerkleRootGeneratorError>
 where
     T: DeserializeOwned,
 {
-    let file = File::open(path).unwrap();
+    let file = File::open(path).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => MerkleRootGeneratorError::FileNotFound(path.clone()),
+        _ => MerkleRootGeneratorError::IoError(e),
+    })?;
     let reader = BufReader::new(file);
-    serde_json::from_reader(reader)
+    Ok(serde_json::from_reader(reader)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use jito_tip_distribution_sdk::derive_tip_distribution_account_address;
+
+    use super::*;
+
+    fn test_stake_meta_collection(total_tips: u64) -> StakeMetaCollection {
+        let validator_vote_account = Pubkey::new_unique();
+        StakeMetaCollection {
+            stake_metas: vec![StakeMeta {
+                validator_vote_account,
+                validator_node_pubkey: Pubkey::new_unique(),
+                maybe_tip_distribution_meta: Some(TipDistributionMeta {
+                    merkle_root_upload_authority: Pubkey::new_unique(),
+                    tip_distribution_pubkey: derive_tip_distribution_account_address(
+                        &jito_tip_distribution::ID,
+                        &validator_vote_account,
+                        0,
+                    )
+                    .0,
+                    total_tips,
+                    validator_fee_bps: 1_000,
+                }),
+                delegations: vec![Delegation {
+                    stake_account_pubkey: Pubkey::new_unique(),
+                    staker_pubkey: Pubkey::new_unique(),
+                    withdrawer_pubkey: Pubkey::new_unique(),
+                    lamports_delegated: 100,
+                }],
+                total_delegated: 100,
+                commission: 10,
+            }],
+            tip_distribution_program_id: jito_tip_distribution::ID,
+            bank_hash: String::default(),
+            epoch: 0,
+            slot: 0,
+        }
+    }
+
+    fn test_ncn_fee_meta(dao_fee_wallet: Pubkey, ncn_fee_wallet: Pubkey) -> NcnFeeMeta {
+        NcnFeeMeta {
+            dao_fee_wallet,
+            ncn_fee_wallet,
+            dao_share_bps: 1_000,
+            ncn_share_bps: 500,
+            block_engine_fee_bps: 0,
+            total_stake_weight: 4,
+            operator_fees: vec![
+                OperatorFeeMeta {
+                    operator: Pubkey::new_unique(),
+                    operator_fee_wallet: Pubkey::new_unique(),
+                    operator_fee_bps: 1_000,
+                    stake_weight: 3,
+                    vault_fees: vec![test_vault_fee_meta(1), test_vault_fee_meta(2)],
+                },
+                OperatorFeeMeta {
+                    operator: Pubkey::new_unique(),
+                    operator_fee_wallet: Pubkey::new_unique(),
+                    operator_fee_bps: 0,
+                    stake_weight: 1,
+                    vault_fees: vec![test_vault_fee_meta(1)],
+                },
+            ],
+        }
+    }
+
+    fn test_vault_fee_meta(stake_weight: u128) -> VaultFeeMeta {
+        VaultFeeMeta {
+            vault: Pubkey::new_unique(),
+            vault_fee_wallet: Pubkey::new_unique(),
+            stake_weight,
+        }
+    }
+
+    #[test]
+    fn test_fee_claimants() {
+        let ncn_fee_meta = test_ncn_fee_meta(Pubkey::new_unique(), Pubkey::new_unique());
+        let collection = GeneratedMerkleTreeCollection::new_from_stake_meta_collection_with_fees(
+            test_stake_meta_collection(1_000_001),
+            &ncn_fee_meta,
+        )
+        .unwrap();
+
+        let tree_nodes = &collection.generated_merkle_trees[0].tree_nodes;
+        let amounts: Vec<(Pubkey, u64)> = tree_nodes
+            .iter()
+            .map(|node| (node.claimant, node.amount))
+            .collect();
+
+        // 10% DAO fee and 5% NCN fee, the first operator is paid 3/4 of the remaining 850_001
+        // and takes 10% of it, its vaults split the rest 1:2. The second operator charges
+        // nothing, so it has no claim and its vault is paid all of its 1/4.
+        let operators = &ncn_fee_meta.operator_fees;
+        assert_eq!(amounts[1], (ncn_fee_meta.dao_fee_wallet, 100_000));
+        assert_eq!(amounts[2], (ncn_fee_meta.ncn_fee_wallet, 50_000));
+        assert_eq!(amounts[3], (operators[0].operator_fee_wallet, 63_750));
+        assert_eq!(
+            amounts[4],
+            (operators[0].vault_fees[0].vault_fee_wallet, 191_250)
+        );
+        assert_eq!(
+            amounts[5],
+            (operators[0].vault_fees[1].vault_fee_wallet, 382_500)
+        );
+        assert_eq!(
+            amounts[6],
+            (operators[1].vault_fees[0].vault_fee_wallet, 212_500)
+        );
+        assert_eq!(amounts.len(), 8);
+
+        // Validator commission and delegator rewards come out of the rounding dust
+        assert_eq!(amounts[0].1, 0);
+        assert_eq!(amounts[7].1, 1);
+
+        assert!(tree_nodes.iter().all(|node| node.proof.is_some()));
+    }
+
+    #[test]
+    fn test_fee_claimants_merged() {
+        let fee_wallet = Pubkey::new_unique();
+        let ncn_fee_meta = test_ncn_fee_meta(fee_wallet, fee_wallet);
+        let collection = GeneratedMerkleTreeCollection::new_from_stake_meta_collection_with_fees(
+            test_stake_meta_collection(1_000_000),
+            &ncn_fee_meta,
+        )
+        .unwrap();
+
+        let tree_nodes = &collection.generated_merkle_trees[0].tree_nodes;
+        let fee_wallet_nodes: Vec<&TreeNode> = tree_nodes
+            .iter()
+            .filter(|node| node.claimant == fee_wallet)
+            .collect();
+        assert_eq!(fee_wallet_nodes.len(), 1);
+        assert_eq!(fee_wallet_nodes[0].amount, 150_000);
+    }
+
+    #[test]
+    fn test_fee_claimants_merged_into_validator_and_delegation_claims() {
+        let stake_meta_collection = test_stake_meta_collection(1_000_000);
+        let stake_meta = &stake_meta_collection.stake_metas[0];
+        let vote_account = stake_meta.validator_vote_account;
+        let stake_account = stake_meta.delegations[0].stake_account_pubkey;
+
+        let ncn_fee_meta = test_ncn_fee_meta(vote_account, stake_account);
+        let collection = GeneratedMerkleTreeCollection::new_from_stake_meta_collection_with_fees(
+            stake_meta_collection,
+            &ncn_fee_meta,
+        )
+        .unwrap();
+
+        // Every claimant, and so every claim status PDA, appears once
+        let tree_nodes = &collection.generated_merkle_trees[0].tree_nodes;
+        let claim_statuses: HashSet<Pubkey> = tree_nodes
+            .iter()
+            .map(|node| node.claim_status_pubkey)
+            .collect();
+        assert_eq!(claim_statuses.len(), tree_nodes.len());
+        assert_eq!(tree_nodes.len(), 6);
+
+        // The DAO fee is added to the commission, the NCN fee to the delegation
+        assert_eq!(tree_nodes[0].claimant, vote_account);
+        assert_eq!(tree_nodes[0].amount, 100_000);
+        let delegation_node = tree_nodes.last().unwrap();
+        assert_eq!(delegation_node.claimant, stake_account);
+        assert_eq!(delegation_node.amount, 50_000);
+        assert_eq!(
+            tree_nodes.iter().map(|node| node.amount).sum::<u64>(),
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn test_no_fees() {
+        let collection = GeneratedMerkleTreeCollection::new_from_stake_meta_collection(
+            test_stake_meta_collection(1_000_000),
+        )
+        .unwrap();
+
+        let tree_nodes = &collection.generated_merkle_trees[0].tree_nodes;
+        assert_eq!(tree_nodes.len(), 2);
+        assert_eq!(tree_nodes[0].amount, 100_000);
+        assert_eq!(tree_nodes[1].amount, 900_000);
+    }
+
+    #[derive(Deserialize)]
+    struct LargestRemainderVector {
+        description: String,
+        amount: u64,
+        weights: Vec<LargestRemainderWeight>,
+        expected: Vec<u64>,
+    }
+
+    #[derive(Deserialize)]
+    struct LargestRemainderWeight {
+        #[serde(with = "pubkey_string_conversion")]
+        key: Pubkey,
+        weight: u64,
+    }
+
+    #[test]
+    fn test_largest_remainder_vectors() {
+        let vectors: Vec<LargestRemainderVector> =
+            serde_json::from_str(include_str!("../test_vectors/largest_remainder.json")).unwrap();
+
+        for vector in vectors {
+            let weights: Vec<(Pubkey, u64)> = vector
+                .weights
+                .iter()
+                .map(|weight| (weight.key, weight.weight))
+                .collect();
+            assert_eq!(
+                split_largest_remainder(vector.amount, &weights).unwrap(),
+                vector.expected,
+                "{}",
+                vector.description
+            );
+        }
+
+        assert!(matches!(
+            split_largest_remainder(1, &[(Pubkey::new_unique(), 0)]),
+            Err(MerkleRootGeneratorError::NoDelegatedStake)
+        ));
+    }
+
+    #[test]
+    fn test_claims_sum_to_total_tips() {
+        let mut stake_meta_collection = test_stake_meta_collection(1_000_003);
+        let stake_meta = &mut stake_meta_collection.stake_metas[0];
+        stake_meta.delegations = (0..3)
+            .map(|_| Delegation {
+                stake_account_pubkey: Pubkey::new_unique(),
+                staker_pubkey: Pubkey::new_unique(),
+                withdrawer_pubkey: Pubkey::new_unique(),
+                lamports_delegated: 100,
+            })
+            .collect();
+        stake_meta.total_delegated = 300;
+
+        let ncn_fee_meta = test_ncn_fee_meta(Pubkey::new_unique(), Pubkey::new_unique());
+        let collection = GeneratedMerkleTreeCollection::new_from_stake_meta_collection_with_fees(
+            stake_meta_collection,
+            &ncn_fee_meta,
+        )
+        .unwrap();
+
+        let tree_nodes = &collection.generated_merkle_trees[0].tree_nodes;
+        let total: u64 = tree_nodes.iter().map(|node| node.amount).sum();
+        assert_eq!(total, 1_000_003);
+
+        // Equal delegations differ by at most the one lamport of dust
+        let delegation_amounts: Vec<u64> = tree_nodes[tree_nodes.len() - 3..]
+            .iter()
+            .map(|node| node.amount)
+            .collect();
+        let min = *delegation_amounts.iter().min().unwrap();
+        let max = *delegation_amounts.iter().max().unwrap();
+        assert!(max - min <= 1);
+    }
+
+    #[test]
+    fn test_no_delegations() {
+        let mut stake_meta_collection = test_stake_meta_collection(1_000_000);
+        stake_meta_collection.stake_metas[0].delegations.clear();
+        stake_meta_collection.stake_metas[0].total_delegated = 0;
+
+        let collection =
+            GeneratedMerkleTreeCollection::new_from_stake_meta_collection(stake_meta_collection)
+                .unwrap();
+
+        // Nothing is left undistributed, the validator claims the delegators' share
+        let tree_nodes = &collection.generated_merkle_trees[0].tree_nodes;
+        assert_eq!(tree_nodes.len(), 1);
+        assert_eq!(tree_nodes[0].amount, 1_000_000);
+    }
+
+    #[test]
+    fn test_malformed_stake_meta() {
+        let mut stake_meta_collection = test_stake_meta_collection(1_000_000);
+        stake_meta_collection.stake_metas[0]
+            .maybe_tip_distribution_meta
+            .as_mut()
+            .unwrap()
+            .validator_fee_bps = 20_000;
+
+        assert!(matches!(
+            GeneratedMerkleTreeCollection::new_from_stake_meta_collection(
+                stake_meta_collection.clone()
+            ),
+            Err(MerkleRootGeneratorError::InvalidStakeMetaCollection(_))
+        ));
+
+        // Forcing skips validation, the entry still fails without panicking
+        assert!(matches!(
+            GeneratedMerkleTreeCollection::new_from_stake_meta_collection_with_options(
+                stake_meta_collection,
+                None,
+                true,
+            ),
+            Err(MerkleRootGeneratorError::ArithmeticOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_read_json_from_missing_file() {
+        let path = PathBuf::from("does-not-exist.json");
+        match read_json_from_file::<StakeMetaCollection>(&path) {
+            Err(MerkleRootGeneratorError::FileNotFound(missing)) => assert_eq!(missing, path),
+            result => panic!("expected a missing file error, got {:?}", result),
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let stake_meta_collection = StakeMetaCollection {
+            stake_metas: (0..8)
+                .flat_map(|_| test_stake_meta_collection(1_000_000).stake_metas)
+                .map(|mut stake_meta| {
+                    stake_meta.delegations = (1..=50)
+                        .map(|lamports_delegated| Delegation {
+                            stake_account_pubkey: Pubkey::new_unique(),
+                            staker_pubkey: Pubkey::new_unique(),
+                            withdrawer_pubkey: Pubkey::new_unique(),
+                            lamports_delegated,
+                        })
+                        .collect();
+                    stake_meta.total_delegated = (1..=50).sum();
+                    stake_meta
+                })
+                .collect(),
+            ..test_stake_meta_collection(0)
+        };
+        let ncn_fee_meta = test_ncn_fee_meta(Pubkey::new_unique(), Pubkey::new_unique());
+
+        let serial = GeneratedMerkleTreeCollection::new_from_stake_meta_collection_with_fees(
+            stake_meta_collection.clone(),
+            &ncn_fee_meta,
+        )
+        .unwrap();
+        let parallel = GeneratedMerkleTreeCollection::new_from_stake_meta_collection_parallel(
+            stake_meta_collection,
+            Some(&ncn_fee_meta),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_vec(&parallel).unwrap(),
+            serde_json::to_vec(&serial).unwrap()
+        );
+    }
 }
@@ -1,7 +1,12 @@
+pub mod binary;
+pub mod csv_entry;
+pub mod diff;
 pub mod error;
 pub mod generated_merkle_tree;
 pub mod merkle_tree;
 pub mod meta_merkle_tree;
+pub mod stake_meta_validation;
+pub mod test_vectors;
 pub mod tree_node;
 pub mod utils;
 pub mod verify;
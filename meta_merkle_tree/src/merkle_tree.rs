@@ -2,6 +2,8 @@
 // https://github.com/jito-foundation/jito-solana/blob/v1.16.19-jito/merkle-tree/src/merkle_tree.rs
 use solana_program::hash::{hashv, Hash};
 
+use crate::verify::MultiProof;
+
 // We need to discern between leaf and intermediate nodes to prevent trivial second
 // pre-image attacks.
 // https://flawed.net.nz/2018/02/21/attacking-merkle-trees-with-a-second-preimage-attack
@@ -201,6 +203,58 @@ impl MerkleTree {
         }
         Some(path)
     }
+
+    /// Multiproof of the leaves at `indices`, in any order and possibly repeated. `None` if
+    /// `indices` is empty or any index is out of range. Verify with
+    /// [`crate::verify::verify_multiproof`], which expects a tree built with sorted hashes.
+    pub fn find_multiproof(&self, indices: &[usize]) -> Option<MultiProof> {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        if indices.is_empty() || indices[indices.len() - 1] >= self.leaf_count {
+            return None;
+        }
+
+        let mut proof = MultiProof {
+            leaf_count: u32::try_from(self.leaf_count).ok()?,
+            indices: indices
+                .iter()
+                .map(|index| u32::try_from(*index).ok())
+                .collect::<Option<Vec<u32>>>()?,
+            hashes: Vec::new(),
+        };
+
+        let mut level_len = self.leaf_count;
+        let mut level_start = 0;
+        let mut known = indices;
+        while level_len > 1 {
+            let level = &self.nodes[level_start..(level_start + level_len)];
+
+            let mut parents = Vec::with_capacity(known.len());
+            let mut i = 0;
+            while i < known.len() {
+                let index = known[i];
+                if index % 2 == 1 {
+                    proof.hashes.push(level[index - 1].to_bytes());
+                } else if index + 1 < level_len {
+                    if i + 1 < known.len() && known[i + 1] == index + 1 {
+                        i += 1;
+                    } else {
+                        proof.hashes.push(level[index + 1].to_bytes());
+                    }
+                }
+
+                parents.push(index / 2);
+                i += 1;
+            }
+
+            known = parents;
+            level_start += level_len;
+            level_len = Self::next_level_len(level_len);
+        }
+
+        Some(proof)
+    }
 }
 
 #[cfg(test)]
@@ -273,6 +327,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_multiproof_verify() {
+        use crate::verify::{verify_multiproof, verify_proof};
+
+        for leaf_count in 1..=TEST.len() {
+            let items = &TEST[..leaf_count];
+            let mt = MerkleTree::new(items, true);
+            let root = mt.get_root().unwrap().to_bytes();
+            let leaves: Vec<[u8; 32]> = items.iter().map(|s| hash_leaf!(s).to_bytes()).collect();
+
+            let subsets: Vec<Vec<usize>> = vec![
+                vec![0],
+                vec![leaf_count - 1],
+                (0..leaf_count).collect(),
+                (0..leaf_count).step_by(2).collect(),
+                (1..leaf_count).step_by(3).collect(),
+            ];
+            for indices in subsets.into_iter().filter(|indices| !indices.is_empty()) {
+                let proof = mt.find_multiproof(&indices).unwrap();
+                let proven: Vec<[u8; 32]> = indices.iter().map(|i| leaves[*i]).collect();
+                assert!(verify_multiproof(&proof, root, &proven), "{:?}", indices);
+
+                // Never larger than the single proofs together
+                let single_proofs_len: usize = indices
+                    .iter()
+                    .map(|i| crate::utils::get_proof(&mt, *i).len())
+                    .sum();
+                assert!(proof.hashes.len() <= single_proofs_len);
+
+                if indices.len() == 1 {
+                    assert!(verify_proof(
+                        &crate::utils::get_proof(&mt, indices[0]),
+                        root,
+                        proven[0]
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiproof_verify_bad() {
+        use crate::verify::verify_multiproof;
+
+        let mt = MerkleTree::new(TEST, true);
+        let root = mt.get_root().unwrap().to_bytes();
+        let leaves: Vec<[u8; 32]> = TEST.iter().map(|s| hash_leaf!(s).to_bytes()).collect();
+
+        // Indices are sorted and deduplicated
+        let proof = mt.find_multiproof(&[7, 2, 2]).unwrap();
+        assert_eq!(proof.indices, vec![2, 7]);
+        assert!(verify_multiproof(&proof, root, &[leaves[2], leaves[7]]));
+
+        // Wrong leaf, swapped leaves, missing leaf
+        let bad_leaf = hash_leaf!(BAD[0]).to_bytes();
+        assert!(!verify_multiproof(&proof, root, &[leaves[2], bad_leaf]));
+        assert!(!verify_multiproof(&proof, root, &[leaves[7], leaves[2]]));
+        assert!(!verify_multiproof(&proof, root, &[leaves[2]]));
+
+        // Hashes left over or missing
+        let mut extra_hash = proof.clone();
+        extra_hash.hashes.push([0; 32]);
+        assert!(!verify_multiproof(
+            &extra_hash,
+            root,
+            &[leaves[2], leaves[7]]
+        ));
+        let mut missing_hash = proof.clone();
+        missing_hash.hashes.pop();
+        assert!(!verify_multiproof(
+            &missing_hash,
+            root,
+            &[leaves[2], leaves[7]]
+        ));
+
+        // Unsorted or out of range indices
+        let mut unsorted = proof.clone();
+        unsorted.indices.reverse();
+        assert!(!verify_multiproof(&unsorted, root, &[leaves[7], leaves[2]]));
+        let mut out_of_range = proof;
+        out_of_range.indices[1] = TEST.len() as u32;
+        assert!(!verify_multiproof(
+            &out_of_range,
+            root,
+            &[leaves[2], leaves[7]]
+        ));
+
+        assert_eq!(mt.find_multiproof(&[]), None);
+        assert_eq!(mt.find_multiproof(&[TEST.len()]), None);
+    }
+
     #[test]
     fn test_proof_entry_instantiation_lsib_set() {
         ProofEntry::new(&Hash::default(), Some(&Hash::default()), None);
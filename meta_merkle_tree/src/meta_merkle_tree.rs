@@ -27,11 +27,31 @@ pub const LEAF_PREFIX: &[u8] = &[0];
 /// Contains all the information necessary to verify claims against the Merkle Tree.
 /// Wrapper around solana MerkleTree
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "SerializedMetaMerkleTree")]
 pub struct MetaMerkleTree {
     /// The merkle root, which is uploaded on-chain
     pub merkle_root: [u8; 32],
     pub num_nodes: u64,
-    pub tree_nodes: Vec<TreeNode>,
+    /// Private so it can't drift from `node_index`, read it with [`Self::tree_nodes`]
+    tree_nodes: Vec<TreeNode>,
+    /// Position of each tip distribution account in `tree_nodes`, rebuilt whenever a tree is
+    /// created or deserialized
+    #[serde(skip)]
+    node_index: HashMap<Pubkey, usize>,
+}
+
+/// On-disk layout of a [`MetaMerkleTree`], without the lookup index
+#[derive(Deserialize)]
+struct SerializedMetaMerkleTree {
+    merkle_root: [u8; 32],
+    num_nodes: u64,
+    tree_nodes: Vec<TreeNode>,
+}
+
+impl From<SerializedMetaMerkleTree> for MetaMerkleTree {
+    fn from(tree: SerializedMetaMerkleTree) -> Self {
+        Self::from_parts(tree.merkle_root, tree.num_nodes, tree.tree_nodes)
+    }
 }
 
 pub type Result<T> = result::Result<T, MerkleTreeError>;
@@ -52,23 +72,37 @@ impl MetaMerkleTree {
             tree_node.proof = Some(get_proof(&tree, i));
         }
 
-        let tree = Self {
-            merkle_root: tree
-                .get_root()
+        let tree = Self::from_parts(
+            tree.get_root()
                 .ok_or(MerkleTreeError::MerkleRootError)?
                 .to_bytes(),
-            num_nodes: tree_nodes.len() as u64,
+            tree_nodes.len() as u64,
             tree_nodes,
-        };
-
-        println!(
-            "created merkle tree with {} nodes and max total claim of {}",
-            tree.num_nodes, tree.num_nodes
         );
+
         tree.validate()?;
         Ok(tree)
     }
 
+    pub(crate) fn from_parts(
+        merkle_root: [u8; 32],
+        num_nodes: u64,
+        tree_nodes: Vec<TreeNode>,
+    ) -> Self {
+        let node_index = tree_nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.tip_distribution_account, i))
+            .collect();
+
+        Self {
+            merkle_root,
+            num_nodes,
+            tree_nodes,
+            node_index,
+        }
+    }
+
     // TODO replace this with the GeneratedMerkleTreeCollection from the Operator module once that's created
     pub fn new_from_generated_merkle_tree_collection(
         generated_merkle_tree_collection: GeneratedMerkleTreeCollection,
@@ -81,14 +115,10 @@ impl MetaMerkleTree {
         Self::new(tree_nodes)
     }
 
-    // TODO if we need to load this from a file (for operator?)
-    /// Load a merkle tree from a csv path
-    // pub fn new_from_csv(path: &PathBuf) -> Result<Self> {
-    //     let csv_entries = CsvEntry::new_from_file(path)?;
-    //     let tree_nodes: Vec<TreeNode> = csv_entries.into_iter().map(TreeNode::from).collect();
-    //     let tree = Self::new(tree_nodes)?;
-    //     Ok(tree)
-    // }
+    /// Nodes in tree order, sorted by hash
+    pub fn tree_nodes(&self) -> &[TreeNode] {
+        &self.tree_nodes
+    }
 
     /// Load a serialized merkle tree from file path
     pub fn new_from_file(path: &PathBuf) -> Result<Self> {
@@ -100,20 +130,34 @@ impl MetaMerkleTree {
     }
 
     /// Write a merkle tree to a filepath
-    pub fn write_to_file(&self, path: &PathBuf) {
-        let serialized = serde_json::to_string_pretty(&self).unwrap();
-        let mut file = File::create(path).unwrap();
-        file.write_all(serialized.as_bytes()).unwrap();
+    pub fn write_to_file(&self, path: &PathBuf) -> Result<()> {
+        let serialized = serde_json::to_string_pretty(&self)?;
+        let mut file = File::create(path)?;
+        file.write_all(serialized.as_bytes())?;
+        Ok(())
     }
 
+    /// Panics if the tip distribution account is not in the tree, see [`Self::try_get_node`]
     pub fn get_node(&self, tip_distribution_account: &Pubkey) -> TreeNode {
-        for i in self.tree_nodes.iter() {
-            if i.tip_distribution_account == *tip_distribution_account {
-                return i.clone();
-            }
-        }
+        self.try_get_node(tip_distribution_account)
+            .expect("Claimant not found in tree")
+            .clone()
+    }
 
-        panic!("Claimant not found in tree");
+    pub fn try_get_node(&self, tip_distribution_account: &Pubkey) -> Result<&TreeNode> {
+        self.node_index
+            .get(tip_distribution_account)
+            .and_then(|i| self.tree_nodes.get(*i))
+            .filter(|node| node.tip_distribution_account == *tip_distribution_account)
+            .ok_or(MerkleTreeError::NodeNotFound(*tip_distribution_account))
+    }
+
+    /// Proof of inclusion of a tip distribution account's node under `merkle_root`
+    pub fn proof_for(&self, tip_distribution_account: &Pubkey) -> Result<&[[u8; 32]]> {
+        self.try_get_node(tip_distribution_account)?
+            .proof
+            .as_deref()
+            .ok_or(MerkleTreeError::MissingProof(*tip_distribution_account))
     }
 
     fn validate(&self) -> Result<()> {
@@ -258,7 +302,7 @@ mod tests {
         let path = PathBuf::from("merkle_tree.json");
 
         // serialize merkle distributor to file
-        merkle_distributor_info.write_to_file(&path);
+        merkle_distributor_info.write_to_file(&path).unwrap();
         // now test we can successfully read from file
         let merkle_distributor_read: MetaMerkleTree = MetaMerkleTree::new_from_file(&path).unwrap();
 
@@ -290,4 +334,53 @@ mod tests {
         assert_eq!(tree.tree_nodes[0].tip_distribution_account, pubkey1);
         assert!(tree.tree_nodes[0].proof.is_some());
     }
+
+    #[test]
+    fn test_try_get_node() {
+        let pubkey1 = Pubkey::new_unique();
+        let pubkey2 = Pubkey::new_unique();
+        let tree = MetaMerkleTree::new(vec![
+            TreeNode::new(pubkey1, [1; 32], 10, 20),
+            TreeNode::new(pubkey2, [2; 32], 1, 2),
+        ])
+        .unwrap();
+
+        let node = tree.try_get_node(&pubkey2).unwrap();
+        assert_eq!(node.tip_distribution_account, pubkey2);
+        assert_eq!(node.validator_merkle_root, [2; 32]);
+        assert_eq!(
+            tree.proof_for(&pubkey2).unwrap(),
+            node.proof.as_ref().unwrap()
+        );
+
+        let unknown = Pubkey::new_unique();
+        assert!(matches!(
+            tree.try_get_node(&unknown),
+            Err(MerkleTreeError::NodeNotFound(key)) if key == unknown
+        ));
+        assert!(matches!(
+            tree.proof_for(&unknown),
+            Err(MerkleTreeError::NodeNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_index_rebuilt_on_deserialize() {
+        let tree_nodes: Vec<TreeNode> = (0..10)
+            .map(|i| TreeNode::new(Pubkey::new_unique(), [i; 32], i as u64, i as u64))
+            .collect();
+        let tree = MetaMerkleTree::new(tree_nodes.clone()).unwrap();
+
+        let serialized = serde_json::to_string(&tree).unwrap();
+        let deserialized: MetaMerkleTree = serde_json::from_str(&serialized).unwrap();
+
+        for node in tree_nodes.iter() {
+            assert_eq!(
+                deserialized
+                    .try_get_node(&node.tip_distribution_account)
+                    .unwrap(),
+                tree.try_get_node(&node.tip_distribution_account).unwrap()
+            );
+        }
+    }
 }
@@ -0,0 +1,279 @@
+//! Consistency checks of a [`StakeMetaCollection`] before merkle trees are generated from it
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use jito_tip_distribution_sdk::{derive_tip_distribution_account_address, jito_tip_distribution};
+use solana_program::pubkey::Pubkey;
+
+use crate::generated_merkle_tree::StakeMetaCollection;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StakeMetaProblem {
+    TipDistributionProgramId {
+        expected: Pubkey,
+        actual: Pubkey,
+    },
+    DuplicateValidatorVoteAccount {
+        validator_vote_account: Pubkey,
+    },
+    DuplicateStakeAccount {
+        stake_account: Pubkey,
+        validator_vote_account: Pubkey,
+    },
+    TotalDelegated {
+        validator_vote_account: Pubkey,
+        total_delegated: u64,
+        sum_delegated: u128,
+    },
+    ValidatorFeeBps {
+        validator_vote_account: Pubkey,
+        validator_fee_bps: u16,
+    },
+    TipDistributionAccount {
+        validator_vote_account: Pubkey,
+        expected: Pubkey,
+        actual: Pubkey,
+    },
+}
+
+impl fmt::Display for StakeMetaProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TipDistributionProgramId { expected, actual } => write!(
+                f,
+                "tip distribution program id expected {expected}, got {actual}"
+            ),
+            Self::DuplicateValidatorVoteAccount {
+                validator_vote_account,
+            } => write!(f, "validator {validator_vote_account}: duplicate"),
+            Self::DuplicateStakeAccount {
+                stake_account,
+                validator_vote_account,
+            } => write!(
+                f,
+                "validator {validator_vote_account}: duplicate stake account {stake_account}"
+            ),
+            Self::TotalDelegated {
+                validator_vote_account,
+                total_delegated,
+                sum_delegated,
+            } => write!(
+                f,
+                "validator {validator_vote_account}: total_delegated {total_delegated}, delegations sum to {sum_delegated}"
+            ),
+            Self::ValidatorFeeBps {
+                validator_vote_account,
+                validator_fee_bps,
+            } => write!(
+                f,
+                "validator {validator_vote_account}: validator_fee_bps {validator_fee_bps} exceeds 10000"
+            ),
+            Self::TipDistributionAccount {
+                validator_vote_account,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "validator {validator_vote_account}: tip distribution account expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+impl StakeMetaCollection {
+    /// Every problem found in the collection, empty if it is valid. Stake accounts are
+    /// duplicates when they appear more than once anywhere in the collection, tip distribution
+    /// accounts are derived for the collection's epoch.
+    pub fn validate(&self) -> Vec<StakeMetaProblem> {
+        let mut problems = Vec::new();
+
+        if self.tip_distribution_program_id != jito_tip_distribution::ID {
+            problems.push(StakeMetaProblem::TipDistributionProgramId {
+                expected: jito_tip_distribution::ID,
+                actual: self.tip_distribution_program_id,
+            });
+        }
+
+        let mut validator_vote_accounts = HashSet::new();
+        let mut stake_accounts: HashMap<Pubkey, Pubkey> = HashMap::new();
+
+        for stake_meta in self.stake_metas.iter() {
+            let validator_vote_account = stake_meta.validator_vote_account;
+
+            if !validator_vote_accounts.insert(validator_vote_account) {
+                problems.push(StakeMetaProblem::DuplicateValidatorVoteAccount {
+                    validator_vote_account,
+                });
+            }
+
+            for delegation in stake_meta.delegations.iter() {
+                if stake_accounts
+                    .insert(delegation.stake_account_pubkey, validator_vote_account)
+                    .is_some()
+                {
+                    problems.push(StakeMetaProblem::DuplicateStakeAccount {
+                        stake_account: delegation.stake_account_pubkey,
+                        validator_vote_account,
+                    });
+                }
+            }
+
+            let sum_delegated: u128 = stake_meta
+                .delegations
+                .iter()
+                .map(|delegation| delegation.lamports_delegated as u128)
+                .sum();
+            if sum_delegated != stake_meta.total_delegated as u128 {
+                problems.push(StakeMetaProblem::TotalDelegated {
+                    validator_vote_account,
+                    total_delegated: stake_meta.total_delegated,
+                    sum_delegated,
+                });
+            }
+
+            let Some(tip_distribution_meta) = stake_meta.maybe_tip_distribution_meta.as_ref()
+            else {
+                continue;
+            };
+
+            if tip_distribution_meta.validator_fee_bps > 10_000 {
+                problems.push(StakeMetaProblem::ValidatorFeeBps {
+                    validator_vote_account,
+                    validator_fee_bps: tip_distribution_meta.validator_fee_bps,
+                });
+            }
+
+            let (expected_tip_distribution_account, _) = derive_tip_distribution_account_address(
+                &jito_tip_distribution::ID,
+                &validator_vote_account,
+                self.epoch,
+            );
+            if tip_distribution_meta.tip_distribution_pubkey != expected_tip_distribution_account {
+                problems.push(StakeMetaProblem::TipDistributionAccount {
+                    validator_vote_account,
+                    expected: expected_tip_distribution_account,
+                    actual: tip_distribution_meta.tip_distribution_pubkey,
+                });
+            }
+        }
+
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated_merkle_tree::{Delegation, StakeMeta, TipDistributionMeta};
+
+    fn delegation(lamports_delegated: u64) -> Delegation {
+        Delegation {
+            stake_account_pubkey: Pubkey::new_unique(),
+            staker_pubkey: Pubkey::new_unique(),
+            withdrawer_pubkey: Pubkey::new_unique(),
+            lamports_delegated,
+        }
+    }
+
+    fn stake_meta(epoch: u64) -> StakeMeta {
+        let validator_vote_account = Pubkey::new_unique();
+        StakeMeta {
+            validator_vote_account,
+            validator_node_pubkey: Pubkey::new_unique(),
+            maybe_tip_distribution_meta: Some(TipDistributionMeta {
+                merkle_root_upload_authority: Pubkey::new_unique(),
+                tip_distribution_pubkey: derive_tip_distribution_account_address(
+                    &jito_tip_distribution::ID,
+                    &validator_vote_account,
+                    epoch,
+                )
+                .0,
+                total_tips: 1_000,
+                validator_fee_bps: 500,
+            }),
+            delegations: vec![delegation(10), delegation(20)],
+            total_delegated: 30,
+            commission: 5,
+        }
+    }
+
+    fn stake_meta_collection() -> StakeMetaCollection {
+        StakeMetaCollection {
+            stake_metas: vec![stake_meta(5), stake_meta(5)],
+            tip_distribution_program_id: jito_tip_distribution::ID,
+            bank_hash: String::default(),
+            epoch: 5,
+            slot: 0,
+        }
+    }
+
+    #[test]
+    fn test_valid_collection() {
+        assert_eq!(stake_meta_collection().validate(), vec![]);
+    }
+
+    #[test]
+    fn test_every_problem_is_reported() {
+        let mut collection = stake_meta_collection();
+        let other_program_id = Pubkey::new_unique();
+        collection.tip_distribution_program_id = other_program_id;
+
+        let duplicate_stake_account = collection.stake_metas[0].delegations[0].clone();
+        collection.stake_metas[1]
+            .delegations
+            .push(duplicate_stake_account.clone());
+        collection.stake_metas[1].total_delegated += duplicate_stake_account.lamports_delegated;
+
+        let duplicate_validator = collection.stake_metas[0].clone();
+        collection.stake_metas.push(duplicate_validator.clone());
+
+        let stake_meta = &mut collection.stake_metas[0];
+        stake_meta.total_delegated = 31;
+        let tip_distribution_meta = stake_meta.maybe_tip_distribution_meta.as_mut().unwrap();
+        tip_distribution_meta.validator_fee_bps = 10_001;
+        let wrong_tip_distribution_account = Pubkey::new_unique();
+        tip_distribution_meta.tip_distribution_pubkey = wrong_tip_distribution_account;
+
+        let validator_vote_account = duplicate_validator.validator_vote_account;
+        let problems = collection.validate();
+
+        assert!(
+            problems.contains(&StakeMetaProblem::TipDistributionProgramId {
+                expected: jito_tip_distribution::ID,
+                actual: other_program_id,
+            })
+        );
+        assert!(
+            problems.contains(&StakeMetaProblem::DuplicateValidatorVoteAccount {
+                validator_vote_account,
+            })
+        );
+        assert!(problems.contains(&StakeMetaProblem::DuplicateStakeAccount {
+            stake_account: duplicate_stake_account.stake_account_pubkey,
+            validator_vote_account: collection.stake_metas[1].validator_vote_account,
+        }));
+        assert!(problems.contains(&StakeMetaProblem::TotalDelegated {
+            validator_vote_account,
+            total_delegated: 31,
+            sum_delegated: 30,
+        }));
+        assert!(problems.contains(&StakeMetaProblem::ValidatorFeeBps {
+            validator_vote_account,
+            validator_fee_bps: 10_001,
+        }));
+        assert!(
+            problems.contains(&StakeMetaProblem::TipDistributionAccount {
+                validator_vote_account,
+                expected: derive_tip_distribution_account_address(
+                    &jito_tip_distribution::ID,
+                    &validator_vote_account,
+                    5,
+                )
+                .0,
+                actual: wrong_tip_distribution_account,
+            })
+        );
+    }
+}
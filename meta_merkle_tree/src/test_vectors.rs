@@ -0,0 +1,296 @@
+//! Deterministic meta merkle trees and validator claim trees for checking other implementations
+//! of the hashing against this crate, written to `test_vectors/meta_merkle_tree.json` and
+//! `test_vectors/generated_merkle_tree.json` by the `generate_test_vectors` binary.
+//!
+//! - A meta tree node hashes to `sha256(tip_distribution_account || validator_merkle_root ||
+//!   max_total_claim as u64 le || max_num_nodes as u64 le)`, see [`TreeNode::hash`]
+//! - Meta tree nodes are sorted by that hash
+//! - A claim node hashes to `sha256(claimant || amount as u64 le)`, see
+//!   [`generated_merkle_tree::TreeNode::hash`]. Claim nodes keep their order.
+//! - A leaf is `sha256(LEAF_PREFIX || node hash)`
+//! - A parent is `sha256([1] || min(a, b) || max(a, b))`, the last node of an odd length
+//!   level is paired with itself
+//! - Proofs list the sibling hashes from the leaf up, see [`crate::verify::verify`]
+use serde::{Deserialize, Serialize};
+use solana_program::{
+    hash::{hashv, Hash},
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::MerkleTreeError,
+    generated_merkle_tree,
+    merkle_tree::MerkleTree,
+    meta_merkle_tree::{Result, LEAF_PREFIX},
+    tree_node::TreeNode,
+    utils::get_proof,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleTestVector {
+    pub description: String,
+    /// Hex encoded
+    pub merkle_root: String,
+    /// In tree order
+    pub nodes: Vec<MerkleTestVectorNode>,
+}
+
+/// Hashes are hex encoded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleTestVectorNode {
+    /// Base58 encoded
+    pub tip_distribution_account: String,
+    pub validator_merkle_root: String,
+    pub max_total_claim: u64,
+    pub max_num_nodes: u64,
+    pub node_hash: String,
+    pub leaf: String,
+    pub proof: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeneratedMerkleTestVector {
+    pub description: String,
+    /// Hex encoded
+    pub merkle_root: String,
+    /// In tree order
+    pub nodes: Vec<GeneratedMerkleTestVectorNode>,
+}
+
+/// Hashes are hex encoded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeneratedMerkleTestVectorNode {
+    /// Base58 encoded
+    pub claimant: String,
+    pub amount: u64,
+    pub node_hash: String,
+    pub leaf: String,
+    pub proof: Vec<String>,
+}
+
+/// Vectors for 1 node, odd node counts, duplicate hashes and 2^k+1 nodes
+pub fn generate_test_vectors() -> Result<Vec<MerkleTestVector>> {
+    test_cases()
+        .into_iter()
+        .map(|(description, indices)| {
+            test_vector(&description, indices.into_iter().map(test_node).collect())
+        })
+        .collect()
+}
+
+/// Claim tree vectors for the same cases as [`generate_test_vectors`]
+pub fn generate_generated_merkle_tree_test_vectors() -> Result<Vec<GeneratedMerkleTestVector>> {
+    test_cases()
+        .into_iter()
+        .map(|(description, indices)| {
+            generated_test_vector(&description, indices.into_iter().map(test_claim).collect())
+        })
+        .collect()
+}
+
+/// Descriptions and the indices of the test nodes of each tree
+fn test_cases() -> Vec<(String, Vec<u8>)> {
+    let mut cases = vec![
+        ("1 node".to_string(), (0..1).collect()),
+        ("2 nodes".to_string(), (0..2).collect()),
+        ("odd count, 3 nodes".to_string(), (0..3).collect()),
+        ("odd count, 7 nodes".to_string(), (0..7).collect()),
+        (
+            "duplicate hashes, 2 of 4 nodes equal".to_string(),
+            vec![0, 1, 1, 2],
+        ),
+        ("duplicate hashes, 3 equal nodes".to_string(), vec![0, 0, 0]),
+    ];
+
+    for k in 1..=5 {
+        let num_nodes = (1 << k) + 1;
+        cases.push((format!("2^{} + 1 nodes", k), (0..num_nodes).collect()));
+    }
+
+    cases
+}
+
+fn test_node(i: u8) -> TreeNode {
+    TreeNode::new(
+        Pubkey::new_from_array([i; 32]),
+        hashv(&[b"validator_merkle_root".as_ref(), &[i]]).to_bytes(),
+        (i as u64 + 1) * 1_000_000_000,
+        i as u64 + 1,
+    )
+}
+
+fn test_claim(i: u8) -> generated_merkle_tree::TreeNode {
+    generated_merkle_tree::TreeNode {
+        claimant: Pubkey::new_from_array([i; 32]),
+        claim_status_pubkey: Pubkey::default(),
+        claim_status_bump: 0,
+        staker_pubkey: Pubkey::default(),
+        withdrawer_pubkey: Pubkey::default(),
+        amount: (i as u64 + 1) * 1_000_000,
+        proof: None,
+    }
+}
+
+/// Built the way [`crate::meta_merkle_tree::MetaMerkleTree::new`] builds its tree, without
+/// rejecting duplicate tip distribution accounts
+fn test_vector(description: &str, mut tree_nodes: Vec<TreeNode>) -> Result<MerkleTestVector> {
+    tree_nodes.sort_by_key(|node| node.hash());
+
+    let hashed_nodes: Vec<[u8; 32]> = tree_nodes
+        .iter()
+        .map(|node| node.hash().to_bytes())
+        .collect();
+    let tree = MerkleTree::new(&hashed_nodes[..], true);
+    let merkle_root = tree.get_root().ok_or(MerkleTreeError::MerkleRootError)?;
+
+    let nodes = tree_nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| {
+            let node_hash = node.hash();
+            MerkleTestVectorNode {
+                tip_distribution_account: node.tip_distribution_account.to_string(),
+                validator_merkle_root: hex::encode(node.validator_merkle_root),
+                max_total_claim: node.max_total_claim,
+                max_num_nodes: node.max_num_nodes,
+                node_hash: hex::encode(node_hash.to_bytes()),
+                leaf: hex::encode(leaf(&node_hash).to_bytes()),
+                proof: get_proof(&tree, i).iter().map(hex::encode).collect(),
+            }
+        })
+        .collect();
+
+    Ok(MerkleTestVector {
+        description: description.to_string(),
+        merkle_root: hex::encode(merkle_root.to_bytes()),
+        nodes,
+    })
+}
+
+/// Built the way a [`generated_merkle_tree::GeneratedMerkleTree`] builds its tree
+fn generated_test_vector(
+    description: &str,
+    tree_nodes: Vec<generated_merkle_tree::TreeNode>,
+) -> Result<GeneratedMerkleTestVector> {
+    let hashed_nodes: Vec<[u8; 32]> = tree_nodes
+        .iter()
+        .map(|node| node.hash().to_bytes())
+        .collect();
+    let tree = MerkleTree::new(&hashed_nodes[..], true);
+    let merkle_root = tree.get_root().ok_or(MerkleTreeError::MerkleRootError)?;
+
+    let nodes = tree_nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| {
+            let node_hash = node.hash();
+            GeneratedMerkleTestVectorNode {
+                claimant: node.claimant.to_string(),
+                amount: node.amount,
+                node_hash: hex::encode(node_hash.to_bytes()),
+                leaf: hex::encode(leaf(&node_hash).to_bytes()),
+                proof: get_proof(&tree, i).iter().map(hex::encode).collect(),
+            }
+        })
+        .collect();
+
+    Ok(GeneratedMerkleTestVector {
+        description: description.to_string(),
+        merkle_root: hex::encode(merkle_root.to_bytes()),
+        nodes,
+    })
+}
+
+fn leaf(node_hash: &Hash) -> Hash {
+    hashv(&[LEAF_PREFIX, &node_hash.to_bytes()])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{meta_merkle_tree::MetaMerkleTree, verify::verify_proof};
+
+    #[test]
+    fn test_vectors_are_stable() {
+        let published: Vec<MerkleTestVector> =
+            serde_json::from_str(include_str!("../test_vectors/meta_merkle_tree.json")).unwrap();
+
+        // Regenerate with `cargo run -p meta-merkle-tree --bin generate_test_vectors` only
+        // when the hashing is meant to change
+        assert_eq!(generate_test_vectors().unwrap(), published);
+
+        let published: Vec<GeneratedMerkleTestVector> =
+            serde_json::from_str(include_str!("../test_vectors/generated_merkle_tree.json"))
+                .unwrap();
+        assert_eq!(
+            generate_generated_merkle_tree_test_vectors().unwrap(),
+            published
+        );
+    }
+
+    #[test]
+    fn test_vectors_match_meta_merkle_tree() {
+        let vector = test_vector("odd count, 7 nodes", (0..7).map(test_node).collect()).unwrap();
+        let tree = MetaMerkleTree::new((0..7).map(test_node).collect()).unwrap();
+
+        assert_eq!(vector.merkle_root, hex::encode(tree.merkle_root));
+        for (vector_node, tree_node) in vector.nodes.iter().zip(tree.tree_nodes().iter()) {
+            let proof: Vec<String> = tree_node
+                .proof
+                .as_ref()
+                .unwrap()
+                .iter()
+                .map(hex::encode)
+                .collect();
+            assert_eq!(vector_node.proof, proof);
+        }
+    }
+
+    #[test]
+    fn test_vectors_verify() {
+        for vector in generate_test_vectors().unwrap() {
+            let root: [u8; 32] = hex::decode(&vector.merkle_root)
+                .unwrap()
+                .try_into()
+                .unwrap();
+            for node in vector.nodes {
+                let leaf: [u8; 32] = hex::decode(&node.leaf).unwrap().try_into().unwrap();
+                let proof: Vec<[u8; 32]> = node
+                    .proof
+                    .iter()
+                    .map(|hash| hex::decode(hash).unwrap().try_into().unwrap())
+                    .collect();
+                assert!(verify_proof(&proof, root, leaf), "{}", vector.description);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_merkle_tree_vectors_verify() {
+        for vector in generate_generated_merkle_tree_test_vectors().unwrap() {
+            let root: [u8; 32] = hex::decode(&vector.merkle_root)
+                .unwrap()
+                .try_into()
+                .unwrap();
+            for node in vector.nodes {
+                let claimant = Pubkey::from_str(&node.claimant).unwrap();
+                let node_hash = hashv(&[claimant.as_ref(), &node.amount.to_le_bytes()]);
+                assert_eq!(node.node_hash, hex::encode(node_hash.to_bytes()));
+                assert_eq!(node.leaf, hex::encode(leaf(&node_hash).to_bytes()));
+
+                let proof: Vec<[u8; 32]> = node
+                    .proof
+                    .iter()
+                    .map(|hash| hex::decode(hash).unwrap().try_into().unwrap())
+                    .collect();
+                assert!(
+                    verify_proof(&proof, root, leaf(&node_hash).to_bytes()),
+                    "{}",
+                    vector.description
+                );
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
 use solana_program::{
     hash::{hashv, Hash},
@@ -7,7 +8,9 @@ use solana_program::{
 use crate::generated_merkle_tree::GeneratedMerkleTree;
 
 /// Represents the information for activating a tip distribution account.
-#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
 pub struct TreeNode {
     /// Pubkey of the vote account for setting the merkle root
     pub tip_distribution_account: Pubkey,
@@ -1,3 +1,5 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
 use solana_program::hash::hashv;
 
 /// modified version of https://github.com/saber-hq/merkle-distributor/blob/ac937d1901033ecb7fa3b0db22f7b39569c8e052/programs/merkle-distributor/src/merkle_proof.rs#L8
@@ -8,16 +10,101 @@ use solana_program::hash::hashv;
 /// sibling hashes on the branch from the leaf to the root of the tree. Each
 /// pair of leaves and each pair of pre-images are assumed to be sorted.
 pub fn verify(proof: Vec<[u8; 32]>, root: [u8; 32], leaf: [u8; 32]) -> bool {
-    let mut computed_hash = leaf;
-    for proof_element in proof.into_iter() {
-        if computed_hash <= proof_element {
-            // Hash(current computed hash + current element of the proof)
-            computed_hash = hashv(&[&[1u8], &computed_hash, &proof_element]).to_bytes();
-        } else {
-            // Hash(current element of the proof + current computed hash)
-            computed_hash = hashv(&[&[1u8], &proof_element, &computed_hash]).to_bytes();
-        }
-    }
+    verify_proof(&proof, root, leaf)
+}
+
+/// Same as [`verify`], borrowing the proof
+pub fn verify_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed_hash = proof.iter().fold(leaf, |computed_hash, proof_element| {
+        hash_sorted_pair(&computed_hash, proof_element)
+    });
     // Check if the computed hash (root) is equal to the provided root
     computed_hash == root
 }
+
+/// Proves several leaves of one tree against its root, sharing the hashes their paths have in
+/// common. Generated by [`crate::merkle_tree::MerkleTree::find_multiproof`].
+///
+/// `hashes` are the siblings that cannot be computed from the proven leaves, level by level
+/// from the leaves up and by ascending index within a level. A node without a sibling at the
+/// end of an odd length level is paired with itself, as when the tree was built.
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+pub struct MultiProof {
+    /// Number of leaves in the tree
+    pub leaf_count: u32,
+    /// Indices of the proven leaves, strictly ascending
+    pub indices: Vec<u32>,
+    pub hashes: Vec<[u8; 32]>,
+}
+
+/// Returns true if every one of `leaves`, at the matching position of `proof.indices`, is part
+/// of the tree defined by `root`. Pairs are hashed sorted, as in [`verify`]. Rejects proofs
+/// with unsorted or out of range indices, and proofs with hashes left over.
+pub fn verify_multiproof(proof: &MultiProof, root: [u8; 32], leaves: &[[u8; 32]]) -> bool {
+    let leaf_count = proof.leaf_count as usize;
+    if leaves.is_empty() || leaves.len() != proof.indices.len() {
+        return false;
+    }
+    if !proof.indices.windows(2).all(|pair| pair[0] < pair[1]) {
+        return false;
+    }
+    if proof
+        .indices
+        .iter()
+        .any(|index| *index as usize >= leaf_count)
+    {
+        return false;
+    }
+
+    let mut level: Vec<(usize, [u8; 32])> = proof
+        .indices
+        .iter()
+        .map(|index| *index as usize)
+        .zip(leaves.iter().copied())
+        .collect();
+    let mut level_len = leaf_count;
+    let mut hashes = proof.hashes.iter();
+
+    while level_len > 1 {
+        let mut next_level = Vec::with_capacity(level.len());
+        let mut i = 0;
+        while i < level.len() {
+            let (index, hash) = level[i];
+            let sibling_hash = if index % 2 == 1 {
+                // A known left sibling would have been paired with this node already
+                match hashes.next() {
+                    Some(sibling_hash) => *sibling_hash,
+                    None => return false,
+                }
+            } else if index + 1 >= level_len {
+                hash
+            } else if i + 1 < level.len() && level[i + 1].0 == index + 1 {
+                i += 1;
+                level[i].1
+            } else {
+                match hashes.next() {
+                    Some(sibling_hash) => *sibling_hash,
+                    None => return false,
+                }
+            };
+
+            next_level.push((index / 2, hash_sorted_pair(&hash, &sibling_hash)));
+            i += 1;
+        }
+
+        level = next_level;
+        level_len = (level_len + 1) / 2;
+    }
+
+    hashes.next().is_none() && level[0].1 == root
+}
+
+fn hash_sorted_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a <= b {
+        hashv(&[&[1u8], a, b]).to_bytes()
+    } else {
+        hashv(&[&[1u8], b, a]).to_bytes()
+    }
+}
@@ -0,0 +1,45 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::{config::Config, ncn::Ncn};
+use jito_tip_router_core::{ballot_box::BallotBox, ncn_config::NcnConfig};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::close_epoch_account::close_epoch_account;
+
+/// Closes an expired ballot box, permissionless
+pub fn process_close_ballot_box(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [ncn_config, restaking_config, ncn, ballot_box, epoch_reward_router, payer, restaking_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    NcnConfig::load(program_id, ncn.key, ncn_config, false)?;
+    Config::load(restaking_program.key, restaking_config, false)?;
+    Ncn::load(restaking_program.key, ncn, false)?;
+    BallotBox::load(program_id, ncn.key, epoch, ballot_box, true)?;
+
+    let recorded_payer = {
+        let ballot_box_data = ballot_box.data.borrow();
+        BallotBox::try_from_slice_unchecked(&ballot_box_data)?.payer()
+    };
+
+    close_epoch_account(
+        program_id,
+        ncn_config,
+        restaking_config,
+        ncn.key,
+        ballot_box,
+        epoch_reward_router,
+        epoch,
+        &recorded_payer,
+        ballot_box,
+        payer,
+    )
+}
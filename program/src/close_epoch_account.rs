@@ -0,0 +1,137 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_tip_router_core::{
+    ballot_box::BallotBox, epoch_reward_router::EpochRewardRouter, error::TipRouterError,
+    ncn_config::NcnConfig, ncn_epoch::NcnEpochSchedule,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_program, sysvar::Sysvar,
+};
+
+/// Closes a per-epoch account once `epochs_before_close` NCN epochs have passed since the
+/// epoch's ballot box reached consensus, or since voting stalled without it, and the epoch's
+/// rewards have been distributed. Returns its rent to the payer recorded at creation.
+#[allow(clippy::too_many_arguments)]
+pub fn close_epoch_account<'a, 'info>(
+    program_id: &Pubkey,
+    ncn_config: &'a AccountInfo<'info>,
+    restaking_config: &'a AccountInfo<'info>,
+    ncn: &Pubkey,
+    ballot_box: &'a AccountInfo<'info>,
+    epoch_reward_router: &'a AccountInfo<'info>,
+    epoch: u64,
+    recorded_payer: &Pubkey,
+    account_to_close: &'a AccountInfo<'info>,
+    payer: &'a AccountInfo<'info>,
+) -> ProgramResult {
+    let current_epoch = NcnEpochSchedule::load(restaking_config)?.ncn_epoch(Clock::get()?.slot);
+    let consensus_reached = is_consensus_reached(program_id, ncn, epoch, ballot_box)?;
+
+    let can_close = {
+        let ncn_config_data = ncn_config.data.borrow();
+        NcnConfig::try_from_slice_unchecked(&ncn_config_data)?.can_close_epoch(
+            epoch,
+            current_epoch,
+            consensus_reached,
+        )?
+    };
+
+    if !can_close {
+        msg!(
+            "Accounts for epoch {} cannot be closed in epoch {}",
+            epoch,
+            current_epoch
+        );
+        return Err(TipRouterError::CannotCloseAccount.into());
+    }
+
+    // Routing and distributing rewards needs the accounts of the epoch
+    if has_pending_rewards(program_id, ncn, epoch, epoch_reward_router)? {
+        msg!("Rewards for epoch {} have not been distributed", epoch);
+        return Err(TipRouterError::RewardsNotDistributed.into());
+    }
+
+    if payer.key.ne(recorded_payer) {
+        msg!("Rent can only be returned to the original payer");
+        return Err(TipRouterError::IncorrectPayer.into());
+    }
+
+    if !payer.is_writable {
+        msg!("Payer account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!(
+        "Closing {} and refunding {}",
+        account_to_close.key,
+        payer.key
+    );
+
+    **payer.lamports.borrow_mut() = payer
+        .lamports()
+        .checked_add(account_to_close.lamports())
+        .ok_or(TipRouterError::ArithmeticOverflow)?;
+    **account_to_close.lamports.borrow_mut() = 0;
+
+    account_to_close.assign(&system_program::ID);
+    account_to_close.realloc(0, false)?;
+
+    Ok(())
+}
+
+/// Whether the ballot box of `epoch` reached consensus. One that was never initialized, or was
+/// already closed, has not.
+fn is_consensus_reached(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+    ballot_box: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    if ballot_box.data_is_empty() {
+        if ballot_box
+            .key
+            .ne(&BallotBox::find_program_address(program_id, ncn, epoch).0)
+        {
+            msg!("Ballot box account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        return Ok(false);
+    }
+
+    BallotBox::load(program_id, ncn, epoch, ballot_box, false)?;
+    let ballot_box_data = ballot_box.data.borrow();
+    Ok(BallotBox::try_from_slice_unchecked(&ballot_box_data)?.is_consensus_reached())
+}
+
+/// Whether the epoch reward router of `epoch` still holds rewards to route or distribute. One
+/// that was never initialized, or was already closed, holds rewards if lamports were sent to it.
+fn has_pending_rewards(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+    epoch_reward_router: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    if epoch_reward_router.data_is_empty() {
+        if epoch_reward_router
+            .key
+            .ne(&EpochRewardRouter::find_program_address(program_id, ncn, epoch).0)
+        {
+            msg!("Epoch reward router account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        return Ok(epoch_reward_router.lamports() > 0);
+    }
+
+    EpochRewardRouter::load(program_id, ncn, epoch, epoch_reward_router, false)?;
+
+    let rewards_held = epoch_reward_router
+        .lamports()
+        .checked_sub(Rent::get()?.minimum_balance(epoch_reward_router.data_len()))
+        .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+    let epoch_reward_router_data = epoch_reward_router.data.borrow();
+    Ok(
+        EpochRewardRouter::try_from_slice_unchecked(&epoch_reward_router_data)?
+            .has_pending_rewards(rewards_held)?,
+    )
+}
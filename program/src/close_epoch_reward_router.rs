@@ -0,0 +1,46 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::{config::Config, ncn::Ncn};
+use jito_tip_router_core::{epoch_reward_router::EpochRewardRouter, ncn_config::NcnConfig};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::close_epoch_account::close_epoch_account;
+
+/// Closes an expired epoch reward router once its rewards are distributed, permissionless.
+/// Rounding dust left behind goes to the payer with the rent.
+pub fn process_close_epoch_reward_router(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [ncn_config, restaking_config, ncn, ballot_box, epoch_reward_router, payer, restaking_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    NcnConfig::load(program_id, ncn.key, ncn_config, false)?;
+    Config::load(restaking_program.key, restaking_config, false)?;
+    Ncn::load(restaking_program.key, ncn, false)?;
+    EpochRewardRouter::load(program_id, ncn.key, epoch, epoch_reward_router, true)?;
+
+    let recorded_payer = {
+        let epoch_reward_router_data = epoch_reward_router.data.borrow();
+        EpochRewardRouter::try_from_slice_unchecked(&epoch_reward_router_data)?.payer()
+    };
+
+    close_epoch_account(
+        program_id,
+        ncn_config,
+        restaking_config,
+        ncn.key,
+        ballot_box,
+        epoch_reward_router,
+        epoch,
+        &recorded_payer,
+        epoch_reward_router,
+        payer,
+    )
+}
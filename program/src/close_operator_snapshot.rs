@@ -0,0 +1,53 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::{config::Config, ncn::Ncn, operator::Operator};
+use jito_tip_router_core::{epoch_snapshot::OperatorSnapshot, ncn_config::NcnConfig};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::close_epoch_account::close_epoch_account;
+
+/// Closes an expired operator snapshot, permissionless
+pub fn process_close_operator_snapshot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [ncn_config, restaking_config, ncn, ballot_box, epoch_reward_router, operator, operator_snapshot, payer, restaking_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    NcnConfig::load(program_id, ncn.key, ncn_config, false)?;
+    Config::load(restaking_program.key, restaking_config, false)?;
+    Ncn::load(restaking_program.key, ncn, false)?;
+    Operator::load(restaking_program.key, operator, false)?;
+    OperatorSnapshot::load(
+        program_id,
+        operator.key,
+        ncn.key,
+        epoch,
+        operator_snapshot,
+        true,
+    )?;
+
+    let recorded_payer = {
+        let operator_snapshot_data = operator_snapshot.data.borrow();
+        OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_data)?.payer()
+    };
+
+    close_epoch_account(
+        program_id,
+        ncn_config,
+        restaking_config,
+        ncn.key,
+        ballot_box,
+        epoch_reward_router,
+        epoch,
+        &recorded_payer,
+        operator_snapshot,
+        payer,
+    )
+}
@@ -0,0 +1,45 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::{config::Config, ncn::Ncn};
+use jito_tip_router_core::{epoch_snapshot::VaultSnapshot, ncn_config::NcnConfig};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::close_epoch_account::close_epoch_account;
+
+/// Closes an expired vault snapshot, permissionless
+pub fn process_close_vault_snapshot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [ncn_config, restaking_config, ncn, ballot_box, epoch_reward_router, vault, vault_snapshot, payer, restaking_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    NcnConfig::load(program_id, ncn.key, ncn_config, false)?;
+    Config::load(restaking_program.key, restaking_config, false)?;
+    Ncn::load(restaking_program.key, ncn, false)?;
+    VaultSnapshot::load(program_id, vault.key, ncn.key, epoch, vault_snapshot, true)?;
+
+    let recorded_payer = {
+        let vault_snapshot_data = vault_snapshot.data.borrow();
+        VaultSnapshot::try_from_slice_unchecked(&vault_snapshot_data)?.payer()
+    };
+
+    close_epoch_account(
+        program_id,
+        ncn_config,
+        restaking_config,
+        ncn.key,
+        ballot_box,
+        epoch_reward_router,
+        epoch,
+        &recorded_payer,
+        vault_snapshot,
+        payer,
+    )
+}
@@ -0,0 +1,90 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_system_account;
+use jito_restaking_core::{ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
+use jito_tip_router_core::{
+    error::TipRouterError, ncn_epoch::NcnEpochSchedule, tracked_mints::TrackedMints,
+    weight_table::WeightTable,
+};
+use jito_vault_core::{vault::Vault, vault_ncn_ticket::VaultNcnTicket};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+/// Deregisters the mint of a vault once either of its tickets is no longer active
+/// Permissionless, and locked for the epoch once the weight table is initialized
+pub fn process_deregister_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [restaking_config, tracked_mints, ncn, weight_table, vault, vault_ncn_ticket, ncn_vault_ticket, restaking_program_id, vault_program_id] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    TrackedMints::load(program_id, ncn.key, tracked_mints, true)?;
+    Ncn::load(restaking_program_id.key, ncn, false)?;
+    Vault::load(vault_program_id.key, vault, false)?;
+    VaultNcnTicket::load(vault_program_id.key, vault_ncn_ticket, vault, ncn, false)?;
+    NcnVaultTicket::load(
+        restaking_program_id.key,
+        ncn_vault_ticket,
+        ncn,
+        vault,
+        false,
+    )?;
+
+    let ncn_epoch_schedule = NcnEpochSchedule::load(restaking_config)?;
+    let epoch_length = ncn_epoch_schedule.ncn_epoch_length();
+
+    let slot = Clock::get()?.slot;
+
+    let ncn_epoch = ncn_epoch_schedule.ncn_epoch(slot);
+
+    // Same as registering, the tracked mints can't change once the weight table is initialized
+    if weight_table.owner.eq(&system_program::ID) {
+        let expected_pubkey = WeightTable::find_program_address(program_id, ncn.key, ncn_epoch).0;
+        if weight_table.key.ne(&expected_pubkey) {
+            msg!("Weight table incorrect PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        load_system_account(weight_table, false)?;
+    }
+
+    if weight_table.owner.eq(program_id) {
+        WeightTable::load(program_id, weight_table, ncn, ncn_epoch, false)?;
+        return Err(TipRouterError::TrackedMintsLocked.into());
+    }
+
+    // Verify at least one of the tickets is no longer active
+    let vault_ncn_ticket_active = {
+        let vault_ncn_ticket_data = vault_ncn_ticket.data.borrow();
+        VaultNcnTicket::try_from_slice_unchecked(&vault_ncn_ticket_data)?
+            .state
+            .is_active_or_cooldown(slot, epoch_length)
+    };
+
+    let ncn_vault_ticket_active = {
+        let ncn_vault_ticket_data = ncn_vault_ticket.data.borrow();
+        NcnVaultTicket::try_from_slice_unchecked(&ncn_vault_ticket_data)?
+            .state
+            .is_active_or_cooldown(slot, epoch_length)
+    };
+
+    if vault_ncn_ticket_active && ncn_vault_ticket_active {
+        msg!("Vault NCN ticket and NCN vault ticket are both active");
+        return Err(TipRouterError::VaultStillActive.into());
+    }
+
+    let vault_data = vault.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+
+    let mut tracked_mints_data = tracked_mints.try_borrow_mut_data()?;
+    let tracked_mints = TrackedMints::try_from_slice_unchecked_mut(&mut tracked_mints_data)?;
+    tracked_mints.deregister_mint(vault.vault_index())?;
+
+    Ok(())
+}
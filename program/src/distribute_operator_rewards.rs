@@ -0,0 +1,173 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_tip_router_core::{
+    epoch_reward_router::{split_operator_rewards, EpochRewardRouter},
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot, VaultOperatorStakeWeight, VaultSnapshot},
+    error::TipRouterError,
+    ncn_config::NcnConfig,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::route_ncn_rewards::transfer_lamports;
+
+const ACCOUNTS_PER_VAULT: usize = 2;
+
+/// Pays an operator and its vaults the share of routed rewards owed to them, to the fee
+/// wallets recorded in their snapshots. Remaining accounts are `[vault_snapshot, fee_wallet]`
+/// pairs for the vaults with non-zero stake weight, in operator snapshot order.
+pub fn process_distribute_operator_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [ncn_config, ncn, operator, operator_fee_wallet, epoch_snapshot, operator_snapshot, epoch_reward_router, restaking_program, vault_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if vault_accounts.len() % ACCOUNTS_PER_VAULT != 0 {
+        msg!("Expected pairs of vault snapshot and fee wallet accounts");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    if restaking_program.key.ne(&jito_restaking_program::id()) {
+        msg!("Incorrect restaking program ID");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    NcnConfig::load(program_id, ncn.key, ncn_config, false)?;
+    Ncn::load(restaking_program.key, ncn, false)?;
+    Operator::load(restaking_program.key, operator, false)?;
+    EpochSnapshot::load(program_id, ncn.key, epoch, epoch_snapshot, false)?;
+    OperatorSnapshot::load(
+        program_id,
+        operator.key,
+        ncn.key,
+        epoch,
+        operator_snapshot,
+        true,
+    )?;
+    EpochRewardRouter::load(program_id, ncn.key, epoch, epoch_reward_router, true)?;
+
+    let total_stake_weight = {
+        let epoch_snapshot_data = epoch_snapshot.data.borrow();
+        let epoch_snapshot_account = EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?;
+
+        if !epoch_snapshot_account.finalized() {
+            msg!("Rewards can only be distributed once the epoch snapshot is finalized");
+            return Err(TipRouterError::EpochSnapshotNotFinalized.into());
+        }
+
+        epoch_snapshot_account.stake_weight()
+    };
+
+    let mut operator_snapshot_data = operator_snapshot.try_borrow_mut_data()?;
+    let operator_snapshot_account =
+        OperatorSnapshot::try_from_slice_unchecked_mut(&mut operator_snapshot_data)?;
+
+    let mut epoch_reward_router_data = epoch_reward_router.try_borrow_mut_data()?;
+    let epoch_reward_router_account =
+        EpochRewardRouter::try_from_slice_unchecked_mut(&mut epoch_reward_router_data)?;
+
+    if operator_fee_wallet
+        .key
+        .ne(&operator_snapshot_account.operator_fee_wallet())
+    {
+        msg!(
+            "Operator fee wallet does not match {}",
+            operator_snapshot_account.operator_fee_wallet()
+        );
+        return Err(TipRouterError::IncorrectFeeWallet.into());
+    }
+    if !operator_fee_wallet.is_writable {
+        msg!("Operator fee wallet is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let rewards = epoch_reward_router_account
+        .operator_reward_entitlement(operator_snapshot_account.stake_weight(), total_stake_weight)?
+        .saturating_sub(operator_snapshot_account.rewards_routed());
+
+    if rewards == 0 {
+        msg!("No rewards owed to operator {}", operator.key);
+        return Ok(());
+    }
+
+    let vault_stake_weights: Vec<VaultOperatorStakeWeight> = operator_snapshot_account
+        .vault_operator_stake_weights()
+        .iter()
+        .filter(|vault| vault.stake_weight() > 0)
+        .copied()
+        .collect();
+
+    if vault_accounts.len() / ACCOUNTS_PER_VAULT != vault_stake_weights.len() {
+        msg!(
+            "Expected {} vault account pairs, got {}",
+            vault_stake_weights.len(),
+            vault_accounts.len() / ACCOUNTS_PER_VAULT
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut vault_fee_wallets = Vec::with_capacity(vault_stake_weights.len());
+    for (group, vault_stake_weight) in vault_accounts
+        .chunks_exact(ACCOUNTS_PER_VAULT)
+        .zip(vault_stake_weights.iter())
+    {
+        let [vault_snapshot, vault_fee_wallet] = group else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        VaultSnapshot::load(
+            program_id,
+            &vault_stake_weight.vault(),
+            ncn.key,
+            epoch,
+            vault_snapshot,
+            false,
+        )?;
+
+        let expected_fee_wallet = {
+            let vault_snapshot_data = vault_snapshot.data.borrow();
+            VaultSnapshot::try_from_slice_unchecked(&vault_snapshot_data)?.fee_wallet()
+        };
+        if vault_fee_wallet.key.ne(&expected_fee_wallet) {
+            msg!("Vault fee wallet does not match {}", expected_fee_wallet);
+            return Err(TipRouterError::IncorrectFeeWallet.into());
+        }
+        if !vault_fee_wallet.is_writable {
+            msg!("Vault fee wallet {} is not writable", vault_fee_wallet.key);
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        vault_fee_wallets.push(vault_fee_wallet);
+    }
+
+    let (operator_rewards, vault_rewards) = split_operator_rewards(
+        rewards,
+        operator_snapshot_account.operator_fee_bps(),
+        &vault_stake_weights,
+        operator_snapshot_account.stake_weight(),
+    )?;
+
+    operator_snapshot_account.increment_rewards_routed(rewards)?;
+    epoch_reward_router_account.record_operator_distribution(rewards)?;
+
+    msg!(
+        "Distributing {} lamports: {} to operator {}",
+        rewards,
+        operator_rewards,
+        operator.key
+    );
+
+    transfer_lamports(epoch_reward_router, operator_fee_wallet, operator_rewards)?;
+    for (vault_fee_wallet, vault_reward) in vault_fee_wallets.into_iter().zip(vault_rewards) {
+        transfer_lamports(epoch_reward_router, vault_fee_wallet, vault_reward)?;
+    }
+
+    Ok(())
+}
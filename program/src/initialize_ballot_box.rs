@@ -50,7 +50,13 @@ pub fn process_initialize_ballot_box(
     ballot_box_data[0] = BallotBox::DISCRIMINATOR;
     let ballot_box_account = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
 
-    ballot_box_account.initialize(*ncn_account.key, epoch, ballot_box_bump, Clock::get()?.slot);
+    ballot_box_account.initialize(
+        *ncn_account.key,
+        epoch,
+        ballot_box_bump,
+        Clock::get()?.slot,
+        *payer.key,
+    );
 
     Ok(())
 }
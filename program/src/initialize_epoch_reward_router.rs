@@ -0,0 +1,69 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_tip_router_core::{epoch_reward_router::EpochRewardRouter, ncn_config::NcnConfig};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Initializes the account that receives the NCN share of tips for an epoch
+pub fn process_initialize_epoch_reward_router(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [ncn_config, ncn, epoch_reward_router, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_account(epoch_reward_router, true)?;
+    load_system_program(system_program)?;
+    load_signer(payer, false)?;
+
+    NcnConfig::load(program_id, ncn.key, ncn_config, false)?;
+
+    let (epoch_reward_router_pda, epoch_reward_router_bump, mut epoch_reward_router_seeds) =
+        EpochRewardRouter::find_program_address(program_id, ncn.key, epoch);
+    epoch_reward_router_seeds.push(vec![epoch_reward_router_bump]);
+
+    if epoch_reward_router_pda.ne(epoch_reward_router.key) {
+        msg!("Incorrect epoch reward router PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    msg!(
+        "Initializing Epoch Reward Router {} for NCN: {} at epoch: {}",
+        epoch_reward_router.key,
+        ncn.key,
+        epoch
+    );
+    create_account(
+        payer,
+        epoch_reward_router,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(std::mem::size_of::<EpochRewardRouter>() as u64)
+            .unwrap(),
+        &epoch_reward_router_seeds,
+    )?;
+
+    let mut epoch_reward_router_data = epoch_reward_router.try_borrow_mut_data()?;
+    epoch_reward_router_data[0] = EpochRewardRouter::DISCRIMINATOR;
+    let epoch_reward_router_account =
+        EpochRewardRouter::try_from_slice_unchecked_mut(&mut epoch_reward_router_data)?;
+
+    *epoch_reward_router_account = EpochRewardRouter::new(
+        *ncn.key,
+        epoch,
+        epoch_reward_router_bump,
+        Clock::get()?.slot,
+        *payer.key,
+    );
+
+    Ok(())
+}
@@ -5,7 +5,7 @@ use jito_jsm_core::{
 };
 use jito_restaking_core::{config::Config, ncn::Ncn};
 use jito_tip_router_core::{
-    epoch_snapshot::EpochSnapshot, error::TipRouterError, fees, loaders::load_ncn_epoch,
+    epoch_snapshot::EpochSnapshot, error::TipRouterError, fees, loaders::load_snapshot_ncn_epoch,
     ncn_config::NcnConfig, tracked_mints::TrackedMints, weight_table::WeightTable,
 };
 use solana_program::{
@@ -41,7 +41,12 @@ pub fn process_initialize_epoch_snapshot(
     load_signer(payer, false)?;
 
     let current_slot = Clock::get()?.slot;
-    let (ncn_epoch, _) = load_ncn_epoch(restaking_config, current_slot, first_slot_of_ncn_epoch)?;
+    let (ncn_epoch, _) = load_snapshot_ncn_epoch(
+        ncn_config,
+        restaking_config,
+        current_slot,
+        first_slot_of_ncn_epoch,
+    )?;
 
     WeightTable::load(program_id, weight_table, ncn, ncn_epoch, false)?;
 
@@ -83,10 +88,10 @@ pub fn process_initialize_epoch_snapshot(
         &epoch_snapshot_seeds,
     )?;
 
-    let ncn_fees: fees::Fees = {
+    let fee: fees::Fee = {
         let ncn_config_data = ncn_config.data.borrow();
         let ncn_config_account = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
-        ncn_config_account.fees
+        *ncn_config_account.fees.current_fee(ncn_epoch)
     };
 
     let operator_count: u64 = {
@@ -117,9 +122,10 @@ pub fn process_initialize_epoch_snapshot(
         ncn_epoch,
         epoch_snapshot_bump,
         current_slot,
-        ncn_fees,
+        fee,
         operator_count,
         vault_count,
+        *payer.key,
     );
 
     Ok(())
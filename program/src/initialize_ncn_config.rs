@@ -6,6 +6,7 @@ use jito_jsm_core::{
 use jito_restaking_core::{config::Config, ncn::Ncn};
 use jito_tip_router_core::{
     constants::MAX_FEE_BPS, error::TipRouterError, fees::Fees, ncn_config::NcnConfig,
+    ncn_epoch::NcnEpochSchedule,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
@@ -19,7 +20,7 @@ pub fn process_initialize_ncn_config(
     ncn_fee_bps: u64,
     block_engine_fee_bps: u64,
 ) -> ProgramResult {
-    let [restaking_config, ncn_config, ncn_account, fee_wallet, ncn_admin, tie_breaker_admin, restaking_program, system_program] =
+    let [restaking_config, ncn_config, ncn_account, dao_fee_wallet, ncn_fee_wallet, ncn_admin, tie_breaker_admin, restaking_program, system_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -32,18 +33,7 @@ pub fn process_initialize_ncn_config(
     Ncn::load(restaking_program.key, ncn_account, false)?;
     Config::load(restaking_program.key, restaking_config, false)?;
 
-    let ncn_epoch_length = {
-        let config_data = restaking_config.data.borrow();
-        let config = Config::try_from_slice_unchecked(&config_data)?;
-        config.epoch_length()
-    };
-
-    let epoch = {
-        let current_slot = Clock::get()?.slot;
-        current_slot
-            .checked_div(ncn_epoch_length)
-            .ok_or(TipRouterError::DenominatorIsZero)?
-    };
+    let epoch = NcnEpochSchedule::load(restaking_config)?.ncn_epoch(Clock::get()?.slot);
 
     let ncn_data = ncn_account.data.borrow();
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
@@ -89,7 +79,8 @@ pub fn process_initialize_ncn_config(
         *tie_breaker_admin.key,
         *ncn_admin.key,
         Fees::new(
-            *fee_wallet.key,
+            *dao_fee_wallet.key,
+            *ncn_fee_wallet.key,
             dao_fee_bps,
             ncn_fee_bps,
             block_engine_fee_bps,
@@ -7,8 +7,8 @@ use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
 };
 use jito_tip_router_core::{
-    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
-    loaders::load_ncn_epoch,
+    epoch_snapshot::{EpochSnapshot, ExclusionReason, OperatorSnapshot},
+    loaders::load_snapshot_ncn_epoch,
     ncn_config::NcnConfig,
 };
 use solana_program::{
@@ -51,8 +51,12 @@ pub fn process_initialize_operator_snapshot(
     load_signer(payer, false)?;
 
     let current_slot = Clock::get()?.slot;
-    let (ncn_epoch, ncn_epoch_length) =
-        load_ncn_epoch(restaking_config, current_slot, first_slot_of_ncn_epoch)?;
+    let (ncn_epoch, ncn_epoch_schedule) = load_snapshot_ncn_epoch(
+        ncn_config,
+        restaking_config,
+        current_slot,
+        first_slot_of_ncn_epoch,
+    )?;
 
     EpochSnapshot::load(program_id, ncn.key, ncn_epoch, epoch_snapshot, true)?;
 
@@ -84,35 +88,46 @@ pub fn process_initialize_operator_snapshot(
     )?;
 
     //TODO move to helper function
-    let (is_active, ncn_operator_index): (bool, u64) = {
+    let (exclusion_reason, ncn_operator_index): (ExclusionReason, u64) = {
         let ncn_operator_state_data = ncn_operator_state.data.borrow();
         let ncn_operator_state_account =
             NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
 
         let ncn_operator_okay = ncn_operator_state_account
             .ncn_opt_in_state
-            .is_active(current_slot, ncn_epoch_length);
+            .is_active(current_slot, ncn_epoch_schedule.ncn_epoch_length());
 
         let operator_ncn_okay = ncn_operator_state_account
             .operator_opt_in_state
-            .is_active(current_slot, ncn_epoch_length);
+            .is_active(current_slot, ncn_epoch_schedule.ncn_epoch_length());
 
         let ncn_operator_index = ncn_operator_state_account.index();
 
-        (ncn_operator_okay && operator_ncn_okay, ncn_operator_index)
+        let exclusion_reason = if !ncn_operator_okay {
+            ExclusionReason::NcnOptInInactive
+        } else if !operator_ncn_okay {
+            ExclusionReason::OperatorOptInInactive
+        } else {
+            ExclusionReason::NotExcluded
+        };
+
+        (exclusion_reason, ncn_operator_index)
     };
 
+    let is_active = exclusion_reason == ExclusionReason::NotExcluded;
+
     let vault_count = {
         let epoch_snapshot_data = epoch_snapshot.data.borrow();
         let epoch_snapshot_account = EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?;
         epoch_snapshot_account.vault_count()
     };
 
-    let (operator_fee_bps, operator_index): (u16, u64) = {
+    let (operator_fee_bps, operator_fee_wallet, operator_index): (u16, Pubkey, u64) = {
         let operator_data = operator.data.borrow();
         let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
         (
             operator_account.operator_fee_bps.into(),
+            operator_account.admin,
             operator_account.index(),
         )
     };
@@ -133,7 +148,9 @@ pub fn process_initialize_operator_snapshot(
             ncn_operator_index,
             operator_index,
             operator_fee_bps,
+            operator_fee_wallet,
             vault_count,
+            *payer.key,
         )?
     } else {
         OperatorSnapshot::new_inactive(
@@ -144,6 +161,8 @@ pub fn process_initialize_operator_snapshot(
             current_slot,
             ncn_operator_index,
             operator_index,
+            *payer.key,
+            exclusion_reason,
         )?
     };
 
@@ -0,0 +1,114 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::{config::Config, ncn::Ncn};
+use jito_tip_router_core::{
+    epoch_snapshot::{EpochSnapshot, VaultSnapshot},
+    loaders::load_snapshot_ncn_epoch,
+    ncn_config::NcnConfig,
+};
+use jito_vault_core::vault::Vault;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Initializes a Vault Snapshot
+pub fn process_initialize_vault_snapshot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    first_slot_of_ncn_epoch: Option<u64>,
+) -> ProgramResult {
+    let [ncn_config, restaking_config, ncn, vault, epoch_snapshot, vault_snapshot, payer, vault_program, restaking_program, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if vault_program.key.ne(&jito_vault_program::id()) {
+        msg!("Incorrect vault program ID");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if restaking_program.key.ne(&jito_restaking_program::id()) {
+        msg!("Incorrect restaking program ID");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    NcnConfig::load(program_id, ncn.key, ncn_config, false)?;
+    Config::load(restaking_program.key, restaking_config, false)?;
+    Ncn::load(restaking_program.key, ncn, false)?;
+    Vault::load(vault_program.key, vault, false)?;
+
+    load_system_account(vault_snapshot, true)?;
+    load_system_program(system_program)?;
+    load_signer(payer, false)?;
+
+    let current_slot = Clock::get()?.slot;
+    let (ncn_epoch, _) = load_snapshot_ncn_epoch(
+        ncn_config,
+        restaking_config,
+        current_slot,
+        first_slot_of_ncn_epoch,
+    )?;
+
+    EpochSnapshot::load(program_id, ncn.key, ncn_epoch, epoch_snapshot, false)?;
+
+    let (vault_snapshot_pubkey, vault_snapshot_bump, mut vault_snapshot_seeds) =
+        VaultSnapshot::find_program_address(program_id, vault.key, ncn.key, ncn_epoch);
+    vault_snapshot_seeds.push(vec![vault_snapshot_bump]);
+
+    if vault_snapshot_pubkey.ne(vault_snapshot.key) {
+        msg!("Incorrect vault snapshot PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!(
+        "Initializing Vault snapshot {} for NCN: {} at epoch: {}",
+        vault_snapshot.key,
+        ncn.key,
+        ncn_epoch
+    );
+    create_account(
+        payer,
+        vault_snapshot,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(std::mem::size_of::<VaultSnapshot>() as u64)
+            .unwrap(),
+        &vault_snapshot_seeds,
+    )?;
+
+    let (vault_index, st_mint, fee_wallet) = {
+        let vault_data = vault.data.borrow();
+        let vault_account = Vault::try_from_slice_unchecked(&vault_data)?;
+        (
+            vault_account.vault_index(),
+            vault_account.supported_mint,
+            vault_account.fee_wallet,
+        )
+    };
+
+    let mut vault_snapshot_data = vault_snapshot.try_borrow_mut_data()?;
+    vault_snapshot_data[0] = VaultSnapshot::DISCRIMINATOR;
+    let vault_snapshot_account =
+        VaultSnapshot::try_from_slice_unchecked_mut(&mut vault_snapshot_data)?;
+
+    *vault_snapshot_account = VaultSnapshot::new(
+        *vault.key,
+        *ncn.key,
+        ncn_epoch,
+        vault_snapshot_bump,
+        current_slot,
+        vault_index,
+        st_mint,
+        fee_wallet,
+        *payer.key,
+    );
+
+    Ok(())
+}
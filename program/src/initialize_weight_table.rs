@@ -49,13 +49,17 @@ pub fn process_initialize_weight_table(
         ncn.vault_count()
     };
 
-    let (tracked_mint_count, unique_mints) = {
+    // Deregistered vaults are still counted, since the NCN's vault count never decreases
+    let (tracked_vault_count, unique_mints) = {
         let tracked_mints_data = tracked_mints.data.borrow();
         let tracked_mints = TrackedMints::try_from_slice_unchecked(&tracked_mints_data)?;
-        (tracked_mints.mint_count(), tracked_mints.get_unique_mints())
+        (
+            tracked_mints.vault_count(),
+            tracked_mints.get_unique_mints(),
+        )
     };
 
-    if vault_count != tracked_mint_count {
+    if vault_count != tracked_vault_count {
         msg!("Vault count does not match supported mint count");
         return Err(ProgramError::InvalidAccountData);
     }
@@ -89,7 +93,13 @@ pub fn process_initialize_weight_table(
     weight_table_data[0] = WeightTable::DISCRIMINATOR;
     let weight_table_account = WeightTable::try_from_slice_unchecked_mut(&mut weight_table_data)?;
 
-    *weight_table_account = WeightTable::new(*ncn.key, ncn_epoch, current_slot, weight_table_bump);
+    *weight_table_account = WeightTable::new(
+        *ncn.key,
+        ncn_epoch,
+        current_slot,
+        weight_table_bump,
+        *payer.key,
+    );
 
     weight_table_account.initalize_weight_table(&unique_mints)?;
 
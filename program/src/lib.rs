@@ -1,17 +1,33 @@
 mod admin_update_weight_table;
 mod cast_vote;
+mod close_ballot_box;
+mod close_epoch_account;
+mod close_epoch_reward_router;
+mod close_epoch_snapshot;
+mod close_operator_snapshot;
+mod close_vault_snapshot;
+mod close_weight_table;
+mod deregister_mint;
+mod distribute_operator_rewards;
 mod initialize_ballot_box;
+mod initialize_epoch_reward_router;
 mod initialize_epoch_snapshot;
 mod initialize_ncn_config;
 mod initialize_operator_snapshot;
 mod initialize_tracked_mints;
+mod initialize_vault_snapshot;
 mod initialize_weight_table;
+mod migrate_ncn_config;
+mod realloc_tracked_mints;
 mod register_mint;
+mod route_ncn_rewards;
 mod set_config_fees;
 mod set_merkle_root;
 mod set_new_admin;
+mod set_parameters;
 mod set_tie_breaker;
 mod snapshot_vault_operator_delegation;
+mod snapshot_vault_operator_delegations;
 
 use borsh::BorshDeserialize;
 use const_str_to_pubkey::str_to_pubkey;
@@ -26,15 +42,28 @@ use solana_security_txt::security_txt;
 
 use crate::{
     admin_update_weight_table::process_admin_update_weight_table, cast_vote::process_cast_vote,
+    close_ballot_box::process_close_ballot_box,
+    close_epoch_reward_router::process_close_epoch_reward_router,
+    close_epoch_snapshot::process_close_epoch_snapshot,
+    close_operator_snapshot::process_close_operator_snapshot,
+    close_vault_snapshot::process_close_vault_snapshot,
+    close_weight_table::process_close_weight_table, deregister_mint::process_deregister_mint,
+    distribute_operator_rewards::process_distribute_operator_rewards,
     initialize_ballot_box::process_initialize_ballot_box,
+    initialize_epoch_reward_router::process_initialize_epoch_reward_router,
     initialize_epoch_snapshot::process_initialize_epoch_snapshot,
     initialize_ncn_config::process_initialize_ncn_config,
     initialize_operator_snapshot::process_initialize_operator_snapshot,
     initialize_tracked_mints::process_initialize_tracked_mints,
-    initialize_weight_table::process_initialize_weight_table, register_mint::process_register_mint,
-    set_config_fees::process_set_config_fees, set_merkle_root::process_set_merkle_root,
+    initialize_vault_snapshot::process_initialize_vault_snapshot,
+    initialize_weight_table::process_initialize_weight_table,
+    migrate_ncn_config::process_migrate_ncn_config,
+    realloc_tracked_mints::process_realloc_tracked_mints, register_mint::process_register_mint,
+    route_ncn_rewards::process_route_ncn_rewards, set_config_fees::process_set_config_fees,
+    set_merkle_root::process_set_merkle_root, set_parameters::process_set_parameters,
     set_tie_breaker::process_set_tie_breaker,
     snapshot_vault_operator_delegation::process_snapshot_vault_operator_delegation,
+    snapshot_vault_operator_delegations::process_snapshot_vault_operator_delegations,
 };
 
 declare_id!(str_to_pubkey(env!("TIP_ROUTER_PROGRAM_ID")));
@@ -101,6 +130,12 @@ pub fn process_instruction(
             msg!("Instruction: InitializeOperatorSnapshot");
             process_initialize_operator_snapshot(program_id, accounts, first_slot_of_ncn_epoch)
         }
+        TipRouterInstruction::InitializeVaultSnapshot {
+            first_slot_of_ncn_epoch,
+        } => {
+            msg!("Instruction: InitializeVaultSnapshot");
+            process_initialize_vault_snapshot(program_id, accounts, first_slot_of_ncn_epoch)
+        }
         TipRouterInstruction::SnapshotVaultOperatorDelegation {
             first_slot_of_ncn_epoch,
         } => {
@@ -111,6 +146,16 @@ pub fn process_instruction(
                 first_slot_of_ncn_epoch,
             )
         }
+        TipRouterInstruction::SnapshotVaultOperatorDelegations {
+            first_slot_of_ncn_epoch,
+        } => {
+            msg!("Instruction: SnapshotVaultOperatorDelegations");
+            process_snapshot_vault_operator_delegations(
+                program_id,
+                accounts,
+                first_slot_of_ncn_epoch,
+            )
+        }
         // ------------------------------------------
         // Update
         // ------------------------------------------
@@ -122,7 +167,8 @@ pub fn process_instruction(
             new_dao_fee_bps,
             new_ncn_fee_bps,
             new_block_engine_fee_bps,
-            new_fee_wallet,
+            new_dao_fee_wallet,
+            new_ncn_fee_wallet,
         } => {
             msg!("Instruction: SetConfigFees");
             process_set_config_fees(
@@ -131,7 +177,8 @@ pub fn process_instruction(
                 new_dao_fee_bps,
                 new_ncn_fee_bps,
                 new_block_engine_fee_bps,
-                new_fee_wallet,
+                new_dao_fee_wallet,
+                new_ncn_fee_wallet,
             )
         }
         TipRouterInstruction::SetNewAdmin { role } => {
@@ -163,6 +210,7 @@ pub fn process_instruction(
             max_total_claim,
             max_num_nodes,
             epoch,
+            tip_distribution_epoch,
         } => {
             msg!("Instruction: SetMerkleRoot");
             process_set_merkle_root(
@@ -173,6 +221,7 @@ pub fn process_instruction(
                 max_total_claim,
                 max_num_nodes,
                 epoch,
+                tip_distribution_epoch,
             )
         }
         TipRouterInstruction::SetTieBreaker {
@@ -182,5 +231,75 @@ pub fn process_instruction(
             msg!("Instruction: SetTieBreaker");
             process_set_tie_breaker(program_id, accounts, meta_merkle_root, epoch)
         }
+        TipRouterInstruction::DeregisterMint => {
+            msg!("Instruction: DeregisterMint");
+            process_deregister_mint(program_id, accounts)
+        }
+        TipRouterInstruction::ReallocTrackedMints => {
+            msg!("Instruction: ReallocTrackedMints");
+            process_realloc_tracked_mints(program_id, accounts)
+        }
+        TipRouterInstruction::MigrateNcnConfig => {
+            msg!("Instruction: MigrateNcnConfig");
+            process_migrate_ncn_config(program_id, accounts)
+        }
+        TipRouterInstruction::SetParameters {
+            epochs_before_close,
+            snapshot_window_slots,
+            snapshot_grace_epochs,
+            fee_activation_delay_epochs,
+        } => {
+            msg!("Instruction: SetParameters");
+            process_set_parameters(
+                program_id,
+                accounts,
+                epochs_before_close,
+                snapshot_window_slots,
+                snapshot_grace_epochs,
+                fee_activation_delay_epochs,
+            )
+        }
+        // ------------------------------------------
+        // Rewards
+        // ------------------------------------------
+        TipRouterInstruction::InitializeEpochRewardRouter { epoch } => {
+            msg!("Instruction: InitializeEpochRewardRouter");
+            process_initialize_epoch_reward_router(program_id, accounts, epoch)
+        }
+        TipRouterInstruction::RouteNcnRewards { epoch } => {
+            msg!("Instruction: RouteNcnRewards");
+            process_route_ncn_rewards(program_id, accounts, epoch)
+        }
+        TipRouterInstruction::DistributeOperatorRewards { epoch } => {
+            msg!("Instruction: DistributeOperatorRewards");
+            process_distribute_operator_rewards(program_id, accounts, epoch)
+        }
+        // ------------------------------------------
+        // Close
+        // ------------------------------------------
+        TipRouterInstruction::CloseWeightTable { epoch } => {
+            msg!("Instruction: CloseWeightTable");
+            process_close_weight_table(program_id, accounts, epoch)
+        }
+        TipRouterInstruction::CloseEpochSnapshot { epoch } => {
+            msg!("Instruction: CloseEpochSnapshot");
+            process_close_epoch_snapshot(program_id, accounts, epoch)
+        }
+        TipRouterInstruction::CloseOperatorSnapshot { epoch } => {
+            msg!("Instruction: CloseOperatorSnapshot");
+            process_close_operator_snapshot(program_id, accounts, epoch)
+        }
+        TipRouterInstruction::CloseVaultSnapshot { epoch } => {
+            msg!("Instruction: CloseVaultSnapshot");
+            process_close_vault_snapshot(program_id, accounts, epoch)
+        }
+        TipRouterInstruction::CloseBallotBox { epoch } => {
+            msg!("Instruction: CloseBallotBox");
+            process_close_ballot_box(program_id, accounts, epoch)
+        }
+        TipRouterInstruction::CloseEpochRewardRouter { epoch } => {
+            msg!("Instruction: CloseEpochRewardRouter");
+            process_close_epoch_reward_router(program_id, accounts, epoch)
+        }
     }
 }
@@ -0,0 +1,64 @@
+use std::mem::size_of;
+
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_signer, load_system_program};
+use jito_tip_router_core::ncn_config::{LegacyNcnConfig, NcnConfig};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar,
+};
+
+/// Rewrites an NCN config created before the DAO and NCN fee wallets were separated into the
+/// current layout, growing the account to fit. Permissionless, the payer covers the additional
+/// rent
+pub fn process_migrate_ncn_config(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [ncn_config, ncn_account, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Owner, discriminator and PDA are the same in both layouts
+    NcnConfig::load(program_id, ncn_account.key, ncn_config, true)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let old_size = ncn_config.data_len();
+    let legacy_size = 8_usize.checked_add(size_of::<LegacyNcnConfig>()).unwrap();
+    let new_size = 8_usize.checked_add(size_of::<NcnConfig>()).unwrap();
+
+    if old_size == new_size {
+        msg!("NCN config is already in the current layout");
+        return Ok(());
+    }
+    if old_size != legacy_size {
+        msg!("NCN config has an unknown layout of {} bytes", old_size);
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let config = {
+        let ncn_config_data = ncn_config.data.borrow();
+        let legacy_config = LegacyNcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+        NcnConfig::from_legacy(legacy_config)
+    };
+
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(new_size);
+    let lamports_needed = rent_exempt_lamports.saturating_sub(ncn_config.lamports());
+    if lamports_needed > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, ncn_config.key, lamports_needed),
+            &[payer.clone(), ncn_config.clone(), system_program.clone()],
+        )?;
+    }
+
+    msg!(
+        "Migrating NCN Config {} from {} to {} bytes",
+        ncn_config.key,
+        old_size,
+        new_size
+    );
+    ncn_config.realloc(new_size, true)?;
+
+    let mut ncn_config_data = ncn_config.try_borrow_mut_data()?;
+    *NcnConfig::try_from_slice_unchecked_mut(&mut ncn_config_data)? = config;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use std::mem::size_of;
+
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_signer, load_system_program};
+use jito_tip_router_core::{
+    ncn_config::NcnConfig,
+    tracked_mints::{MintEntry, TrackedMints},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar,
+};
+
+/// Grows a tracked mints account created with a smaller `st_mint_list` to the current size
+/// Permissionless, the payer covers the additional rent
+pub fn process_realloc_tracked_mints(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [ncn_config, tracked_mints, ncn_account, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    NcnConfig::load(program_id, ncn_account.key, ncn_config, false)?;
+    TrackedMints::load(program_id, ncn_account.key, tracked_mints, true)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let old_size = tracked_mints.data_len();
+    let new_size = 8_usize.checked_add(size_of::<TrackedMints>()).unwrap();
+
+    if old_size >= new_size {
+        msg!("Tracked mints account is already at full size");
+        return Ok(());
+    }
+
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(new_size);
+    let lamports_needed = rent_exempt_lamports.saturating_sub(tracked_mints.lamports());
+    if lamports_needed > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, tracked_mints.key, lamports_needed),
+            &[payer.clone(), tracked_mints.clone(), system_program.clone()],
+        )?;
+    }
+
+    msg!(
+        "Reallocating Tracked Mints {} from {} to {} bytes",
+        tracked_mints.key,
+        old_size,
+        new_size
+    );
+    tracked_mints.realloc(new_size, true)?;
+
+    // Zeroed entries would read as vault index 0, mark them as empty instead
+    let previous_entry_count = TrackedMints::entries_for_len(old_size);
+    let mut tracked_mints_data = tracked_mints.try_borrow_mut_data()?;
+    let tracked_mints_account =
+        TrackedMints::try_from_slice_unchecked_mut(&mut tracked_mints_data)?;
+    tracked_mints_account.st_mint_list[previous_entry_count..]
+        .iter_mut()
+        .for_each(|mint_entry| *mint_entry = MintEntry::default());
+
+    Ok(())
+}
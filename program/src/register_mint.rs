@@ -1,8 +1,9 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_system_account;
-use jito_restaking_core::{config::Config, ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
+use jito_restaking_core::{ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
 use jito_tip_router_core::{
-    error::TipRouterError, tracked_mints::TrackedMints, weight_table::WeightTable,
+    error::TipRouterError, ncn_epoch::NcnEpochSchedule, tracked_mints::TrackedMints,
+    weight_table::WeightTable,
 };
 use jito_vault_core::{vault::Vault, vault_ncn_ticket::VaultNcnTicket};
 use solana_program::{
@@ -34,18 +35,14 @@ pub fn process_register_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
         false,
     )?;
 
-    let epoch_length = {
-        let restaking_config_data = restaking_config.data.borrow();
-        Config::try_from_slice_unchecked(&restaking_config_data)?.epoch_length()
-    };
+    let ncn_epoch_schedule = NcnEpochSchedule::load(restaking_config)?;
+    let epoch_length = ncn_epoch_schedule.ncn_epoch_length();
 
     let slot = Clock::get()?.slot;
 
-    let ncn_epoch = slot
-        .checked_div(epoch_length)
-        .ok_or(TipRouterError::DenominatorIsZero)?;
+    let ncn_epoch = ncn_epoch_schedule.ncn_epoch(slot);
 
-    // Once tracked_mints.mint_count() == ncn.vault_count, the weight table can be initialized
+    // Once tracked_mints.vault_count() == ncn.vault_count, the weight table can be initialized
     // Once the weight table is initialized, you can't add any more mints
     if weight_table.owner.eq(&system_program::ID) {
         let expected_pubkey = WeightTable::find_program_address(program_id, ncn.key, ncn_epoch).0;
@@ -0,0 +1,113 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_tip_router_core::{
+    epoch_reward_router::EpochRewardRouter, epoch_snapshot::EpochSnapshot, error::TipRouterError,
+    ncn_config::NcnConfig,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Pays the DAO and NCN fees out of any rewards the router received since the last call
+/// and sets the remainder aside for operators
+pub fn process_route_ncn_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [ncn_config, ncn, epoch_snapshot, epoch_reward_router, dao_fee_wallet, ncn_fee_wallet] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    NcnConfig::load(program_id, ncn.key, ncn_config, false)?;
+    EpochSnapshot::load(program_id, ncn.key, epoch, epoch_snapshot, false)?;
+    EpochRewardRouter::load(program_id, ncn.key, epoch, epoch_reward_router, true)?;
+
+    if !dao_fee_wallet.is_writable || !ncn_fee_wallet.is_writable {
+        msg!("Fee wallets must be writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let (fee, operator_count) = {
+        let epoch_snapshot_data = epoch_snapshot.data.borrow();
+        let epoch_snapshot_account = EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?;
+
+        if !epoch_snapshot_account.finalized() {
+            msg!("Rewards can only be routed once the epoch snapshot is finalized");
+            return Err(TipRouterError::EpochSnapshotNotFinalized.into());
+        }
+
+        (
+            *epoch_snapshot_account.fee(),
+            epoch_snapshot_account.operator_count(),
+        )
+    };
+
+    let expected_dao_fee_wallet = fee.dao_fee_wallet();
+    if dao_fee_wallet.key.ne(&expected_dao_fee_wallet) {
+        msg!("DAO fee wallet does not match {}", expected_dao_fee_wallet);
+        return Err(TipRouterError::IncorrectFeeWallet.into());
+    }
+
+    let expected_ncn_fee_wallet = fee.ncn_fee_wallet();
+    if ncn_fee_wallet.key.ne(&expected_ncn_fee_wallet) {
+        msg!("NCN fee wallet does not match {}", expected_ncn_fee_wallet);
+        return Err(TipRouterError::IncorrectFeeWallet.into());
+    }
+
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(epoch_reward_router.data_len());
+
+    let mut epoch_reward_router_data = epoch_reward_router.try_borrow_mut_data()?;
+    let epoch_reward_router_account =
+        EpochRewardRouter::try_from_slice_unchecked_mut(&mut epoch_reward_router_data)?;
+    epoch_reward_router_account.set_operator_count(operator_count);
+
+    // Anything above rent and the rewards still owed to operators arrived since the last call
+    let undistributed_operator_rewards =
+        epoch_reward_router_account.undistributed_operator_rewards()?;
+    let incoming_rewards = epoch_reward_router
+        .lamports()
+        .checked_sub(rent_exempt_minimum)
+        .and_then(|x| x.checked_sub(undistributed_operator_rewards))
+        .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+    if incoming_rewards == 0 {
+        msg!("No new rewards to route");
+        return Ok(());
+    }
+
+    let (dao_rewards, ncn_rewards) =
+        epoch_reward_router_account.route_incoming_rewards(incoming_rewards, &fee)?;
+
+    msg!(
+        "Routing {} lamports: {} to the DAO, {} to the NCN",
+        incoming_rewards,
+        dao_rewards,
+        ncn_rewards
+    );
+
+    transfer_lamports(epoch_reward_router, dao_fee_wallet, dao_rewards)?;
+    transfer_lamports(epoch_reward_router, ncn_fee_wallet, ncn_rewards)?;
+
+    Ok(())
+}
+
+/// Moves lamports out of an account owned by this program
+pub fn transfer_lamports(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    if lamports == 0 {
+        return Ok(());
+    }
+
+    **from.try_borrow_mut_lamports()? = from
+        .lamports()
+        .checked_sub(lamports)
+        .ok_or(TipRouterError::ArithmeticOverflow)?;
+    **to.try_borrow_mut_lamports()? = to
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+    Ok(())
+}
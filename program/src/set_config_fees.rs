@@ -1,7 +1,9 @@
 use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::{config::Config, ncn::Ncn};
-use jito_tip_router_core::{error::TipRouterError, ncn_config::NcnConfig};
+use jito_tip_router_core::{
+    error::TipRouterError, ncn_config::NcnConfig, ncn_epoch::NcnEpochSchedule,
+};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
@@ -13,7 +15,8 @@ pub fn process_set_config_fees(
     new_dao_fee_bps: Option<u64>,
     new_ncn_fee_bps: Option<u64>,
     new_block_engine_fee_bps: Option<u64>,
-    new_fee_wallet: Option<Pubkey>,
+    new_dao_fee_wallet: Option<Pubkey>,
+    new_ncn_fee_wallet: Option<Pubkey>,
 ) -> ProgramResult {
     let [restaking_config, config, ncn_account, fee_admin, restaking_program] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -25,18 +28,7 @@ pub fn process_set_config_fees(
     Ncn::load(restaking_program.key, ncn_account, false)?;
     Config::load(restaking_program.key, restaking_config, false)?;
 
-    let ncn_epoch_length = {
-        let config_data = restaking_config.data.borrow();
-        let config = Config::try_from_slice_unchecked(&config_data)?;
-        config.epoch_length()
-    };
-
-    let epoch = {
-        let current_slot = Clock::get()?.slot;
-        current_slot
-            .checked_div(ncn_epoch_length)
-            .ok_or(TipRouterError::DenominatorIsZero)?
-    };
+    let epoch = NcnEpochSchedule::load(restaking_config)?.ncn_epoch(Clock::get()?.slot);
 
     let mut config_data = config.try_borrow_mut_data()?;
     if config_data[0] != NcnConfig::DISCRIMINATOR {
@@ -57,7 +49,8 @@ pub fn process_set_config_fees(
         new_dao_fee_bps,
         new_ncn_fee_bps,
         new_block_engine_fee_bps,
-        new_fee_wallet,
+        new_dao_fee_wallet,
+        new_ncn_fee_wallet,
         epoch,
     )?;
 
@@ -1,14 +1,18 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_restaking_core::ncn::Ncn;
+use jito_restaking_core::{config::Config, ncn::Ncn};
 use jito_tip_distribution_sdk::{
     derive_tip_distribution_account_address, instruction::upload_merkle_root_ix,
 };
-use jito_tip_router_core::{ballot_box::BallotBox, error::TipRouterError, ncn_config::NcnConfig};
+use jito_tip_router_core::{
+    ballot_box::BallotBox, error::TipRouterError, ncn_config::NcnConfig,
+    ncn_epoch::NcnEpochSchedule,
+};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
     program_error::ProgramError, pubkey::Pubkey,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn process_set_merkle_root(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -17,21 +21,36 @@ pub fn process_set_merkle_root(
     max_total_claim: u64,
     max_num_nodes: u64,
     epoch: u64,
+    tip_distribution_epoch: u64,
 ) -> ProgramResult {
-    let [ncn_config, ncn, ballot_box, vote_account, tip_distribution_account, tip_distribution_config, tip_distribution_program_id, restaking_program_id] =
+    let [ncn_config, restaking_config, ncn, ballot_box, vote_account, tip_distribution_account, tip_distribution_config, tip_distribution_program_id, restaking_program_id] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     NcnConfig::load(program_id, ncn.key, ncn_config, true)?;
+    Config::load(restaking_program_id.key, restaking_config, false)?;
     Ncn::load(restaking_program_id.key, ncn, false)?;
     BallotBox::load(program_id, ncn.key, epoch, ballot_box, false)?;
 
+    // Tip distribution accounts are keyed by Solana epoch, which only line up with NCN epochs
+    // when their lengths match
+    if !NcnEpochSchedule::load(restaking_config)?
+        .ncn_epoch_overlaps_solana_epoch(epoch, tip_distribution_epoch)?
+    {
+        msg!(
+            "Solana epoch {} is not part of NCN epoch {}",
+            tip_distribution_epoch,
+            epoch
+        );
+        return Err(TipRouterError::InvalidTipDistributionEpoch.into());
+    }
+
     let (tip_distribution_address, _) = derive_tip_distribution_account_address(
         tip_distribution_program_id.key,
         vote_account.key,
-        epoch,
+        tip_distribution_epoch,
     );
 
     if tip_distribution_address.ne(tip_distribution_account.key) {
@@ -0,0 +1,74 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{error::TipRouterError, ncn_config::NcnConfig};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_set_parameters(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epochs_before_close: Option<u64>,
+    snapshot_window_slots: Option<u64>,
+    snapshot_grace_epochs: Option<u64>,
+    fee_activation_delay_epochs: Option<u64>,
+) -> ProgramResult {
+    let [config, ncn_account, ncn_admin, restaking_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(ncn_admin, true)?;
+
+    NcnConfig::load(program_id, ncn_account.key, config, true)?;
+    Ncn::load(restaking_program.key, ncn_account, false)?;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    if config_data[0] != NcnConfig::DISCRIMINATOR {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let config = NcnConfig::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    // Verify NCN and Admin
+    if config.ncn != *ncn_account.key {
+        return Err(TipRouterError::IncorrectNcn.into());
+    }
+
+    let ncn_data = ncn_account.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+
+    if ncn.admin != *ncn_admin.key {
+        return Err(TipRouterError::IncorrectNcnAdmin.into());
+    }
+
+    if let Some(epochs_before_close) = epochs_before_close {
+        config.set_epochs_before_close(epochs_before_close)?;
+        msg!("Epochs before close set to {}", epochs_before_close);
+    }
+
+    if let Some(snapshot_window_slots) = snapshot_window_slots {
+        config.set_snapshot_window_slots(snapshot_window_slots)?;
+        msg!("Snapshot window set to {} slots", snapshot_window_slots);
+    }
+
+    if let Some(snapshot_grace_epochs) = snapshot_grace_epochs {
+        config.set_snapshot_grace_epochs(snapshot_grace_epochs);
+        msg!(
+            "Snapshot grace period set to {} epochs",
+            snapshot_grace_epochs
+        );
+    }
+
+    if let Some(fee_activation_delay_epochs) = fee_activation_delay_epochs {
+        config
+            .fees
+            .set_activation_delay_epochs(fee_activation_delay_epochs)?;
+        msg!(
+            "Fee activation delay set to {} epochs",
+            fee_activation_delay_epochs
+        );
+    }
+
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::ncn::Ncn;
-use jito_tip_router_core::{ballot_box::BallotBox, error::TipRouterError, ncn_config::NcnConfig};
+use jito_restaking_core::{config::Config, ncn::Ncn};
+use jito_tip_router_core::{
+    ballot_box::BallotBox, error::TipRouterError, ncn_config::NcnConfig,
+    ncn_epoch::NcnEpochSchedule,
+};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
@@ -13,12 +16,15 @@ pub fn process_set_tie_breaker(
     meta_merkle_root: [u8; 32],
     ncn_epoch: u64,
 ) -> ProgramResult {
-    let [ncn_config, ballot_box, ncn, tie_breaker_admin, restaking_program] = accounts else {
+    let [ncn_config, restaking_config, ballot_box, ncn, tie_breaker_admin, restaking_program] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     NcnConfig::load(program_id, ncn.key, ncn_config, false)?;
     BallotBox::load(program_id, ncn.key, ncn_epoch, ballot_box, false)?;
+    Config::load(restaking_program.key, restaking_config, false)?;
     Ncn::load(restaking_program.key, ncn, false)?;
     load_signer(tie_breaker_admin, false)?;
 
@@ -33,7 +39,8 @@ pub fn process_set_tie_breaker(
     let mut ballot_box_data = ballot_box.data.borrow_mut();
     let ballot_box_account = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
 
-    let current_epoch = Clock::get()?.epoch;
+    // Stalls are measured in NCN epochs, the same unit the ballot box is keyed by
+    let current_epoch = NcnEpochSchedule::load(restaking_config)?.ncn_epoch(Clock::get()?.slot);
 
     ballot_box_account.set_tie_breaker_ballot(
         meta_merkle_root,
@@ -3,9 +3,10 @@ use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_vault_ticket::NcnVaultTicket, operator::Operator,
 };
 use jito_tip_router_core::{
-    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
-    loaders::load_ncn_epoch,
+    epoch_snapshot::{EpochSnapshot, ExclusionReason, OperatorSnapshot, VaultSnapshot},
+    loaders::load_snapshot_ncn_epoch,
     ncn_config::NcnConfig,
+    ncn_epoch::NcnEpochSchedule,
     weight_table::WeightTable,
 };
 use jito_vault_core::{
@@ -14,7 +15,7 @@ use jito_vault_core::{
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    program_error::ProgramError, pubkey::Pubkey, system_program, sysvar::Sysvar,
 };
 
 pub fn process_snapshot_vault_operator_delegation(
@@ -22,7 +23,7 @@ pub fn process_snapshot_vault_operator_delegation(
     accounts: &[AccountInfo],
     first_slot_of_ncn_epoch: Option<u64>,
 ) -> ProgramResult {
-    let [ncn_config, restaking_config, ncn, operator, vault, vault_ncn_ticket, ncn_vault_ticket, vault_operator_delegation, weight_table, epoch_snapshot, operator_snapshot, vault_program, restaking_program] =
+    let [ncn_config, restaking_config, ncn, operator, vault, vault_ncn_ticket, ncn_vault_ticket, vault_operator_delegation, weight_table, epoch_snapshot, operator_snapshot, vault_snapshot, vault_program, restaking_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -42,31 +43,14 @@ pub fn process_snapshot_vault_operator_delegation(
     Config::load(restaking_program.key, restaking_config, false)?;
     Ncn::load(restaking_program.key, ncn, false)?;
     Operator::load(restaking_program.key, operator, false)?;
-    Vault::load(vault_program.key, vault, false)?;
-
-    VaultOperatorDelegation::load(
-        vault_program.key,
-        vault_operator_delegation,
-        vault,
-        operator,
-        false,
-    )?;
-    VaultNcnTicket::load(vault_program.key, vault_ncn_ticket, vault, ncn, false)?;
-    NcnVaultTicket::load(restaking_program.key, ncn_vault_ticket, ncn, vault, false)?;
-
-    if !vault_operator_delegation.data_is_empty() {
-        VaultOperatorDelegation::load(
-            vault_program.key,
-            vault_operator_delegation,
-            vault,
-            operator,
-            false,
-        )?;
-    }
 
     let current_slot = Clock::get()?.slot;
-    let (ncn_epoch, ncn_epoch_length) =
-        load_ncn_epoch(restaking_config, current_slot, first_slot_of_ncn_epoch)?;
+    let (ncn_epoch, ncn_epoch_schedule) = load_snapshot_ncn_epoch(
+        ncn_config,
+        restaking_config,
+        current_slot,
+        first_slot_of_ncn_epoch,
+    )?;
 
     WeightTable::load(program_id, weight_table, ncn, ncn_epoch, false)?;
     EpochSnapshot::load(program_id, ncn.key, ncn_epoch, epoch_snapshot, true)?;
@@ -78,6 +62,84 @@ pub fn process_snapshot_vault_operator_delegation(
         operator_snapshot,
         true,
     )?;
+    VaultSnapshot::load(
+        program_id,
+        vault.key,
+        ncn.key,
+        ncn_epoch,
+        vault_snapshot,
+        true,
+    )?;
+
+    snapshot_vault_operator_delegation(
+        ncn,
+        operator,
+        vault,
+        vault_ncn_ticket,
+        ncn_vault_ticket,
+        vault_operator_delegation,
+        weight_table,
+        epoch_snapshot,
+        operator_snapshot,
+        vault_snapshot,
+        vault_program,
+        restaking_program,
+        current_slot,
+        &ncn_epoch_schedule,
+    )
+}
+
+/// Snapshots a single vault delegation. The NCN, operator, weight table and snapshot
+/// accounts, including the vault snapshot, must already be loaded by the caller.
+#[allow(clippy::too_many_arguments)]
+pub fn snapshot_vault_operator_delegation(
+    ncn: &AccountInfo,
+    operator: &AccountInfo,
+    vault: &AccountInfo,
+    vault_ncn_ticket: &AccountInfo,
+    ncn_vault_ticket: &AccountInfo,
+    vault_operator_delegation: &AccountInfo,
+    weight_table: &AccountInfo,
+    epoch_snapshot: &AccountInfo,
+    operator_snapshot: &AccountInfo,
+    vault_snapshot: &AccountInfo,
+    vault_program: &AccountInfo,
+    restaking_program: &AccountInfo,
+    current_slot: u64,
+    ncn_epoch_schedule: &NcnEpochSchedule,
+) -> ProgramResult {
+    Vault::load(vault_program.key, vault, false)?;
+    VaultNcnTicket::load(vault_program.key, vault_ncn_ticket, vault, ncn, false)?;
+    NcnVaultTicket::load(restaking_program.key, ncn_vault_ticket, ncn, vault, false)?;
+
+    // A missing delegation excludes the vault, so it must be the real delegation address
+    if vault_operator_delegation.data_is_empty() {
+        let expected_vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+            vault_program.key,
+            vault.key,
+            operator.key,
+        )
+        .0;
+        if vault_operator_delegation
+            .key
+            .ne(&expected_vault_operator_delegation)
+        {
+            msg!("Vault operator delegation account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if vault_operator_delegation.owner.ne(&system_program::ID) {
+            msg!("Vault operator delegation account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+    } else {
+        VaultOperatorDelegation::load(
+            vault_program.key,
+            vault_operator_delegation,
+            vault,
+            operator,
+            false,
+        )?;
+    }
 
     let (vault_index, st_mint) = {
         let vault_data = vault.data.borrow();
@@ -86,7 +148,7 @@ pub fn process_snapshot_vault_operator_delegation(
     };
 
     //TODO move to helper function
-    let is_active: bool = {
+    let exclusion_reason: ExclusionReason = {
         let vault_ncn_ticket_data = vault_ncn_ticket.data.borrow();
         let vault_ncn_ticket_account =
             VaultNcnTicket::try_from_slice_unchecked(&vault_ncn_ticket_data)?;
@@ -97,18 +159,24 @@ pub fn process_snapshot_vault_operator_delegation(
 
         let vault_ncn_okay = vault_ncn_ticket_account
             .state
-            .is_active(current_slot, ncn_epoch_length);
+            .is_active(current_slot, ncn_epoch_schedule.ncn_epoch_length());
 
         let ncn_vault_okay = ncn_vault_ticket_account
             .state
-            .is_active(current_slot, ncn_epoch_length);
+            .is_active(current_slot, ncn_epoch_schedule.ncn_epoch_length());
 
         let delegation_dne = vault_operator_delegation.data_is_empty();
 
-        vault_ncn_okay && ncn_vault_okay && !delegation_dne
+        if !vault_ncn_okay || !ncn_vault_okay {
+            ExclusionReason::VaultTicketInactive
+        } else if delegation_dne {
+            ExclusionReason::DelegationMissing
+        } else {
+            ExclusionReason::NotExcluded
+        }
     };
 
-    let total_votes: u128 = if is_active {
+    let total_votes: u128 = if exclusion_reason == ExclusionReason::NotExcluded {
         let vault_operator_delegation_data = vault_operator_delegation.data.borrow();
         let vault_operator_delegation_account =
             VaultOperatorDelegation::try_from_slice_unchecked(&vault_operator_delegation_data)?;
@@ -125,6 +193,12 @@ pub fn process_snapshot_vault_operator_delegation(
         0u128
     };
 
+    let exclusion_reason = if exclusion_reason == ExclusionReason::NotExcluded && total_votes == 0 {
+        ExclusionReason::ZeroSecurity
+    } else {
+        exclusion_reason
+    };
+
     // Increment vault operator delegation
     let mut operator_snapshot_data = operator_snapshot.try_borrow_mut_data()?;
     let operator_snapshot_account =
@@ -135,8 +209,15 @@ pub fn process_snapshot_vault_operator_delegation(
         *vault.key,
         vault_index,
         total_votes,
+        exclusion_reason,
     )?;
 
+    let mut vault_snapshot_data = vault_snapshot.try_borrow_mut_data()?;
+    let vault_snapshot_account =
+        VaultSnapshot::try_from_slice_unchecked_mut(&mut vault_snapshot_data)?;
+
+    vault_snapshot_account.increment_operator_registration(current_slot, total_votes)?;
+
     // If operator is finalized, increment operator registration
     if operator_snapshot_account.finalized() {
         let mut epoch_snapshot_data = epoch_snapshot.try_borrow_mut_data()?;
@@ -0,0 +1,148 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::{config::Config, ncn::Ncn, operator::Operator};
+use jito_tip_router_core::{
+    constants::SNAPSHOT_COMPUTE_UNITS_PER_VAULT,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot, VaultSnapshot},
+    error::TipRouterError,
+    loaders::load_snapshot_ncn_epoch,
+    ncn_config::NcnConfig,
+    weight_table::WeightTable,
+};
+use jito_vault_core::vault::Vault;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, compute_units::sol_remaining_compute_units,
+    entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::snapshot_vault_operator_delegation::snapshot_vault_operator_delegation;
+
+const ACCOUNTS_PER_VAULT: usize = 5;
+
+/// Snapshots several vault delegations for one operator. Remaining accounts are
+/// `[vault, vault_ncn_ticket, ncn_vault_ticket, vault_operator_delegation, vault_snapshot]` groups,
+/// sized by the caller to fit the compute budget. The batch stops early once fewer than
+/// `SNAPSHOT_COMPUTE_UNITS_PER_VAULT` compute units remain, keeping the vaults snapshotted so far,
+/// and fails if that leaves it unable to snapshot any vault. Vaults already in the operator
+/// snapshot are skipped, so the same or overlapping batches can be resubmitted to resume.
+pub fn process_snapshot_vault_operator_delegations(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    first_slot_of_ncn_epoch: Option<u64>,
+) -> ProgramResult {
+    let [ncn_config, restaking_config, ncn, operator, weight_table, epoch_snapshot, operator_snapshot, vault_program, restaking_program, vault_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if vault_accounts.is_empty() || vault_accounts.len() % ACCOUNTS_PER_VAULT != 0 {
+        msg!("Expected groups of {} vault accounts", ACCOUNTS_PER_VAULT);
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    if vault_program.key.ne(&jito_vault_program::id()) {
+        msg!("Incorrect vault program ID");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if restaking_program.key.ne(&jito_restaking_program::id()) {
+        msg!("Incorrect restaking program ID");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    NcnConfig::load(program_id, ncn.key, ncn_config, false)?;
+    Config::load(restaking_program.key, restaking_config, false)?;
+    Ncn::load(restaking_program.key, ncn, false)?;
+    Operator::load(restaking_program.key, operator, false)?;
+
+    let current_slot = Clock::get()?.slot;
+    let (ncn_epoch, ncn_epoch_schedule) = load_snapshot_ncn_epoch(
+        ncn_config,
+        restaking_config,
+        current_slot,
+        first_slot_of_ncn_epoch,
+    )?;
+
+    WeightTable::load(program_id, weight_table, ncn, ncn_epoch, false)?;
+    EpochSnapshot::load(program_id, ncn.key, ncn_epoch, epoch_snapshot, true)?;
+    OperatorSnapshot::load(
+        program_id,
+        operator.key,
+        ncn.key,
+        ncn_epoch,
+        operator_snapshot,
+        true,
+    )?;
+
+    let mut vaults_snapshotted: usize = 0;
+    for group in vault_accounts.chunks(ACCOUNTS_PER_VAULT) {
+        let [vault, vault_ncn_ticket, ncn_vault_ticket, vault_operator_delegation, vault_snapshot] =
+            group
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if sol_remaining_compute_units() < SNAPSHOT_COMPUTE_UNITS_PER_VAULT {
+            if vaults_snapshotted == 0 {
+                msg!("Not enough compute units left to snapshot a vault");
+                return Err(TipRouterError::NotEnoughComputeUnits.into());
+            }
+
+            msg!("Not enough compute units left for the next vault, stopping early");
+            break;
+        }
+
+        Vault::load(vault_program.key, vault, false)?;
+
+        let vault_index = {
+            let vault_data = vault.data.borrow();
+            Vault::try_from_slice_unchecked(&vault_data)?.vault_index()
+        };
+
+        {
+            let operator_snapshot_data = operator_snapshot.data.borrow();
+            let operator_snapshot_account =
+                OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_data)?;
+
+            if operator_snapshot_account.finalized() {
+                msg!("Operator snapshot finalized");
+                break;
+            }
+
+            if operator_snapshot_account.contains_vault_index(vault_index) {
+                msg!("Vault {} already snapshotted, skipping", vault_index);
+                continue;
+            }
+        }
+
+        VaultSnapshot::load(
+            program_id,
+            vault.key,
+            ncn.key,
+            ncn_epoch,
+            vault_snapshot,
+            true,
+        )?;
+
+        snapshot_vault_operator_delegation(
+            ncn,
+            operator,
+            vault,
+            vault_ncn_ticket,
+            ncn_vault_ticket,
+            vault_operator_delegation,
+            weight_table,
+            epoch_snapshot,
+            operator_snapshot,
+            vault_snapshot,
+            vault_program,
+            restaking_program,
+            current_slot,
+            &ncn_epoch_schedule,
+        )?;
+
+        vaults_snapshotted += 1;
+    }
+
+    Ok(())
+}